@@ -2,6 +2,18 @@ game-addons = Spielerweiterungen
 
 addon-install = Installieren
 addon-uninstall = Deinstallieren 
+addon-verify = Dateien der Erweiterung verifizieren
+addon-verify-all = Alle verifizieren
 addon-required = Benötigt
 
 addon-unavailable = Die Erweiterung ist nicht verfügbar 
+
+addon-layers-order = Ladereihenfolge der Ebenen
+addon-layers-order-description = Ziehe Ebenen, um ihre Reihenfolge zu ändern. Dateien der unteren Ebenen ersetzen Dateien der oberen
+addon-layer-overrides = Überschriebene Dateien: {$files}
+
+addon-profile = Erweiterungsprofil
+addon-profile-create = Neues Profil
+addon-profile-duplicate = Profil duplizieren
+addon-profile-delete = Profil löschen
+addon-profile-name = Profilname
@@ -5,5 +5,18 @@ details-last-played = Zuletzt gespielt: {$last-played}
 details-play = Spielen
 details-kill = Beenden
 details-verify = Verifizieren
+details-verify-quick-description = Seit der letzten Verifizierung geänderte Dateien verifizieren
+details-verify-full = Vollständige Verifizierung
+details-verify-full-description = Alle Spieldateien verifizieren
+details-verify-dry-run = Beschädigte Dateien finden
+details-verify-dry-run-description = Alle Spieldateien verifizieren, ohne sie zu reparieren
 details-manage-addons = Erweiterungen verwalten
+details-predownload = Vorab herunterladen
+details-predownloaded = Vorab heruntergeladen
 details-download = Herunterladen
+details-update-policy = Update-Richtlinie
+details-update-policy-default = Standard-Updates
+details-update-policy-never = Nicht nach Updates suchen
+details-update-policy-notify = Über Updates benachrichtigen
+details-update-policy-download = Updates herunterladen
+details-addons-profile = Erweiterungsprofil
@@ -2,20 +2,33 @@ config-property-update-failed = Aktualisierung fehlgeschalgen
 
 tasks-resolve-queued-failed = Aufgabe in der Warteschlange konnte nicht ausgeführt werden
 tasks-get-status-failed     = Das Erhalten des Status der Aufgaben für {$game-title} ist fehlgeschlagen
+tasks-history-read-failed   = Aufgabenverlauf konnte nicht gelesen werden
+tasks-history-export-failed = Aufgabenverlauf konnte nicht exportiert werden
+tasks-history-clear-failed  = Aufgabenverlauf konnte nicht geleert werden
+verify-report-export-failed = Verifizierungsbericht konnte nicht exportiert werden
 
 addon-verify-installation-failed = Das Verifizieren der Erweiterung für {$game-title} ist fehlgeschlagen
 addon-find-diff-failed           = Der Versionenpatch für die {$game-title} Erweiterng konnte nicht gefunden werden 
 addon-find-download-failed       = Die Downloadinformationen für die {$game-title} Erweiterung konnten nicht gefunden werden
 addon-find-path-failed           = Der Installationspfad für die {$game-title} Erweiterung konnte nicht gefunden werden
 addon-get-paths-failed           = Die Pfade für die {$game-title} Erweiterung sind nicht erhältlich
+addon-get-integrity-failed       = Die Integritätsinformationen für die {$game-title} Erweiterung sind nicht erhältlich
+addon-patches-unsupported        = Update-Patches der {$game-title} Erweiterung können nicht angewendet werden
 addon-not-installed              = Die {$game-title} Erweiterung ist nicht installiert
+addon-enable-failed              = Erweiterung konnte nicht aktiviert werden
+addon-disable-failed             = Erweiterung konnte nicht deaktiviert werden
+addon-profile-name-invalid       = Der Profilname "{$name}" ist leer oder wird bereits verwendet
 
 game-verify-installation-failed = Installation von {$game-title} konnte nicht verifiziert werden
 game-find-diff-failed           = Der Versionenpatch für {$game-title} konnte nicht gefunden werden
 game-find-download-failed       = Die Downloadinformationen für {$game-title} konnten nicht gefunden werden
+game-find-predownload-failed    = Vorab-Download-Informationen für {$game-title} konnten nicht gefunden werden
+game-predownload-unavailable    = Vorab-Download für {$game-title} ist nicht verfügbar
 game-find-path-failed           = Der Installationsphad für {$game-title} konnte nicht gefunden werden
 game-get-settings-failed        = Einstellungen für {$game-title} sind nicht verfügbar
 game-get-addons-failed          = Erweiterungen für {$game-title} sind nicht verfügbar
+game-addons-conflicts           = Einige Erweiterungen für {$game-title} wurden wegen Konflikten übersprungen
+game-patches-unsupported        = Update-Patches für {$game-title} können nicht angewendet werden
 game-get-paths-failed           = Pfade für {$game-title} sind nicht verfügbar
 game-get-integrity-failed       = Integritätsinformationen für {$game-title} sind nicht verfügbar
 game-get-status-failed          = Der Status von {$game-title} ist nicht verfügbar
@@ -25,6 +38,11 @@ game-kill-failed                = {$game-title} konnte nicht beendet werden
 
 launch-required-addon-unavailable = Die Erweiterung {$addon-title} der Gruppe {$group-title} ist nicht verfügbar oder veraltet. Es ist möglich das Spiel ohne dies zu starten oder mit der veralteten Version fortzufahren
 
+disk-space-not-enough         = Nicht genügend Speicherplatz
+disk-space-not-enough-message = {$path} benötigt {$required} freien Speicherplatz, aber nur {$available} ist verfügbar
+disk-space-low                = Wenig Speicherplatz
+disk-space-low-message        = Nach der Installation bleiben in {$path} nur {$remaining} freier Speicherplatz übrig
+
 loading-preparing-folders-failed     = Standartordner konnten nicht vorbereitet werden
 loading-initializing-debug-failed    = Die Initializierung der Debuginformationsausgabe ist fehlgeschlagen
 loading-preparing-config-failed      = Die Vorbereitung der Konfigurationsdatei ist fehlgeschlagen
@@ -1,9 +1,23 @@
 main-menu-launcher-folder = Launcher Order
 main-menu-config-file     = Konfigurationsdatei
 main-menu-debug-file      = Debugdatei
+main-menu-update-all      = Alles aktualisieren
+main-menu-verify-all      = Alle Spiele verifizieren
+main-menu-tasks-history   = Aufgabenverlauf
 main-menu-about           = Über
 
 main-installed-games = Installierte Spiele
 main-queued-games    = Ausstehende Spiele
 main-outdated-games  = Veraltete Spiele
 main-available-games = Verfügbare Spiele
+
+batch-update-all = Alles aktualisieren
+batch-update-summary = Spiele: {$games}, Erweiterungen: {$addons}. Downloadgröße: {$size}
+batch-verify-all = Alle Spiele verifizieren
+batch-verify-summary = Spiele: {$games}. Dateigröße: {$size}
+batch-queue = Einreihen
+
+batch-nothing-to-update = Alles ist auf dem neuesten Stand
+batch-nothing-to-verify = Keine installierten Spiele zum Verifizieren
+
+auto-update-available = Neue Updates sind verfügbar
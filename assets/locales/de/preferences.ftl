@@ -7,6 +7,23 @@ general-launcher-language-description = Ändert sich mit dem nächsten Neustart
 
 general-verify-games = Verifiziere Spiele
 general-verify-games-description = Verifiziere Spielinstallationen nach Installation oder einem Update
+general-stream-extraction = Beim Herunterladen entpacken
+general-stream-extraction-description = Tar- und Zip-Archive während des Herunterladens entpacken. Benötigt weniger freien Speicherplatz
+general-files-cache = Dateicache
+general-files-cache-description = Heruntergeladene Dateien in einem gemeinsamen Cache speichern und in anderen Spielen, Editionen und Add-ons wiederverwenden
+general-max-concurrent-tasks = Gleichzeitige Aufgaben
+general-max-concurrent-tasks-description = Maximale Anzahl gleichzeitig laufender Aufgaben. Aufgaben, die dieselben Dateien verwenden, werden immer nacheinander ausgeführt
+general-proxy = Proxy
+general-proxy-description = HTTP- oder SOCKS-Proxy, der vom Launcher und den Integrationsskripten der Spiele verwendet wird
+general-proxy-uri = Proxy-Adresse, z. B. http://127.0.0.1:8080
+general-proxy-bypass = Hosts mit direkter Verbindung, durch Kommas getrennt
+general-update-policy = Spiel-Updates
+general-update-policy-description = Was getan werden soll, wenn neue Versionen der installierten Spiele und Add-ons gefunden werden. Kann pro Spiel in dessen Details geändert werden
+general-update-policy-never = Nicht prüfen
+general-update-policy-notify = Benachrichtigen
+general-update-policy-download = Automatisch herunterladen
+general-updates-check-interval = Update-Prüfintervall
+general-updates-check-interval-description = Zeit zwischen Update-Prüfungen im Hintergrund, in Minuten
 
 preferences--wine = Wine
 
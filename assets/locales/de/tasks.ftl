@@ -5,13 +5,19 @@ tasks-avg-speed = {$format ->
     *[data] Geschwindigkeit: {$speed}/s
 }
 
-tasks-avg-eta = Verbleibende Zeit: {$eta}
+tasks-speed = {$format ->
+    [items] Geschwindigkeit: {$speed} p/s
+    *[data] Geschwindigkeit: {$speed}/s
+}
+
+tasks-eta = Verbleibend: {$eta}
 
 tasks-pending              = Ausstehend
 tasks-preparing-transition = Bereite Übergang vor...
 tasks-finishing-transition = Schließe Übergang ab...
 tasks-downloading          = Lade herunter...
 tasks-unpacking            = Entpacke...
+tasks-applying-patches     = Wende Patches an...
 tasks-deleting-files       = Lösche Dateien...
 tasks-pre-transition-code  = Führe Vorübergangscodeaufgabe aus...
 tasks-transition-code      = Führe Übergangscodeaufgabe aus...
@@ -24,3 +30,25 @@ tasks-repairing-files      = Repariere Dateien...
 tasks-finished             = Fertig
 
 tasks-delete-files = Lösche Dateien
+
+tasks-move-earlier = Früher starten
+tasks-move-later = Später starten
+tasks-cancel = Abbrechen
+
+tasks-history             = Aufgabenverlauf
+tasks-history-empty       = Es wurden noch keine Aufgaben ausgeführt
+tasks-history-export      = Exportieren
+tasks-history-clear       = Leeren
+tasks-history-finished    = Abgeschlossen
+tasks-history-failed      = Fehlgeschlagen
+tasks-history-cancelled   = Abgebrochen
+tasks-history-transferred = Übertragen
+tasks-history-errors      = Fehler
+
+verify-report = Verifizierungsbericht von {$game-title}
+verify-report-summary = Beschädigte Dateien: {$broken} von {$total}
+verify-report-missing = Datei fehlt
+verify-report-size-mismatch = Größe ist {$actual} statt {$expected}
+verify-report-hash-mismatch = {$hash-type}-Hash ist {$actual} statt {$expected}
+verify-report-export = Exportieren
+verify-report-repair = Jetzt reparieren
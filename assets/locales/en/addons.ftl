@@ -2,6 +2,18 @@ game-addons = Game addons
 
 addon-install = Install
 addon-uninstall = Uninstall
+addon-verify = Verify addon files
+addon-verify-all = Verify all
 addon-required = Required
 
 addon-unavailable = Addon is unavailable
+
+addon-layers-order = Layers load order
+addon-layers-order-description = Drag layers to change their order. Files of the lower layers replace files of the upper ones
+addon-layer-overrides = Overridden files: {$files}
+
+addon-profile = Addons profile
+addon-profile-create = New profile
+addon-profile-duplicate = Duplicate profile
+addon-profile-delete = Delete profile
+addon-profile-name = Profile name
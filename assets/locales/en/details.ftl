@@ -5,5 +5,18 @@ details-last-played = Last played: {$last-played}
 details-play = Play
 details-kill = Kill
 details-verify = Verify
+details-verify-quick-description = Verify files changed since the last verification
+details-verify-full = Full verification
+details-verify-full-description = Verify all the game files
+details-verify-dry-run = Find broken files
+details-verify-dry-run-description = Verify all the game files without repairing them
 details-manage-addons = Manage addons
+details-predownload = Pre-download
+details-predownloaded = Pre-downloaded
 details-download = Download
+details-update-policy = Updates policy
+details-update-policy-default = Default updates
+details-update-policy-never = Don't check updates
+details-update-policy-notify = Notify about updates
+details-update-policy-download = Download updates
+details-addons-profile = Addons profile
@@ -2,20 +2,33 @@ config-property-update-failed = Failed to update property
 
 tasks-resolve-queued-failed = Failed to resolve queued task
 tasks-get-status-failed     = Failed to get {$game-title} task status
+tasks-history-read-failed   = Failed to read tasks history
+tasks-history-export-failed = Failed to export tasks history
+tasks-history-clear-failed  = Failed to clear tasks history
+verify-report-export-failed = Failed to export verification report
 
 addon-verify-installation-failed = Unable to verify addon installation for {$game-title}
 addon-find-diff-failed           = Unable to find {$game-title} addon version diff
 addon-find-download-failed       = Unable to find {$game-title} addon download info
 addon-find-path-failed           = Unable to find {$game-title} addon installation path
 addon-get-paths-failed           = Unable to get {$game-title} addon paths
+addon-get-integrity-failed       = Unable to get {$game-title} addon integrity info
+addon-patches-unsupported        = Unable to apply {$game-title} addon update patches
 addon-not-installed              = {$game-title} addon is not installed
+addon-enable-failed              = Unable to enable addon
+addon-disable-failed             = Unable to disable addon
+addon-profile-name-invalid       = Profile name "{$name}" is empty or already used
 
 game-verify-installation-failed = Unable to verify {$game-title} installation
 game-find-diff-failed           = Unable to find {$game-title} version diff
 game-find-download-failed       = Unable to find {$game-title} download info
+game-find-predownload-failed    = Unable to find {$game-title} pre-download info
+game-predownload-unavailable    = {$game-title} pre-download is not available
 game-find-path-failed           = Unable to find {$game-title} installation path
 game-get-settings-failed        = Unable to get {$game-title} settings
 game-get-addons-failed          = Unable to get {$game-title} addons
+game-addons-conflicts           = Some {$game-title} addons were skipped because of conflicts
+game-patches-unsupported        = Unable to apply {$game-title} update patches
 game-get-paths-failed           = Unable to get {$game-title} paths
 game-get-integrity-failed       = Unable to get {$game-title} integrity info
 game-get-status-failed          = Unable to get {$game-title} status
@@ -25,6 +38,11 @@ game-kill-failed                = Failed to kill {$game-title}
 
 launch-required-addon-unavailable = Addon {$addon-title} from group {$group-title} is unavailable or outdated. You can launch the game without it or continue to use old version
 
+disk-space-not-enough         = Not enough disk space
+disk-space-not-enough-message = {$path} requires {$required} of free space but only {$available} is available
+disk-space-low                = Low disk space
+disk-space-low-message        = Only {$remaining} of free space will remain in {$path} after the installation
+
 loading-preparing-folders-failed     = Failed to prepare default folders
 loading-initializing-debug-failed    = Failed to initialize debug output
 loading-preparing-config-failed      = Failed to prepare config file
@@ -1,9 +1,23 @@
 main-menu-launcher-folder = Launcher folder
 main-menu-config-file     = Config file
 main-menu-debug-file      = Debug file
+main-menu-update-all      = Update all
+main-menu-verify-all      = Verify all games
+main-menu-tasks-history   = Tasks history
 main-menu-about           = About
 
 main-installed-games = Installed games
 main-queued-games    = Queued games
 main-outdated-games  = Outdated games
 main-available-games = Available games
+
+batch-update-all = Update all
+batch-update-summary = Games: {$games}, addons: {$addons}. Download size: {$size}
+batch-verify-all = Verify all games
+batch-verify-summary = Games: {$games}. Files size: {$size}
+batch-queue = Queue
+
+batch-nothing-to-update = Everything is up to date
+batch-nothing-to-verify = No installed games to verify
+
+auto-update-available = New updates are available
@@ -7,6 +7,23 @@ general-launcher-language-description = Changes after restart
 
 general-verify-games = Verify games
 general-verify-games-description = Verify games installations after installation or updating
+general-stream-extraction = Extract while downloading
+general-stream-extraction-description = Extract tar and zip archives while downloading them. Requires less free disk space
+general-files-cache = Files cache
+general-files-cache-description = Keep downloaded files in a shared cache and reuse them in other games, editions and addons
+general-max-concurrent-tasks = Concurrent tasks
+general-max-concurrent-tasks-description = Max amount of tasks running at the same time. Tasks which use the same files are always run one by one
+general-proxy = Proxy
+general-proxy-description = HTTP or SOCKS proxy used by the launcher and games integration scripts
+general-proxy-uri = Proxy address, e.g. http://127.0.0.1:8080
+general-proxy-bypass = Direct connection hosts, separated by commas
+general-update-policy = Games updates
+general-update-policy-description = What to do when new versions of the installed games and addons are found. Can be changed per game in its details
+general-update-policy-never = Don't check
+general-update-policy-notify = Notify
+general-update-policy-download = Download automatically
+general-updates-check-interval = Updates check interval
+general-updates-check-interval-description = Time between background updates checks, in minutes
 
 preferences--wine = Wine
 
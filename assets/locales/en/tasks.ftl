@@ -5,13 +5,19 @@ tasks-avg-speed = {$format ->
     *[data] Avg speed: {$speed}/s
 }
 
-tasks-avg-eta = Avg ETA: {$eta}
+tasks-speed = {$format ->
+    [items] Speed: {$speed} p/s
+    *[data] Speed: {$speed}/s
+}
+
+tasks-eta = ETA: {$eta}
 
 tasks-pending              = Pending
 tasks-preparing-transition = Preparing transition...
 tasks-finishing-transition = Finishing transition...
 tasks-downloading          = Downloading...
 tasks-unpacking            = Unpacking...
+tasks-applying-patches     = Applying patches...
 tasks-deleting-files       = Deleting files...
 tasks-pre-transition-code  = Running task pre-transition code...
 tasks-transition-code      = Running task transition code...
@@ -24,3 +30,25 @@ tasks-repairing-files      = Repairing files...
 tasks-finished             = Finished
 
 tasks-delete-files = Delete files
+
+tasks-move-earlier = Start earlier
+tasks-move-later = Start later
+tasks-cancel = Cancel
+
+tasks-history             = Tasks history
+tasks-history-empty       = No tasks were run yet
+tasks-history-export      = Export
+tasks-history-clear       = Clear
+tasks-history-finished    = Finished
+tasks-history-failed      = Failed
+tasks-history-cancelled   = Cancelled
+tasks-history-transferred = Transferred
+tasks-history-errors      = Errors
+
+verify-report = {$game-title} verification report
+verify-report-summary = Broken files: {$broken} of {$total}
+verify-report-missing = file is missing
+verify-report-size-mismatch = size is {$actual} instead of {$expected}
+verify-report-hash-mismatch = {$hash-type} hash is {$actual} instead of {$expected}
+verify-report-export = Export
+verify-report-repair = Repair now
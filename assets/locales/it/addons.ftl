@@ -2,6 +2,18 @@ game-addons = Addon del gioco
 
 addon-install = Installa addon
 addon-uninstall = Disinstalla addon
+addon-verify = Verifica i file dell'addon
+addon-verify-all = Verifica tutti
 addon-required = Addon richiesto
 
 addon-unavailable = Addon non disponibile
+
+addon-layers-order = Ordine di caricamento dei livelli
+addon-layers-order-description = Trascina i livelli per cambiarne l'ordine. I file dei livelli inferiori sostituiscono quelli dei livelli superiori
+addon-layer-overrides = File sovrascritti: {$files}
+
+addon-profile = Profilo degli addon
+addon-profile-create = Nuovo profilo
+addon-profile-duplicate = Duplica profilo
+addon-profile-delete = Elimina profilo
+addon-profile-name = Nome del profilo
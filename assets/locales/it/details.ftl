@@ -5,5 +5,18 @@ details-last-played = Giocato l'ultima volta: {$last-played}
 details-play = Gioca
 details-kill = Chiudi
 details-verify = Verifica
+details-verify-quick-description = Verifica i file modificati dall'ultima verifica
+details-verify-full = Verifica completa
+details-verify-full-description = Verifica tutti i file del gioco
+details-verify-dry-run = Trova file danneggiati
+details-verify-dry-run-description = Verifica tutti i file del gioco senza ripararli
 details-manage-addons = Gestisci gli addon
+details-predownload = Pre-download
+details-predownloaded = Pre-scaricato
 details-download = Scarica
+details-update-policy = Politica degli aggiornamenti
+details-update-policy-default = Aggiornamenti predefiniti
+details-update-policy-never = Non controllare gli aggiornamenti
+details-update-policy-notify = Notifica gli aggiornamenti
+details-update-policy-download = Scarica gli aggiornamenti
+details-addons-profile = Profilo degli addon
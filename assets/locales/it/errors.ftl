@@ -2,20 +2,33 @@ config-property-update-failed = Aggiornamento delle proprietà non riuscito
 
 tasks-resolve-queued-failed = Risoluzione delle attività in coda non riuscita
 tasks-get-status-failed     = Ottenimento dello stato attività del gioco {$game-title} non riuscito
+tasks-history-read-failed   = Lettura della cronologia attività non riuscita
+tasks-history-export-failed = Esportazione della cronologia attività non riuscita
+tasks-history-clear-failed  = Cancellazione della cronologia attività non riuscita
+verify-report-export-failed = Impossibile esportare il rapporto di verifica
 
 addon-verify-installation-failed = Impossibile verificare l'installazione dell'addon per il gioco {$game-title}
 addon-find-diff-failed           = Impossibile trovare version diff dell'addon per il gioco {$game-title}
 addon-find-download-failed       = Impossibile trovare informazioni sul download dell'addon per il gioco {$game-title} 
 addon-find-path-failed           = Impossibile trovare il percorso di installazione dell'addon per il gioco {$game-title}
 addon-get-paths-failed           = Impossibile ottenere i percorsi dell'addon per il gioco {$game-title}
+addon-get-integrity-failed       = Impossibile ottenere le informazioni di integrità dell'addon per il gioco {$game-title}
+addon-patches-unsupported        = Impossibile applicare le patch di aggiornamento dell'addon per il gioco {$game-title}
 addon-not-installed              = L'addon per il gioco {$game-title} non è installato
+addon-enable-failed              = Impossibile abilitare l'addon
+addon-disable-failed             = Impossibile disabilitare l'addon
+addon-profile-name-invalid       = Il nome del profilo "{$name}" è vuoto o già in uso
 
 game-verify-installation-failed = Impossibile verificare l'installazione del gioco {$game-title}
 game-find-diff-failed           = Impossibile trovare la version diff del gioco {$game-title}
 game-find-download-failed       = Impossibile trovare informazioni sul download del gioco {$game-title}
+game-find-predownload-failed    = Impossibile trovare le informazioni di pre-download di {$game-title}
+game-predownload-unavailable    = Il pre-download di {$game-title} non è disponibile
 game-find-path-failed           = Impossibile trovare il percorso di installazione del gioco {$game-title}
 game-get-settings-failed        = Impossibile ottenere le impostazioni del gioco {$game-title}
 game-get-addons-failed          = Impossibile ottenere gli addon per il gioco {$game-title}
+game-addons-conflicts           = Alcuni addon per il gioco {$game-title} sono stati saltati a causa di conflitti
+game-patches-unsupported        = Impossibile applicare le patch di aggiornamento per il gioco {$game-title}
 game-get-paths-failed           = Impossibile ottenere i percorsi per il gioco {$game-title}
 game-get-integrity-failed       = Impossibile ottenere le informazioni sull'integrità del gioco {$game-title}
 game-get-status-failed          = Impossibile ottenere informazioni sullo stato del gioco {$game-title}
@@ -25,6 +38,11 @@ game-kill-failed                = Chiusura del gioco {$game-title} non riuscita
 
 launch-required-addon-unavailable = L'addon {$addon-title} del gruppo {$group-title} non è disponibile o/e non aggiornato. Puoi avviare il gioco senza di esso o continuare ad usare la versione precedente.
 
+disk-space-not-enough         = Spazio su disco insufficiente
+disk-space-not-enough-message = {$path} richiede {$required} di spazio libero ma solo {$available} è disponibile
+disk-space-low                = Spazio su disco in esaurimento
+disk-space-low-message        = Dopo l'installazione rimarranno solo {$remaining} di spazio libero in {$path}
+
 loading-preparing-folders-failed     = Preparazione delle cartelle predefinite non riuscita
 loading-initializing-debug-failed    = Inizializzazione degli output di debug non riuscita
 loading-preparing-config-failed      = Preparazione del file di configurazione non riuscita
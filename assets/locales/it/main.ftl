@@ -1,9 +1,23 @@
 main-menu-launcher-folder = Cartella del launcher
 main-menu-config-file     = File di configurazione
 main-menu-debug-file      = File di debug
+main-menu-update-all      = Aggiorna tutto
+main-menu-verify-all      = Verifica tutti i giochi
+main-menu-tasks-history   = Cronologia attività
 main-menu-about           = Informazioni
 
 main-installed-games = Giochi installati
 main-queued-games    = Giochi in coda
 main-outdated-games  = Giochi non aggiornati
 main-available-games = Giochi disponibili
+
+batch-update-all = Aggiorna tutto
+batch-update-summary = Giochi: {$games}, addon: {$addons}. Dimensione del download: {$size}
+batch-verify-all = Verifica tutti i giochi
+batch-verify-summary = Giochi: {$games}. Dimensione dei file: {$size}
+batch-queue = Metti in coda
+
+batch-nothing-to-update = È tutto aggiornato
+batch-nothing-to-verify = Nessun gioco installato da verificare
+
+auto-update-available = Sono disponibili nuovi aggiornamenti
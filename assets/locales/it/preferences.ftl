@@ -7,6 +7,23 @@ general-launcher-language-description = Si applica dopo il riavvio dell'applicaz
 
 general-verify-games = Verifica giochi
 general-verify-games-description = Verifica i file di installazione dei giochi dopo l'installazione od un aggiornamento.
+general-stream-extraction = Estrai durante il download
+general-stream-extraction-description = Estrai gli archivi tar e zip durante il download. Richiede meno spazio libero su disco
+general-files-cache = Cache dei file
+general-files-cache-description = Conserva i file scaricati in una cache condivisa e riutilizzali in altri giochi, edizioni e addon
+general-max-concurrent-tasks = Attività simultanee
+general-max-concurrent-tasks-description = Numero massimo di attività eseguite contemporaneamente. Le attività che usano gli stessi file vengono sempre eseguite una alla volta
+general-proxy = Proxy
+general-proxy-description = Proxy HTTP o SOCKS usato dal launcher e dagli script di integrazione dei giochi
+general-proxy-uri = Indirizzo del proxy, ad esempio http://127.0.0.1:8080
+general-proxy-bypass = Host con connessione diretta, separati da virgole
+general-update-policy = Aggiornamenti dei giochi
+general-update-policy-description = Cosa fare quando vengono trovate nuove versioni dei giochi e degli addon installati. Può essere cambiato per ogni gioco nei suoi dettagli
+general-update-policy-never = Non controllare
+general-update-policy-notify = Notifica
+general-update-policy-download = Scarica automaticamente
+general-updates-check-interval = Intervallo di controllo aggiornamenti
+general-updates-check-interval-description = Tempo tra i controlli degli aggiornamenti in background, in minuti
 
 preferences--wine = Wine
 
@@ -5,13 +5,19 @@ tasks-avg-speed = {$format ->
     *[data] Velocità media: {$speed}/s
 }
 
-tasks-avg-eta = Tempo stimato: {$eta}
+tasks-speed = {$format ->
+    [items] Velocità: {$speed} p/s
+    *[data] Velocità: {$speed}/s
+}
+
+tasks-eta = Tempo rimanente: {$eta}
 
 tasks-pending              = In attesa
 tasks-preparing-transition = Preparazione transizione...
 tasks-finishing-transition = Ultimazione transizione...
 tasks-downloading          = Scaricando...
 tasks-unpacking            = Spacchettando...
+tasks-applying-patches     = Applicazione delle patch...
 tasks-deleting-files       = Cancellazione dei file...
 tasks-pre-transition-code  = Esecuzione attività pre-transizione...
 tasks-transition-code      = Esecuzione attività di transizione...
@@ -24,3 +30,25 @@ tasks-repairing-files      = Riparazione dei file...
 tasks-finished             = Completato
 
 tasks-delete-files = Cancella i file
+
+tasks-move-earlier = Avvia prima
+tasks-move-later = Avvia dopo
+tasks-cancel = Annulla
+
+tasks-history             = Cronologia attività
+tasks-history-empty       = Nessuna attività è stata ancora eseguita
+tasks-history-export      = Esporta
+tasks-history-clear       = Cancella
+tasks-history-finished    = Completata
+tasks-history-failed      = Non riuscita
+tasks-history-cancelled   = Annullata
+tasks-history-transferred = Trasferito
+tasks-history-errors      = Errori
+
+verify-report = Rapporto di verifica di {$game-title}
+verify-report-summary = File danneggiati: {$broken} su {$total}
+verify-report-missing = il file è mancante
+verify-report-size-mismatch = la dimensione è {$actual} invece di {$expected}
+verify-report-hash-mismatch = l'hash {$hash-type} è {$actual} invece di {$expected}
+verify-report-export = Esporta
+verify-report-repair = Ripara ora
@@ -2,6 +2,18 @@ game-addons = Complementos
 
 addon-install = Instalar
 addon-uninstall = Desinstalar
+addon-verify = Verificar arquivos do complemento
+addon-verify-all = Verificar todos
 addon-required = Necessário
 
 addon-unavailable = O Complemento não está disponível
+
+addon-layers-order = Ordem de carregamento das camadas
+addon-layers-order-description = Arraste as camadas para alterar a ordem. Arquivos das camadas inferiores substituem os das superiores
+addon-layer-overrides = Arquivos substituídos: {$files}
+
+addon-profile = Perfil de complementos
+addon-profile-create = Novo perfil
+addon-profile-duplicate = Duplicar perfil
+addon-profile-delete = Excluir perfil
+addon-profile-name = Nome do perfil
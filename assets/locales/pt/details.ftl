@@ -5,5 +5,18 @@ details-last-played = Jogado pela última vez: {$last-played}
 details-play = Jogar
 details-kill = Matar processo
 details-verify = Verificar
+details-verify-quick-description = Verificar arquivos alterados desde a última verificação
+details-verify-full = Verificação completa
+details-verify-full-description = Verificar todos os arquivos do jogo
+details-verify-dry-run = Encontrar arquivos corrompidos
+details-verify-dry-run-description = Verificar todos os arquivos do jogo sem repará-los
 details-manage-addons = Gerenciar complementos
+details-predownload = Pré-download
+details-predownloaded = Pré-baixado
 details-download = Baixar
+details-update-policy = Política de atualizações
+details-update-policy-default = Atualizações padrão
+details-update-policy-never = Não verificar atualizações
+details-update-policy-notify = Notificar sobre atualizações
+details-update-policy-download = Baixar atualizações
+details-addons-profile = Perfil de complementos
@@ -2,20 +2,33 @@ config-property-update-failed = Erro ao atualizar propriedade
 
 tasks-resolve-queued-failed = Erro ao finalizar a tarefa na fila
 tasks-get-status-failed     = Falha ao pegar o status da tarefa de {$game-title}
+tasks-history-read-failed   = Falha ao ler o histórico de tarefas
+tasks-history-export-failed = Falha ao exportar o histórico de tarefas
+tasks-history-clear-failed  = Falha ao limpar o histórico de tarefas
+verify-report-export-failed = Falha ao exportar o relatório de verificação
 
 addon-verify-installation-failed = Impossível verificar a instalação do complemento para {$game-title}
 addon-find-diff-failed           = Impossível encontrar o diff do complemento de {$game-title}
 addon-find-download-failed       = Impossível encontrar informações de download do complemento de {$game-title}
 addon-find-path-failed           = Impossível encontrar o diretório de instalação do complemento de {$game-title}
 addon-get-paths-failed           = Impossível encontrar o diretório de complementos de {$game-title}
+addon-get-integrity-failed       = Impossível obter as informações de integridade do complemento de {$game-title}
+addon-patches-unsupported        = Não foi possível aplicar os patches de atualização do complemento de {$game-title}
 addon-not-installed              = O complemento de {$game-title} não está instalado
+addon-enable-failed              = Não foi possível ativar o complemento
+addon-disable-failed             = Não foi possível desativar o complemento
+addon-profile-name-invalid       = O nome de perfil "{$name}" está vazio ou já está em uso
 
 game-verify-installation-failed = Erro ao verificar a instalação de {$game-title}
 game-find-diff-failed           = Impossível encontrar diff de {$game-title}
 game-find-download-failed       = Impossível encontrar informações do Download de {$game-title}
+game-find-predownload-failed    = Não foi possível encontrar informações de pré-download de {$game-title}
+game-predownload-unavailable    = O pré-download de {$game-title} não está disponível
 game-find-path-failed           = Não foi encontrado o diretório de instalação de {$game-title}
 game-get-settings-failed        = Foi impossível encontrar as configurações para {$game-title}
 game-get-addons-failed          = Não foi possível listar os complementos de {$game-title}
+game-addons-conflicts           = Alguns complementos de {$game-title} foram ignorados devido a conflitos
+game-patches-unsupported        = Não foi possível aplicar os patches de atualização de {$game-title}
 game-get-paths-failed           = Erro ao checar o diretório de instalação de {$game-title}
 game-get-integrity-failed       = Foi impossível conseguir dados de integridade de {$game-title}
 game-get-status-failed          = Não foi possível conseguir o status de {$game-title}
@@ -25,6 +38,11 @@ game-kill-failed                = Não foi possível matar o processo de {$game-
 
 launch-required-addon-unavailable = O complemento {$addon-title} do grupo {$group-title} está indisponível ou desatualizado. Você pode iniciar o jogo sem ele ou permanecer com a versão antiga.
 
+disk-space-not-enough         = Espaço em disco insuficiente
+disk-space-not-enough-message = {$path} requer {$required} de espaço livre, mas apenas {$available} está disponível
+disk-space-low                = Pouco espaço em disco
+disk-space-low-message        = Apenas {$remaining} de espaço livre restará em {$path} após a instalação
+
 loading-preparing-folders-failed     = Erro ao preparar os diretórios padrão
 loading-initializing-debug-failed    = Erro ao iniciar a saída de debugging
 loading-preparing-config-failed      = Erro ao preparar arquivo de configuração
@@ -1,9 +1,23 @@
 main-menu-launcher-folder = Pasta do launcher
 main-menu-config-file     = Arquivo de Configuração
 main-menu-debug-file      = Arquivo do Debug
+main-menu-update-all      = Atualizar tudo
+main-menu-verify-all      = Verificar todos os jogos
+main-menu-tasks-history   = Histórico de tarefas
 main-menu-about           = Sobre
 
 main-installed-games = Jogos instalados
 main-queued-games    = Jogos na fila
 main-outdated-games  = Jogos desatualizados
 main-available-games = Jogos disponíveis
+
+batch-update-all = Atualizar tudo
+batch-update-summary = Jogos: {$games}, complementos: {$addons}. Tamanho do download: {$size}
+batch-verify-all = Verificar todos os jogos
+batch-verify-summary = Jogos: {$games}. Tamanho dos arquivos: {$size}
+batch-queue = Adicionar à fila
+
+batch-nothing-to-update = Tudo está atualizado
+batch-nothing-to-verify = Nenhum jogo instalado para verificar
+
+auto-update-available = Novas atualizações estão disponíveis
@@ -7,6 +7,23 @@ general-launcher-language-description = É alterado após a reinicialização
 
 general-verify-games = Verificar as instalações dos jogos
 general-verify-games-description = Verifica a instalação do jogo após uma atualização ou instalação.
+general-stream-extraction = Extrair durante o download
+general-stream-extraction-description = Extrai arquivos tar e zip durante o download. Requer menos espaço livre em disco
+general-files-cache = Cache de arquivos
+general-files-cache-description = Mantém os arquivos baixados em um cache compartilhado e os reutiliza em outros jogos, edições e complementos
+general-max-concurrent-tasks = Tarefas simultâneas
+general-max-concurrent-tasks-description = Quantidade máxima de tarefas executadas ao mesmo tempo. Tarefas que usam os mesmos arquivos são sempre executadas uma por vez
+general-proxy = Proxy
+general-proxy-description = Proxy HTTP ou SOCKS usado pelo launcher e pelos scripts de integração dos jogos
+general-proxy-uri = Endereço do proxy, por exemplo http://127.0.0.1:8080
+general-proxy-bypass = Hosts com conexão direta, separados por vírgulas
+general-update-policy = Atualizações de jogos
+general-update-policy-description = O que fazer quando novas versões dos jogos e addons instalados forem encontradas. Pode ser alterado por jogo nos seus detalhes
+general-update-policy-never = Não verificar
+general-update-policy-notify = Notificar
+general-update-policy-download = Baixar automaticamente
+general-updates-check-interval = Intervalo de verificação de atualizações
+general-updates-check-interval-description = Tempo entre verificações de atualizações em segundo plano, em minutos
 
 preferences--wine = Wine
 
@@ -5,13 +5,19 @@ tasks-avg-speed = {$format ->
     *[data] Velocidade média: {$speed}/s
 }
 
-tasks-avg-eta = Tempo médio esperado: {$eta}
+tasks-speed = {$format ->
+    [items] Velocidade: {$speed} p/ segundo
+    *[data] Velocidade: {$speed}/s
+}
+
+tasks-eta = Tempo restante: {$eta}
 
 tasks-pending              = Na fila
 tasks-preparing-transition = Preparando transição...
 tasks-finishing-transition = Finalizando transição...
 tasks-downloading          = Baixando...
 tasks-unpacking            = Descompactando...
+tasks-applying-patches     = Aplicando patches...
 tasks-deleting-files       = Apagando arquivos...
 tasks-pre-transition-code  = Rodando tarefa de pré-transição do código...
 tasks-transition-code      = Rodando tarefa de transição do código...
@@ -24,3 +30,25 @@ tasks-repairing-files      = Reparando arquivos...
 tasks-finished             = Pronto
 
 tasks-delete-files = Apagar arquivos
+
+tasks-move-earlier = Iniciar antes
+tasks-move-later = Iniciar depois
+tasks-cancel = Cancelar
+
+tasks-history             = Histórico de tarefas
+tasks-history-empty       = Nenhuma tarefa foi executada ainda
+tasks-history-export      = Exportar
+tasks-history-clear       = Limpar
+tasks-history-finished    = Concluída
+tasks-history-failed      = Falhou
+tasks-history-cancelled   = Cancelada
+tasks-history-transferred = Transferido
+tasks-history-errors      = Erros
+
+verify-report = Relatório de verificação de {$game-title}
+verify-report-summary = Arquivos corrompidos: {$broken} de {$total}
+verify-report-missing = arquivo ausente
+verify-report-size-mismatch = tamanho é {$actual} em vez de {$expected}
+verify-report-hash-mismatch = hash {$hash-type} é {$actual} em vez de {$expected}
+verify-report-export = Exportar
+verify-report-repair = Reparar agora
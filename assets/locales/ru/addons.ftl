@@ -2,6 +2,18 @@ game-addons = Дополнения игры
 
 addon-install = Установить
 addon-uninstall = Удалить
+addon-verify = Проверить файлы дополнения
+addon-verify-all = Проверить все
 addon-required = Обязательный
 
 addon-unavailable = Дополнение недоступно
+
+addon-layers-order = Порядок загрузки слоёв
+addon-layers-order-description = Перетаскивайте слои, чтобы изменить их порядок. Файлы нижних слоёв заменяют файлы верхних
+addon-layer-overrides = Заменённых файлов: {$files}
+
+addon-profile = Профиль дополнений
+addon-profile-create = Новый профиль
+addon-profile-duplicate = Дублировать профиль
+addon-profile-delete = Удалить профиль
+addon-profile-name = Название профиля
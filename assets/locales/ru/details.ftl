@@ -5,5 +5,18 @@ details-last-played = Последний запуск: {$last-played}
 details-play = Играть
 details-kill = Убить
 details-verify = Проверить
+details-verify-quick-description = Проверить файлы, изменённые с последней проверки
+details-verify-full = Полная проверка
+details-verify-full-description = Проверить все файлы игры
+details-verify-dry-run = Найти повреждённые файлы
+details-verify-dry-run-description = Проверить все файлы игры без их восстановления
 details-manage-addons = Дополнения
+details-predownload = Предзагрузка
+details-predownloaded = Предзагружено
 details-download = Установить
+details-update-policy = Политика обновлений
+details-update-policy-default = Обновления по умолчанию
+details-update-policy-never = Не проверять обновления
+details-update-policy-notify = Уведомлять об обновлениях
+details-update-policy-download = Скачивать обновления
+details-addons-profile = Профиль дополнений
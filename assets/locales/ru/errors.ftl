@@ -2,20 +2,33 @@ config-property-update-failed = Не удалось обновить настр
 
 tasks-resolve-queued-failed = Не удалось запустить задачу из очереди
 tasks-get-status-failed     = Не удалось получить статус задачи {$game-title}
+tasks-history-read-failed   = Не удалось прочитать историю задач
+tasks-history-export-failed = Не удалось экспортировать историю задач
+tasks-history-clear-failed  = Не удалось очистить историю задач
+verify-report-export-failed = Не удалось экспортировать отчёт о проверке
 
 addon-verify-installation-failed = Не удалось проверить наличие дополнения для {$game-title}
 addon-find-diff-failed           = Не удалось найти обновление дополнения для {$game-title}
 addon-find-download-failed       = Не удалось найти информацию для установки дополнения для {$game-title}
 addon-find-path-failed           = Не удалось найти путь установки дополнения для {$game-title}
 addon-get-paths-failed           = Не удалось получить пути дополнения для {$game-title}
+addon-get-integrity-failed       = Не удалось получить информацию о целостности дополнения для {$game-title}
+addon-patches-unsupported        = Невозможно применить патчи обновления дополнения для {$game-title}
 addon-not-installed              = Дополнение для {$game-title} не установлено
+addon-enable-failed              = Не удалось включить дополнение
+addon-disable-failed             = Не удалось отключить дополнение
+addon-profile-name-invalid       = Название профиля "{$name}" пустое или уже используется
 
 game-verify-installation-failed = Не удалось проверить установку {$game-title}
 game-find-diff-failed           = Не удалось найти обновление {$game-title}
 game-find-download-failed       = Не удалось найти информацию для установки {$game-title}
+game-find-predownload-failed    = Не удалось найти информацию о предзагрузке {$game-title}
+game-predownload-unavailable    = Предзагрузка {$game-title} недоступна
 game-find-path-failed           = Не удалось найти путь установки {$game-title}
 game-get-settings-failed        = Не удалось получить настройки {$game-title}
 game-get-addons-failed          = Не удалось получить дополнения {$game-title}
+game-addons-conflicts           = Некоторые дополнения {$game-title} пропущены из-за конфликтов
+game-patches-unsupported        = Невозможно применить патчи обновления {$game-title}
 game-get-paths-failed           = Не удалось получить пути для {$game-title}
 game-get-integrity-failed       = Не удалось получить информацию для проверки файлов {$game-title}
 game-get-status-failed          = Не удалось получить статус {$game-title}
@@ -25,6 +38,11 @@ game-kill-failed                = Не удалось убить процесс
 
 launch-required-addon-unavailable = Дополнение {$addon-title} из группы {$group-title} недоступно или устарело. Вы можете запустить игру без него или продолжить использовать старую версию
 
+disk-space-not-enough         = Недостаточно места на диске
+disk-space-not-enough-message = Для {$path} требуется {$required} свободного места, но доступно только {$available}
+disk-space-low                = Мало места на диске
+disk-space-low-message        = После установки в {$path} останется только {$remaining} свободного места
+
 loading-preparing-folders-failed     = Не удалось подготовить папки по умолчанию
 loading-initializing-debug-failed    = Не удалось инициализировать отладочный вывод
 loading-preparing-config-failed      = Не удалось подготовить файл настроек
@@ -1,9 +1,23 @@
 main-menu-launcher-folder = Папка лаунчера
 main-menu-config-file     = Файл настроек
 main-menu-debug-file      = Файл отладки
+main-menu-update-all      = Обновить всё
+main-menu-verify-all      = Проверить все игры
+main-menu-tasks-history   = История задач
 main-menu-about           = О программе
 
 main-installed-games = Установленные игры
 main-queued-games    = Игры в очереди
 main-outdated-games  = Устаревшие игры
 main-available-games = Доступные игры
+
+batch-update-all = Обновить всё
+batch-update-summary = Игр: {$games}, дополнений: {$addons}. Размер загрузки: {$size}
+batch-verify-all = Проверить все игры
+batch-verify-summary = Игр: {$games}. Размер файлов: {$size}
+batch-queue = В очередь
+
+batch-nothing-to-update = Всё уже обновлено
+batch-nothing-to-verify = Нет установленных игр для проверки
+
+auto-update-available = Доступны новые обновления
@@ -7,6 +7,23 @@ general-launcher-language-description = Изменится после перез
 
 general-verify-games = Проверять игры
 general-verify-games-description = Проверять файлы игр после установки или обновлений
+general-stream-extraction = Распаковывать при загрузке
+general-stream-extraction-description = Распаковывать tar и zip архивы во время их загрузки. Требует меньше свободного места на диске
+general-files-cache = Кэш файлов
+general-files-cache-description = Хранить загруженные файлы в общем кэше и использовать их в других играх, изданиях и дополнениях
+general-max-concurrent-tasks = Одновременные задачи
+general-max-concurrent-tasks-description = Максимальное количество одновременно выполняемых задач. Задачи, использующие одни и те же файлы, всегда выполняются по очереди
+general-proxy = Прокси
+general-proxy-description = HTTP или SOCKS прокси, используемый лаунчером и скриптами интеграций игр
+general-proxy-uri = Адрес прокси, например http://127.0.0.1:8080
+general-proxy-bypass = Хосты для прямого подключения через запятую
+general-update-policy = Обновления игр
+general-update-policy-description = Что делать при обнаружении новых версий установленных игр и дополнений. Можно изменить для каждой игры в её описании
+general-update-policy-never = Не проверять
+general-update-policy-notify = Уведомлять
+general-update-policy-download = Скачивать автоматически
+general-updates-check-interval = Интервал проверки обновлений
+general-updates-check-interval-description = Время между фоновыми проверками обновлений, в минутах
 
 preferences--wine = Wine
 
@@ -1,13 +1,20 @@
 tasks-nothing = Нет задач
 
 tasks-avg-speed = Средняя скорость: {$speed}/с
-tasks-avg-eta = Времени осталось: {$eta}
+
+tasks-speed = {$format ->
+    [items] Скорость: {$speed} шт/с
+    *[data] Скорость: {$speed}/с
+}
+
+tasks-eta = Осталось: {$eta}
 
 tasks-pending              = Ожидается
 tasks-preparing-transition = Подготовка перехода...
 tasks-finishing-transition = Завершение перехода...
 tasks-downloading          = Загрузка...
 tasks-unpacking            = Распаковка...
+tasks-applying-patches     = Применение патчей...
 tasks-deleting-files       = Удаление файлов...
 tasks-pre-transition-code  = Выполнение обработчика перед переходом...
 tasks-transition-code      = Выполнение обработчика перехода...
@@ -20,3 +27,25 @@ tasks-repairing-files      = Восстановление файлов...
 tasks-finished             = Завершена
 
 tasks-delete-files = Удаление файлов
+
+tasks-move-earlier = Начать раньше
+tasks-move-later = Начать позже
+tasks-cancel = Отменить
+
+tasks-history             = История задач
+tasks-history-empty       = Задачи ещё не запускались
+tasks-history-export      = Экспортировать
+tasks-history-clear       = Очистить
+tasks-history-finished    = Завершена
+tasks-history-failed      = Ошибка
+tasks-history-cancelled   = Отменена
+tasks-history-transferred = Передано
+tasks-history-errors      = Ошибки
+
+verify-report = Отчёт о проверке {$game-title}
+verify-report-summary = Повреждённых файлов: {$broken} из {$total}
+verify-report-missing = файл отсутствует
+verify-report-size-mismatch = размер {$actual} вместо {$expected}
+verify-report-hash-mismatch = хеш {$hash-type} {$actual} вместо {$expected}
+verify-report-export = Экспортировать
+verify-report-repair = Восстановить
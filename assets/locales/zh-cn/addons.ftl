@@ -2,6 +2,18 @@ game-addons = 语言文件
 
 addon-install = 安装
 addon-uninstall = 卸载
+addon-verify = 验证语言文件
+addon-verify-all = 全部验证
 addon-required = 需要
 
 addon-unavailable = 语言文件不可用
+
+addon-layers-order = 图层加载顺序
+addon-layers-order-description = 拖动图层以更改顺序。下方图层的文件会替换上方图层的文件
+addon-layer-overrides = 覆盖的文件：{$files}
+
+addon-profile = 附加组件配置
+addon-profile-create = 新建配置
+addon-profile-duplicate = 复制配置
+addon-profile-delete = 删除配置
+addon-profile-name = 配置名称
@@ -5,5 +5,18 @@ details-last-played = 上次游玩: {$last-played}
 details-play = 启动
 details-kill = 杀死进程
 details-verify = 验证
+details-verify-quick-description = 验证自上次验证以来更改的文件
+details-verify-full = 完整验证
+details-verify-full-description = 验证所有游戏文件
+details-verify-dry-run = 查找损坏的文件
+details-verify-dry-run-description = 验证所有游戏文件但不修复
 details-manage-addons = 管理拓展
+details-predownload = 预下载
+details-predownloaded = 已预下载
 details-download = 下载
+details-update-policy = 更新策略
+details-update-policy-default = 默认更新策略
+details-update-policy-never = 不检查更新
+details-update-policy-notify = 通知更新
+details-update-policy-download = 下载更新
+details-addons-profile = 附加组件配置
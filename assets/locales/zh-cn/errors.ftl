@@ -2,20 +2,33 @@ config-property-update-error = 更新属性失败
 
 tasks-resolve-queued-failed = 无法解决正在进行中的任务
 tasks-get-status-failed = 无法获取 {$game-title} 任务状态
+tasks-history-read-failed = 无法读取任务历史
+tasks-history-export-failed = 无法导出任务历史
+tasks-history-clear-failed = 无法清空任务历史
+verify-report-export-failed = 导出验证报告失败
 
 addon-verify-installation-failed = 无法验证 {$game-title} 的语言文件
 addon-find-diff-failed           = 无法找到 {$game-title} addon version diff
 addon-find-download-failed       = 无法找到 {$game-title} addon download info
 addon-find-path-failed           = 无法找到 {$game-title} addon installation path
 addon-get-paths-failed           = 无法获取 {$game-title} addon paths
+addon-get-integrity-failed       = 无法获取 {$game-title} addon integrity info
+addon-patches-unsupported        = 无法应用 {$game-title} 附加组件的更新补丁
 addon-not-installed              = {$game-title} 语言文件未安装
+addon-enable-failed              = 无法启用附加组件
+addon-disable-failed             = 无法禁用附加组件
+addon-profile-name-invalid       = 配置名称 "{$name}" 为空或已被使用
 
 game-verify-installation-failed = 无法验证 {$game-title} 的安装
 game-find-diff-failed           = 无法找到 {$game-title} version diff
 game-find-download-failed       = 无法找到 {$game-title} 的下载详情
+game-find-predownload-failed    = 无法找到 {$game-title} 的预下载信息
+game-predownload-unavailable    = {$game-title} 的预下载不可用
 game-find-path-failed           = 无法找到 {$game-title} 的安装路径
 game-get-settings-failed        = 无法获取 {$game-title} 的设置
 game-get-addons-failed          = 无法获取 {$game-title} 的语言文件
+game-addons-conflicts           = {$game-title} 的部分语言文件因冲突被跳过
+game-patches-unsupported        = 无法应用 {$game-title} 的更新补丁
 game-get-paths-failed           = 无法获取 {$game-title} 的路径
 game-get-integrity-failed       = 无法获取 {$game-title} integrity info
 game-get-status-failed          = Unable to get {$game-title} status
@@ -25,6 +38,11 @@ game-kill-failed                = Failed to kill {$game-title}
 
 launch-required-addon-unavailable = Addon {$addon-title} from group {$group-title} is unavailable or outdated. You can launch the game without it or continue to use old version
 
+disk-space-not-enough         = 磁盘空间不足
+disk-space-not-enough-message = {$path} 需要 {$required} 可用空间，但只有 {$available} 可用
+disk-space-low                = 磁盘空间不足
+disk-space-low-message        = 安装后 {$path} 中仅剩 {$remaining} 可用空间
+
 loading-preparing-folders-failed     = Failed to prepare default folders
 loading-initializing-debug-failed    = Failed to initialize debug output
 loading-preparing-config-failed      = Failed to prepare config file
@@ -1,9 +1,23 @@
 main-menu-launcher-folder = 启动器路径
 main-menu-config-file     = 配置文件
 main-menu-debug-file      = Debug 文件
+main-menu-update-all      = 全部更新
+main-menu-verify-all      = 验证所有游戏
+main-menu-tasks-history   = 任务历史
 main-menu-about           = 关于
 
 main-installed-games = Installed games
 main-queued-games    = Queued games
 main-outdated-games  = Outdated games
 main-available-games = Available games
+
+batch-update-all = 全部更新
+batch-update-summary = 游戏：{$games}，语言文件：{$addons}。下载大小：{$size}
+batch-verify-all = 验证所有游戏
+batch-verify-summary = 游戏：{$games}。文件大小：{$size}
+batch-queue = 加入队列
+
+batch-nothing-to-update = 全部已是最新
+batch-nothing-to-verify = 没有可验证的已安装游戏
+
+auto-update-available = 有新的更新可用
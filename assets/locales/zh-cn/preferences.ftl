@@ -7,6 +7,23 @@ general-launcher-language-description = 将在下次重启后使用
 
 general-verify-games = 验证游戏文件
 general-verify-games-description = 安装或更新后验证游戏文件
+general-stream-extraction = 边下载边解压
+general-stream-extraction-description = 在下载 tar 和 zip 压缩包的同时解压。需要更少的磁盘空间
+general-files-cache = 文件缓存
+general-files-cache-description = 将下载的文件保存在共享缓存中，并在其他游戏、版本和附加组件中复用
+general-max-concurrent-tasks = 并发任务
+general-max-concurrent-tasks-description = 同时运行的最大任务数。使用相同文件的任务总是依次运行
+general-proxy = 代理
+general-proxy-description = 启动器和游戏集成脚本使用的 HTTP 或 SOCKS 代理
+general-proxy-uri = 代理地址，例如 http://127.0.0.1:8080
+general-proxy-bypass = 直接连接的主机，用逗号分隔
+general-update-policy = 游戏更新
+general-update-policy-description = 发现已安装游戏和附加组件的新版本时的操作。可以在游戏详情中为每个游戏单独设置
+general-update-policy-never = 不检查
+general-update-policy-notify = 通知
+general-update-policy-download = 自动下载
+general-updates-check-interval = 更新检查间隔
+general-updates-check-interval-description = 后台检查更新的间隔时间，单位为分钟
 
 preferences--wine = Wine
 
@@ -5,13 +5,19 @@ tasks-avg-speed = {$format ->
     *[data] 平均速度: {$speed}/s
 }
 
-tasks-avg-eta = 剩余时间: {$eta}
+tasks-speed = {$format ->
+    [items] 速度: {$speed} p/s
+    *[data] 速度: {$speed}/s
+}
+
+tasks-eta = 剩余: {$eta}
 
 tasks-pending              = 待处理
 tasks-preparing-transition = 准备转换...
 tasks-finishing-transition = 转换成功
 tasks-downloading          = 正在下载...
 tasks-unpacking            = 正在解压...
+tasks-applying-patches     = 正在应用补丁...
 tasks-deleting-files       = 正在删除文件...
 tasks-pre-transition-code  = Running task pre-transition code...
 tasks-transition-code      = 运行任务转换代码...
@@ -24,3 +30,25 @@ tasks-repairing-files      = 正在修复文件...
 tasks-finished             = 完成
 
 tasks-delete-files = Delete files
+
+tasks-move-earlier = 提前开始
+tasks-move-later = 推迟开始
+tasks-cancel = 取消
+
+tasks-history             = 任务历史
+tasks-history-empty       = 尚未运行任何任务
+tasks-history-export      = 导出
+tasks-history-clear       = 清空
+tasks-history-finished    = 已完成
+tasks-history-failed      = 失败
+tasks-history-cancelled   = 已取消
+tasks-history-transferred = 已传输
+tasks-history-errors      = 错误
+
+verify-report = {$game-title} 验证报告
+verify-report-summary = 损坏的文件：{$broken} / {$total}
+verify-report-missing = 文件缺失
+verify-report-size-mismatch = 大小为 {$actual}，应为 {$expected}
+verify-report-hash-mismatch = {$hash-type} 哈希为 {$actual}，应为 {$expected}
+verify-report-export = 导出
+verify-report-repair = 立即修复
@@ -0,0 +1,158 @@
+use std::path::{Path, PathBuf};
+use std::fs::File;
+use std::os::fd::AsRawFd;
+use std::sync::atomic::{AtomicU64, Ordering};
+
+use crate::config::general::prelude::Cache;
+
+use crate::games::integrations::driver::Driver;
+use crate::games::integrations::standards::IntegrationStandard;
+use crate::games::integrations::standards::integrity::FileHash;
+
+/// `ioctl` request which makes copy-on-write clone of the file
+const FICLONE: libc::c_ulong = 0x40049409;
+
+/// Counter used to make unique names of temporary files
+static TEMP_COUNTER: AtomicU64 = AtomicU64::new(0);
+
+/// Make copy-on-write clone of the source file
+///
+/// Works only if both files are on the same filesystem which supports it (btrfs, xfs, ...)
+fn reflink(source: &Path, target: &Path) -> std::io::Result<()> {
+    let source = File::open(source)?;
+    let target_file = File::create(target)?;
+
+    let result = unsafe {
+        libc::ioctl(target_file.as_raw_fd(), FICLONE as _, source.as_raw_fd())
+    };
+
+    if result != 0 {
+        let err = std::io::Error::last_os_error();
+
+        drop(target_file);
+
+        std::fs::remove_file(target)?;
+
+        return Err(err);
+    }
+
+    Ok(())
+}
+
+/// Link source file to the target path
+///
+/// Tries to reflink the file, then to hardlink it
+/// and copies it if both methods have failed
+pub fn link_file(source: impl AsRef<Path>, target: impl AsRef<Path>) -> anyhow::Result<()> {
+    let source = source.as_ref();
+    let target = target.as_ref();
+
+    if let Some(parent) = target.parent() {
+        if !parent.exists() {
+            std::fs::create_dir_all(parent)?;
+        }
+    }
+
+    // Remove the file instead of overwriting it
+    // so we don't write through hard links
+    if target.symlink_metadata().is_ok() {
+        std::fs::remove_file(target)?;
+    }
+
+    if reflink(source, target).is_ok() || std::fs::hard_link(source, target).is_ok() {
+        return Ok(());
+    }
+
+    std::fs::copy(source, target)?;
+
+    Ok(())
+}
+
+/// Replace characters which can't be used in file names
+fn escape(value: &str) -> String {
+    value.chars()
+        .map(|c| if c.is_ascii_alphanumeric() || c == '-' || c == '_' { c } else { '_' })
+        .collect()
+}
+
+/// Content-addressed cache of downloaded files
+///
+/// Files are identified by their hash and size so identical files
+/// of different games, editions and addons are downloaded only once
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct FilesCache {
+    path: PathBuf
+}
+
+impl FilesCache {
+    #[inline]
+    pub fn new(path: impl Into<PathBuf>) -> Self {
+        Self {
+            path: path.into()
+        }
+    }
+
+    #[inline]
+    /// Get files cache from the config
+    ///
+    /// Returns `None` if the cache is disabled
+    pub fn from_config(config: &Cache) -> Option<Self> {
+        config.enabled.then(|| Self::new(&config.path))
+    }
+
+    /// Get path to the cached file with given hash and size
+    pub fn get_path(&self, hash: &FileHash, size: u64) -> PathBuf {
+        self.path
+            .join(escape(hash.r#type.to_str(IntegrationStandard::V1)))
+            .join(format!("{}-{size}", escape(&hash.value)))
+    }
+
+    /// Link cached file to the given path if it's available
+    ///
+    /// Cached file is verified before using it because it could be
+    /// changed through a hard link. Broken files are removed from the cache.
+    ///
+    /// Returns `true` if the file was taken from the cache
+    pub fn get(&self, hash: &FileHash, size: u64, path: impl AsRef<Path>, driver: &Driver) -> anyhow::Result<bool> {
+        let cached_path = self.get_path(hash, size);
+
+        if !cached_path.is_file() {
+            return Ok(false);
+        }
+
+        if cached_path.metadata()?.len() != size || !hash.verify_file(&cached_path, driver)? {
+            tracing::warn!("Cached file {cached_path:?} is broken, removing it");
+
+            std::fs::remove_file(cached_path)?;
+
+            return Ok(false);
+        }
+
+        link_file(cached_path, path)?;
+
+        Ok(true)
+    }
+
+    /// Put verified file to the cache
+    pub fn put(&self, hash: &FileHash, size: u64, path: impl AsRef<Path>) -> anyhow::Result<()> {
+        let cached_path = self.get_path(hash, size);
+
+        if cached_path.exists() {
+            return Ok(());
+        }
+
+        // Link the file under a temporary name first
+        // so other threads never see partially copied files
+        let temp_path = cached_path.with_extension(format!(
+            "tmp-{}-{}",
+            std::process::id(),
+            TEMP_COUNTER.fetch_add(1, Ordering::Relaxed)
+        ));
+
+        link_file(path, &temp_path)?;
+
+        std::fs::rename(temp_path, cached_path)?;
+
+        Ok(())
+    }
+}
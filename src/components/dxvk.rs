@@ -1,30 +1,27 @@
-use std::cell::Cell;
 use std::path::PathBuf;
 
 use serde_json::Value as Json;
 
-use anime_game_core::network::minreq;
 use anime_game_core::archive;
-
-use anime_game_core::network::downloader::DownloaderExt;
-use anime_game_core::network::downloader::basic::Downloader;
-
 use anime_game_core::updater::UpdaterExt;
 
 use crate::{
     config,
+    network,
     COMPONENTS_FOLDER
 };
 
-use crate::components::{
-    Updater,
-    Status
-};
+use crate::network::downloader::Downloader;
 
 use crate::ui::components::game_card::CardInfo;
-use crate::ui::components::tasks_queue::{QueuedTask, ResolvedTask};
+use crate::ui::components::tasks_queue::{
+    QueuedTask,
+    ResolvedTask,
+    TaskPriority,
+    TaskDescriptor
+};
 
-use super::DownloadComponentResolvedTask;
+use crate::ui::components::tasks_queue::pipeline::Pipeline;
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct Dxvk {
@@ -39,7 +36,7 @@ impl Dxvk {
     pub fn versions() -> anyhow::Result<Vec<Self>> {
         let components = config::get().components;
 
-        let dxvk_versions = minreq::get(format!("{}/dxvk/{}.json", &components.channel, &components.dxvk.build))
+        let dxvk_versions = network::get(format!("{}/dxvk/{}.json", &components.channel, &components.dxvk.build))
             .send()?.json::<Vec<Json>>()?;
 
         let mut versions = Vec::new();
@@ -90,65 +87,43 @@ impl Dxvk {
         self.get_folder().exists()
     }
 
-    /// Download component
-    pub fn download(&self) -> anyhow::Result<Updater> {
-        let (sender, receiver) = flume::unbounded();
+    /// Get pipeline which downloads and unpacks the component
+    pub fn download(&self, card_info: CardInfo) -> Pipeline {
+        let path = COMPONENTS_FOLDER.join("dxvk");
+        let archive = path.join(network::get_file_name(&self.uri));
 
         let download_uri = self.uri.clone();
 
-        Ok(Updater {
-            status: Cell::new(Status::Downloading),
-            current: Cell::new(0),
-            total: Cell::new(1), // To prevent division by 0
-
-            worker_result: None,
-            updater: receiver,
-
-            worker: Some(std::thread::spawn(move || -> anyhow::Result<()> {
-                let downloader = Downloader::new(download_uri);
+        Pipeline::new(card_info)
+            .download_step("tasks-downloading", {
+                let path = path.clone();
+                let archive = archive.clone();
 
-                let path = COMPONENTS_FOLDER.join("dxvk");
-                let archive = path.join(downloader.file_name());
+                move |context| {
+                    // Create dxvk dir if needed
 
-                // Create dxvk dir if needed
+                    std::fs::create_dir_all(&path)?;
 
-                std::fs::create_dir_all(&path)?;
+                    // Download update archive
 
-                // Download update archive
-
-                let mut updater = downloader.download(&archive)?;
-
-                while !updater.is_finished() {
-                    sender.send((
-                        Status::Downloading,
-                        updater.current(),
-                        updater.total()
-                    ))?;
+                    Downloader::new(download_uri).download(&archive, |current, total| {
+                        context.set_progress(current, total)
+                    })
                 }
-
-                // Extract archive
-
+            })
+            .step("tasks-unpacking", move |context| {
                 let Some(mut updater) = archive::extract(&archive, &path) else {
                     anyhow::bail!("Unable to extract archive: {:?}", archive);
                 };
 
                 while let Ok(false) = updater.status() {
-                    sender.send((
-                        Status::Unpacking,
-                        updater.current(),
-                        updater.total()
-                    ))?;
+                    context.set_progress(updater.current(), updater.total())?;
                 }
 
                 std::fs::remove_file(archive)?;
 
-                // Finish downloading
-
-                sender.send((Status::Finished, 1, 1))?;
-
                 Ok(())
-            }))
-        })
+            })
     }
 }
 
@@ -164,10 +139,24 @@ impl QueuedTask for DownloadDxvkQueuedTask {
         self.card_info.clone()
     }
 
+    #[inline]
+    fn get_paths(&self) -> Vec<PathBuf> {
+        vec![self.version.get_folder()]
+    }
+
+    #[inline]
+    fn get_priority(&self) -> TaskPriority {
+        TaskPriority::Component
+    }
+
+    #[inline]
+    fn get_descriptor(&self) -> TaskDescriptor {
+        TaskDescriptor::DownloadDxvk {
+            name: self.version.name.clone()
+        }
+    }
+
     fn resolve(self: Box<Self>) -> anyhow::Result<Box<dyn ResolvedTask>> {
-        Ok(Box::new(DownloadComponentResolvedTask {
-            card_info: self.card_info,
-            updater: self.version.download()?
-        }))
+        Ok(Box::new(self.version.download(self.card_info).spawn()))
     }
 }
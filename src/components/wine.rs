@@ -1,4 +1,3 @@
-use std::cell::Cell;
 use std::path::PathBuf;
 
 use serde_json::Value as Json;
@@ -11,28 +10,26 @@ use wincompatlib::wine::{
     WineLoader as WincompatlibWineLoader
 };
 
-use anime_game_core::network::minreq;
 use anime_game_core::archive;
-
-use anime_game_core::network::downloader::DownloaderExt;
-use anime_game_core::network::downloader::basic::Downloader;
-
 use anime_game_core::updater::UpdaterExt;
 
 use crate::ui::components::game_card::CardInfo;
-use crate::ui::components::tasks_queue::{QueuedTask, ResolvedTask};
+use crate::ui::components::tasks_queue::{
+    QueuedTask,
+    ResolvedTask,
+    TaskPriority,
+    TaskDescriptor
+};
+
+use crate::ui::components::tasks_queue::pipeline::Pipeline;
 
 use crate::{
     config,
+    network,
     COMPONENTS_FOLDER
 };
 
-use crate::components::{
-    Updater,
-    Status
-};
-
-use super::DownloadComponentResolvedTask;
+use crate::network::downloader::Downloader;
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct Wine {
@@ -46,7 +43,7 @@ impl Wine {
     pub fn versions() -> anyhow::Result<Vec<Self>> {
         let components = config::get().components;
 
-        let wine_versions = minreq::get(format!("{}/wine/{}.json", &components.channel, &components.wine.build))
+        let wine_versions = network::get(format!("{}/wine/{}.json", &components.channel, &components.wine.build))
             .send()?.json::<Vec<Json>>()?;
 
         let mut versions = Vec::new();
@@ -101,65 +98,43 @@ impl Wine {
         self.get_folder().exists()
     }
 
-    /// Download component
-    pub fn download(&self) -> anyhow::Result<Updater> {
-        let (sender, receiver) = flume::unbounded();
+    /// Get pipeline which downloads and unpacks the component
+    pub fn download(&self, card_info: CardInfo) -> Pipeline {
+        let path = COMPONENTS_FOLDER.join("wine");
+        let archive = path.join(network::get_file_name(&self.uri));
 
         let download_uri = self.uri.clone();
 
-        Ok(Updater {
-            status: Cell::new(Status::Downloading),
-            current: Cell::new(0),
-            total: Cell::new(1), // To prevent division by 0
-
-            worker_result: None,
-            updater: receiver,
+        Pipeline::new(card_info)
+            .download_step("tasks-downloading", {
+                let path = path.clone();
+                let archive = archive.clone();
 
-            worker: Some(std::thread::spawn(move || -> anyhow::Result<()> {
-                let downloader = Downloader::new(download_uri);
+                move |context| {
+                    // Create wine dir if needed
 
-                let path = COMPONENTS_FOLDER.join("wine");
-                let archive = path.join(downloader.file_name());
+                    std::fs::create_dir_all(&path)?;
 
-                // Create wine dir if needed
+                    // Download update archive
 
-                std::fs::create_dir_all(&path)?;
-
-                // Download update archive
-
-                let mut updater = downloader.download(&archive)?;
-
-                while !updater.is_finished() {
-                    sender.send((
-                        Status::Downloading,
-                        updater.current(),
-                        updater.total()
-                    ))?;
+                    Downloader::new(download_uri).download(&archive, |current, total| {
+                        context.set_progress(current, total)
+                    })
                 }
-
-                // Extract archive
-
+            })
+            .step("tasks-unpacking", move |context| {
                 let Some(mut updater) = archive::extract(&archive, &path) else {
                     anyhow::bail!("Unable to extract archive: {:?}", archive);
                 };
 
                 while !updater.is_finished() {
-                    sender.send((
-                        Status::Unpacking,
-                        updater.current(),
-                        updater.total()
-                    ))?;
+                    context.set_progress(updater.current(), updater.total())?;
                 }
 
                 std::fs::remove_file(archive)?;
 
-                // Finish downloading
-
-                sender.send((Status::Finished, 1, 1))?;
-
                 Ok(())
-            }))
-        })
+            })
     }
 
     /// Get wincompatlib descriptor of the current wine version
@@ -189,10 +164,24 @@ impl QueuedTask for DownloadWineQueuedTask {
         self.card_info.clone()
     }
 
+    #[inline]
+    fn get_paths(&self) -> Vec<PathBuf> {
+        vec![self.version.get_folder()]
+    }
+
+    #[inline]
+    fn get_priority(&self) -> TaskPriority {
+        TaskPriority::Component
+    }
+
+    #[inline]
+    fn get_descriptor(&self) -> TaskDescriptor {
+        TaskDescriptor::DownloadWine {
+            name: self.version.name.clone()
+        }
+    }
+
     fn resolve(self: Box<Self>) -> anyhow::Result<Box<dyn ResolvedTask>> {
-        Ok(Box::new(DownloadComponentResolvedTask {
-            card_info: self.card_info,
-            updater: self.version.download()?
-        }))
+        Ok(Box::new(self.version.download(self.card_info).spawn()))
     }
 }
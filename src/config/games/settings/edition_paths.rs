@@ -9,7 +9,8 @@ use crate::LAUNCHER_FOLDER;
 pub struct GameEditionPaths {
     pub game: PathBuf,
     pub addons: PathBuf,
-    pub deployment: PathBuf
+    pub deployment: PathBuf,
+    pub predownload: PathBuf
 }
 
 impl GameEditionPaths {
@@ -31,7 +32,13 @@ impl GameEditionPaths {
                 .join("games")
                 .join(game_name.as_ref())
                 .join(edition_name.as_ref())
-                .join("deployment")
+                .join("deployment"),
+
+            predownload: LAUNCHER_FOLDER
+                .join("games")
+                .join(game_name.as_ref())
+                .join(edition_name.as_ref())
+                .join("predownload")
         })
     }
 
@@ -52,7 +59,12 @@ impl GameEditionPaths {
             deployment: value.get("deployment")
                 .and_then(Json::as_str)
                 .map(PathBuf::from)
-                .unwrap_or(default.deployment)
+                .unwrap_or(default.deployment),
+
+            predownload: value.get("predownload")
+                .and_then(Json::as_str)
+                .map(PathBuf::from)
+                .unwrap_or(default.predownload)
         })
     }
 }
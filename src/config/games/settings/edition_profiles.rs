@@ -0,0 +1,97 @@
+use std::collections::HashMap;
+
+use serde::{Serialize, Deserialize};
+use serde_json::Value as Json;
+
+use super::edition_addons::GameEditionAddon;
+
+/// Name of the profile created by default
+pub const DEFAULT_PROFILE: &str = "default";
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct GameEditionProfiles {
+    /// Name of the profile used to launch the game
+    pub active: String,
+
+    /// Enabled addons of the named profiles
+    pub profiles: HashMap<String, Vec<GameEditionAddon>>
+}
+
+impl Default for GameEditionProfiles {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            active: String::from(DEFAULT_PROFILE),
+            profiles: HashMap::from([
+                (String::from(DEFAULT_PROFILE), vec![])
+            ])
+        }
+    }
+}
+
+impl From<&Json> for GameEditionProfiles {
+    fn from(value: &Json) -> Self {
+        let mut default = Self::default();
+
+        // Enabled addons list used before profiles were introduced
+        if let Some(addons) = value.as_array() {
+            let addons = addons.iter()
+                .map(GameEditionAddon::from)
+                .collect();
+
+            default.profiles.insert(String::from(DEFAULT_PROFILE), addons);
+
+            return default;
+        }
+
+        if let Some(profiles) = value.get("profiles").and_then(Json::as_object) {
+            let profiles = profiles.iter()
+                .filter_map(|(name, addons)| {
+                    let addons = addons.as_array()?
+                        .iter()
+                        .map(GameEditionAddon::from)
+                        .collect();
+
+                    Some((name.clone(), addons))
+                })
+                .collect::<HashMap<_, _>>();
+
+            if !profiles.is_empty() {
+                default.profiles = profiles;
+            }
+        }
+
+        let active = value.get("active")
+            .and_then(Json::as_str)
+            .filter(|active| default.profiles.contains_key(*active));
+
+        default.active = match active {
+            Some(active) => active.to_string(),
+            None => default.get_names()[0].clone()
+        };
+
+        default
+    }
+}
+
+impl GameEditionProfiles {
+    #[inline]
+    /// Get enabled addons of the active profile
+    pub fn get_active(&self) -> &[GameEditionAddon] {
+        self.profiles.get(&self.active)
+            .map(Vec::as_slice)
+            .unwrap_or_default()
+    }
+
+    #[inline]
+    /// Get sorted profiles names
+    pub fn get_names(&self) -> Vec<String> {
+        let mut names = self.profiles.keys()
+            .cloned()
+            .collect::<Vec<_>>();
+
+        names.sort();
+
+        names
+    }
+}
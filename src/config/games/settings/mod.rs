@@ -3,12 +3,16 @@ use std::collections::HashMap;
 use serde::{Serialize, Deserialize};
 use serde_json::Value as Json;
 
+use crate::config::general::updates::UpdatePolicy;
+
 pub mod edition_addons;
 pub mod edition_paths;
+pub mod edition_profiles;
 
 pub mod prelude {
     pub use super::edition_addons::GameEditionAddon;
     pub use super::edition_paths::GameEditionPaths;
+    pub use super::edition_profiles::GameEditionProfiles;
     pub use super::GameSettings;
 }
 
@@ -17,7 +21,13 @@ use prelude::*;
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct GameSettings {
     pub paths: HashMap<String, GameEditionPaths>,
-    pub addons: HashMap<String, Vec<GameEditionAddon>>
+    pub addons: HashMap<String, GameEditionProfiles>,
+
+    /// Load order of the layer addons. Later layers override files of the earlier ones
+    pub layers_order: HashMap<String, Vec<GameEditionAddon>>,
+
+    /// Game's own updates policy. Global one is used if not set
+    pub update_policy: Option<UpdatePolicy>
 }
 
 impl GameSettings {
@@ -34,9 +44,17 @@ impl GameSettings {
                 }).collect(),
 
             addons: edition_names
+                .clone()
+                .into_iter()
+                .map(|edition| (edition.as_ref().to_string(), GameEditionProfiles::default()))
+                .collect::<HashMap<_, _>>(),
+
+            layers_order: edition_names
                 .into_iter()
                 .map(|edition| (edition.as_ref().to_string(), vec![]))
                 .collect::<HashMap<_, _>>(),
+
+            update_policy: None
         })
     }
 
@@ -52,20 +70,32 @@ impl GameSettings {
         }
 
         if let Some(values) = value.get("addons").and_then(Json::as_object) {
-            for (edition, names) in values.clone() {
-                if let Some(names) = names.as_array() {
-                    let names = names.iter()
+            for (edition, profiles) in values {
+                default.addons.insert(edition.clone(), GameEditionProfiles::from(profiles));
+            }
+        }
+
+        if let Some(values) = value.get("layers_order").and_then(Json::as_object) {
+            for (edition, layers) in values.clone() {
+                if let Some(layers) = layers.as_array() {
+                    let layers = layers.iter()
                         .map(GameEditionAddon::from)
                         .collect();
 
-                    default.addons.insert(edition, names);
+                    default.layers_order.insert(edition, layers);
                 }
             }
         }
 
+        let update_policy = value.get("update_policy")
+            .filter(|value| !value.is_null())
+            .map(UpdatePolicy::from);
+
         Ok(Self {
             paths: default.paths,
-            addons: default.addons
+            addons: default.addons,
+            layers_order: default.layers_order,
+            update_policy
         })
     }
 }
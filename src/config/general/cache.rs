@@ -0,0 +1,43 @@
+use std::path::PathBuf;
+
+use serde::{Serialize, Deserialize};
+use serde_json::Value as Json;
+
+use crate::LAUNCHER_FOLDER;
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Cache {
+    /// Store downloaded files with known hashes in the cache
+    /// and reuse them in other games, editions and addons
+    pub enabled: bool,
+
+    pub path: PathBuf
+}
+
+impl Default for Cache {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            path: LAUNCHER_FOLDER.join("cache")
+        }
+    }
+}
+
+impl From<&Json> for Cache {
+    #[inline]
+    fn from(value: &Json) -> Self {
+        let default = Self::default();
+
+        Self {
+            enabled: value.get("enabled")
+                .and_then(Json::as_bool)
+                .unwrap_or(default.enabled),
+
+            path: value.get("path")
+                .and_then(Json::as_str)
+                .map(PathBuf::from)
+                .unwrap_or(default.path)
+        }
+    }
+}
@@ -5,10 +5,19 @@ use crate::i18n;
 
 pub mod transitions;
 pub mod threads;
+pub mod tasks;
+pub mod network;
+pub mod cache;
+pub mod updates;
 
 pub mod prelude {
     pub use super::transitions::Transitions;
     pub use super::threads::Threads;
+    pub use super::tasks::Tasks;
+    pub use super::network::Network;
+    pub use super::network::proxy::Proxy;
+    pub use super::cache::Cache;
+    pub use super::updates::{Updates, UpdatePolicy};
     pub use super::General;
 }
 
@@ -18,8 +27,15 @@ use prelude::*;
 pub struct General {
     pub transitions: Transitions,
     pub threads: Threads,
+    pub tasks: Tasks,
+    pub network: Network,
+    pub cache: Cache,
+    pub updates: Updates,
     pub language: String,
-    pub verify_games: bool
+    pub verify_games: bool,
+
+    /// Extract supported archives while downloading them
+    pub stream_extraction: bool
 }
 
 impl Default for General {
@@ -28,8 +44,13 @@ impl Default for General {
         Self {
             transitions: Transitions::default(),
             threads: Threads::default(),
+            tasks: Tasks::default(),
+            network: Network::default(),
+            cache: Cache::default(),
+            updates: Updates::default(),
             language: i18n::format_language(&i18n::get_default_language()),
-            verify_games: true
+            verify_games: true,
+            stream_extraction: true
         }
     }
 }
@@ -48,6 +69,22 @@ impl From<&Json> for General {
                 .map(Threads::from)
                 .unwrap_or(default.threads),
 
+            tasks: value.get("tasks")
+                .map(Tasks::from)
+                .unwrap_or(default.tasks),
+
+            network: value.get("network")
+                .map(Network::from)
+                .unwrap_or(default.network),
+
+            cache: value.get("cache")
+                .map(Cache::from)
+                .unwrap_or(default.cache),
+
+            updates: value.get("updates")
+                .map(Updates::from)
+                .unwrap_or(default.updates),
+
             language: value.get("language")
                 .and_then(Json::as_str)
                 .map(String::from)
@@ -55,7 +92,11 @@ impl From<&Json> for General {
 
             verify_games: value.get("verify_games")
                 .and_then(Json::as_bool)
-                .unwrap_or(default.verify_games)
+                .unwrap_or(default.verify_games),
+
+            stream_extraction: value.get("stream_extraction")
+                .and_then(Json::as_bool)
+                .unwrap_or(default.stream_extraction)
         }
     }
 }
@@ -0,0 +1,62 @@
+use std::path::PathBuf;
+
+use serde::{Serialize, Deserialize};
+use serde_json::Value as Json;
+
+pub mod proxy;
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Network {
+    /// Minimal download speed, in bytes per second.
+    /// If a mirror is slower than that the next one will be used
+    pub min_speed: u64,
+
+    /// Time during which the download speed is measured, in seconds
+    pub min_speed_timeout: u64,
+
+    /// Proxy used by all the launcher and integration scripts requests
+    pub proxy: proxy::Proxy,
+
+    /// Folder with network responses fixtures.
+    /// If set, all the requests are served from this folder
+    /// instead of the internet, e.g. for offline testing
+    pub fixtures: Option<PathBuf>
+}
+
+impl Default for Network {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            min_speed: 64 * 1024,
+            min_speed_timeout: 30,
+            proxy: proxy::Proxy::default(),
+            fixtures: None
+        }
+    }
+}
+
+impl From<&Json> for Network {
+    #[inline]
+    fn from(value: &Json) -> Self {
+        let default = Self::default();
+
+        Self {
+            min_speed: value.get("min_speed")
+                .and_then(Json::as_u64)
+                .unwrap_or(default.min_speed),
+
+            min_speed_timeout: value.get("min_speed_timeout")
+                .and_then(Json::as_u64)
+                .unwrap_or(default.min_speed_timeout),
+
+            proxy: value.get("proxy")
+                .map(proxy::Proxy::from)
+                .unwrap_or(default.proxy),
+
+            fixtures: value.get("fixtures")
+                .and_then(Json::as_str)
+                .map(PathBuf::from)
+                .or(default.fixtures)
+        }
+    }
+}
@@ -0,0 +1,31 @@
+use serde::{Serialize, Deserialize};
+use serde_json::Value as Json;
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Tasks {
+    /// Max amount of tasks which can be run at the same time.
+    /// Tasks which use the same paths are always run one by one
+    pub max_concurrent: u64
+}
+
+impl Default for Tasks {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            max_concurrent: 2
+        }
+    }
+}
+
+impl From<&Json> for Tasks {
+    #[inline]
+    fn from(value: &Json) -> Self {
+        let default = Self::default();
+
+        Self {
+            max_concurrent: value.get("max_concurrent")
+                .and_then(Json::as_u64)
+                .unwrap_or(default.max_concurrent)
+        }
+    }
+}
@@ -0,0 +1,64 @@
+use serde::{Serialize, Deserialize};
+use serde_json::Value as Json;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum UpdatePolicy {
+    /// Don't check for updates in background
+    Never,
+
+    /// Mark outdated games and notify user about new updates
+    Notify,
+
+    /// Queue updates downloading automatically
+    Download
+}
+
+impl Default for UpdatePolicy {
+    #[inline]
+    fn default() -> Self {
+        Self::Notify
+    }
+}
+
+impl From<&Json> for UpdatePolicy {
+    #[inline]
+    fn from(value: &Json) -> Self {
+        serde_json::from_value(value.clone()).unwrap_or_default()
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Updates {
+    /// Policy of the games which don't have their own one
+    pub policy: UpdatePolicy,
+
+    /// Time between background updates checks, in minutes
+    pub check_interval: u64
+}
+
+impl Default for Updates {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            policy: UpdatePolicy::default(),
+            check_interval: 60
+        }
+    }
+}
+
+impl From<&Json> for Updates {
+    #[inline]
+    fn from(value: &Json) -> Self {
+        let default = Self::default();
+
+        Self {
+            policy: value.get("policy")
+                .map(UpdatePolicy::from)
+                .unwrap_or(default.policy),
+
+            check_interval: value.get("check_interval")
+                .and_then(Json::as_u64)
+                .unwrap_or(default.check_interval)
+        }
+    }
+}
@@ -0,0 +1,145 @@
+use std::path::Path;
+use std::io::{Read, BufReader};
+
+use crate::games::integrations::standards::mirrors::Mirrors;
+
+use crate::network::stream::MirrorsStream;
+
+pub mod tar;
+pub mod zip;
+
+/// Size of the buffer used to read streamed archives
+const BUFFER_SIZE: usize = 64 * 1024;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum TarCompression {
+    None,
+    Gzip,
+    Bzip2,
+    Xz,
+    Lzma,
+    Zstd
+}
+
+impl TarCompression {
+    /// Get `tar` flag which enables this compression
+    pub fn flag(&self) -> Option<&'static str> {
+        match self {
+            Self::None  => None,
+            Self::Gzip  => Some("--gzip"),
+            Self::Bzip2 => Some("--bzip2"),
+            Self::Xz    => Some("--xz"),
+            Self::Lzma  => Some("--lzma"),
+            Self::Zstd  => Some("--zstd")
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum StreamFormat {
+    Tar(TarCompression),
+    Zip
+}
+
+impl StreamFormat {
+    /// Get format of the archive from its file name
+    ///
+    /// Returns `None` if the archive can't be extracted while downloading
+    pub fn from_file_name(file_name: impl AsRef<str>) -> Option<Self> {
+        let file_name = file_name.as_ref().to_ascii_lowercase();
+
+        let formats = [
+            (".tar",      Self::Tar(TarCompression::None)),
+            (".tar.gz",   Self::Tar(TarCompression::Gzip)),
+            (".tgz",      Self::Tar(TarCompression::Gzip)),
+            (".tar.bz2",  Self::Tar(TarCompression::Bzip2)),
+            (".tbz2",     Self::Tar(TarCompression::Bzip2)),
+            (".tar.xz",   Self::Tar(TarCompression::Xz)),
+            (".txz",      Self::Tar(TarCompression::Xz)),
+            (".tar.lzma", Self::Tar(TarCompression::Lzma)),
+            (".tar.zst",  Self::Tar(TarCompression::Zstd)),
+            (".zip",      Self::Zip)
+        ];
+
+        formats.into_iter()
+            .find(|(extension, _)| file_name.ends_with(extension))
+            .map(|(_, format)| format)
+    }
+}
+
+#[inline]
+/// Check if the archive can be extracted while downloading
+pub fn is_streamable(file_name: impl AsRef<str>) -> bool {
+    StreamFormat::from_file_name(file_name).is_some()
+}
+
+struct ProgressReader<F> {
+    stream: MirrorsStream,
+    progress: F
+}
+
+impl<F: FnMut(u64, u64) -> anyhow::Result<()>> Read for ProgressReader<F> {
+    fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+        let read = self.stream.read(buf)?;
+
+        (self.progress)(self.stream.offset(), self.stream.total())
+            .map_err(|err| std::io::Error::new(std::io::ErrorKind::Other, err))?;
+
+        Ok(read)
+    }
+}
+
+/// Extract archive to the given folder while downloading it
+///
+/// `progress` callback is called with `(current, total)` downloaded bytes.
+/// Extraction is stopped if the callback returns an error.
+///
+/// Returns `false` if the archive can't be streamed, e.g. because of
+/// unsupported format or compression method. Nothing is extracted in this case
+pub fn extract_stream(mirrors: &Mirrors, path: impl AsRef<Path>, mut progress: impl FnMut(u64, u64) -> anyhow::Result<()>) -> anyhow::Result<bool> {
+    let Some(format) = StreamFormat::from_file_name(mirrors.file_name()) else {
+        return Ok(false);
+    };
+
+    match format {
+        StreamFormat::Tar(compression) => {
+            let stream = MirrorsStream::open(mirrors)?;
+
+            progress(0, stream.total())?;
+
+            let reader = BufReader::with_capacity(BUFFER_SIZE, ProgressReader {
+                stream,
+                progress
+            });
+
+            tar::extract(reader, compression, path)?;
+        }
+
+        StreamFormat::Zip => {
+            // Central directory is stored at the end of the archive
+            // so it should be read before streaming the entries
+            let entries = zip::read_central_directory(mirrors)?;
+
+            if !zip::is_supported(&entries) {
+                return Ok(false);
+            }
+
+            let stream = MirrorsStream::open(mirrors)?;
+            let total = stream.total();
+
+            progress(0, total)?;
+
+            let mut reader = BufReader::with_capacity(BUFFER_SIZE, ProgressReader {
+                stream,
+                progress
+            });
+
+            zip::extract(&mut reader, entries, path)?;
+
+            // Central directory itself isn't read
+            (reader.get_mut().progress)(total, total)?;
+        }
+    }
+
+    Ok(true)
+}
@@ -0,0 +1,60 @@
+use std::path::Path;
+use std::io::Read;
+use std::process::{Command, Stdio};
+
+use super::TarCompression;
+
+/// Extract tar archive from the reader to the given folder
+///
+/// Archive is extracted by the `tar` binary which should be available in `PATH`
+pub fn extract(mut reader: impl Read, compression: TarCompression, path: impl AsRef<Path>) -> anyhow::Result<()> {
+    let path = path.as_ref();
+
+    if !path.exists() {
+        std::fs::create_dir_all(path)?;
+    }
+
+    let mut command = Command::new("tar");
+
+    command.arg("-x");
+
+    if let Some(flag) = compression.flag() {
+        command.arg(flag);
+    }
+
+    let mut child = command
+        .arg("-f")
+        .arg("-")
+        .arg("-C")
+        .arg(path)
+        .stdin(Stdio::piped())
+        .stdout(Stdio::null())
+        .stderr(Stdio::piped())
+        .spawn()?;
+
+    let Some(mut stdin) = child.stdin.take() else {
+        anyhow::bail!("Failed to open tar stdin");
+    };
+
+    let result = std::io::copy(&mut reader, &mut stdin);
+
+    // Close stdin so tar knows the archive is finished
+    drop(stdin);
+
+    if let Err(err) = result {
+        // tar could have failed by itself which closes the pipe
+        let _ = child.kill();
+
+        let output = child.wait_with_output()?;
+
+        anyhow::bail!("Failed to stream tar archive: {err}. {}", String::from_utf8_lossy(&output.stderr));
+    }
+
+    let output = child.wait_with_output()?;
+
+    if !output.status.success() {
+        anyhow::bail!("Failed to extract tar archive: {}", String::from_utf8_lossy(&output.stderr));
+    }
+
+    Ok(())
+}
@@ -0,0 +1,397 @@
+use std::path::{Path, Component};
+use std::io::{Read, Write, BufWriter};
+use std::fs::File;
+
+use flate2::read::DeflateDecoder;
+
+use crate::games::integrations::standards::mirrors::Mirrors;
+
+use crate::network::stream::fetch_range;
+
+/// Max size of the end of central directory record, including the comment
+const EOCD_MAX_SIZE: u64 = 22 + u16::MAX as u64;
+
+const EOCD_SIGNATURE: u32 = 0x06054b50;
+const ZIP64_EOCD_LOCATOR_SIGNATURE: u32 = 0x07064b50;
+const ZIP64_EOCD_SIGNATURE: u32 = 0x06064b50;
+const CENTRAL_HEADER_SIGNATURE: u32 = 0x02014b50;
+const LOCAL_HEADER_SIGNATURE: u32 = 0x04034b50;
+
+const ZIP64_EXTRA_FIELD: u16 = 0x0001;
+
+const METHOD_STORED: u16 = 0;
+const METHOD_DEFLATE: u16 = 8;
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ZipEntry {
+    pub name: String,
+    pub method: u16,
+    pub encrypted: bool,
+    pub crc32: u32,
+    pub compressed_size: u64,
+    pub uncompressed_size: u64,
+
+    /// Offset of the local file header
+    pub offset: u64,
+
+    /// Unix file mode if the archive was made on unix
+    pub mode: Option<u32>
+}
+
+impl ZipEntry {
+    #[inline]
+    pub fn is_dir(&self) -> bool {
+        self.name.ends_with('/')
+    }
+
+    #[inline]
+    pub fn is_symlink(&self) -> bool {
+        matches!(self.mode, Some(mode) if mode & 0o170000 == 0o120000)
+    }
+}
+
+fn read_u16(data: &[u8], offset: usize) -> anyhow::Result<u16> {
+    data.get(offset..offset + 2)
+        .map(|bytes| u16::from_le_bytes([bytes[0], bytes[1]]))
+        .ok_or_else(|| anyhow::anyhow!("Unexpected end of zip record"))
+}
+
+fn read_u32(data: &[u8], offset: usize) -> anyhow::Result<u32> {
+    data.get(offset..offset + 4)
+        .map(|bytes| u32::from_le_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]))
+        .ok_or_else(|| anyhow::anyhow!("Unexpected end of zip record"))
+}
+
+fn read_u64(data: &[u8], offset: usize) -> anyhow::Result<u64> {
+    data.get(offset..offset + 8)
+        .map(|bytes| {
+            let mut value = [0; 8];
+
+            value.copy_from_slice(bytes);
+
+            u64::from_le_bytes(value)
+        })
+        .ok_or_else(|| anyhow::anyhow!("Unexpected end of zip record"))
+}
+
+/// Get bytes in `start..start + length` range of the remote file
+/// using already fetched tail of the file if possible
+fn get_range(mirrors: &Mirrors, tail: &[u8], tail_offset: u64, start: u64, length: u64) -> anyhow::Result<Vec<u8>> {
+    if start >= tail_offset && start + length <= tail_offset + tail.len() as u64 {
+        let start = (start - tail_offset) as usize;
+
+        return Ok(tail[start..start + length as usize].to_vec());
+    }
+
+    if length == 0 {
+        return Ok(vec![]);
+    }
+
+    let (data, _) = fetch_range(mirrors, format!("{start}-{}", start + length - 1))?;
+
+    if data.len() as u64 != length {
+        anyhow::bail!("Server returned wrong amount of bytes: {} instead of {length}", data.len());
+    }
+
+    Ok(data)
+}
+
+/// Read central directory of the remote zip archive
+pub fn read_central_directory(mirrors: &Mirrors) -> anyhow::Result<Vec<ZipEntry>> {
+    let (tail, total) = fetch_range(mirrors, format!("-{EOCD_MAX_SIZE}"))?;
+
+    let tail_offset = total - tail.len() as u64;
+
+    // Find end of central directory record
+
+    let eocd = (0..tail.len().saturating_sub(21)).rev()
+        .find(|i| read_u32(&tail, *i).ok() == Some(EOCD_SIGNATURE))
+        .ok_or_else(|| anyhow::anyhow!("Failed to find end of central directory record"))?;
+
+    let mut entries_count = read_u16(&tail, eocd + 10)? as u64;
+    let mut directory_size = read_u32(&tail, eocd + 12)? as u64;
+    let mut directory_offset = read_u32(&tail, eocd + 16)? as u64;
+
+    // Read zip64 end of central directory record
+
+    if entries_count == u16::MAX as u64 || directory_size == u32::MAX as u64 || directory_offset == u32::MAX as u64 {
+        let locator = eocd.checked_sub(20)
+            .filter(|locator| read_u32(&tail, *locator).ok() == Some(ZIP64_EOCD_LOCATOR_SIGNATURE))
+            .ok_or_else(|| anyhow::anyhow!("Failed to find zip64 end of central directory locator"))?;
+
+        let record_offset = read_u64(&tail, locator + 8)?;
+        let record = get_range(mirrors, &tail, tail_offset, record_offset, 56)?;
+
+        if read_u32(&record, 0)? != ZIP64_EOCD_SIGNATURE {
+            anyhow::bail!("Wrong zip64 end of central directory record signature");
+        }
+
+        entries_count = read_u64(&record, 32)?;
+        directory_size = read_u64(&record, 40)?;
+        directory_offset = read_u64(&record, 48)?;
+    }
+
+    // Read central directory entries
+
+    let directory = get_range(mirrors, &tail, tail_offset, directory_offset, directory_size)?;
+
+    let mut entries = Vec::with_capacity(entries_count as usize);
+    let mut offset = 0;
+
+    for _ in 0..entries_count {
+        if read_u32(&directory, offset)? != CENTRAL_HEADER_SIGNATURE {
+            anyhow::bail!("Wrong central directory file header signature");
+        }
+
+        let made_by = read_u16(&directory, offset + 4)?;
+        let flags = read_u16(&directory, offset + 8)?;
+        let method = read_u16(&directory, offset + 10)?;
+        let crc32 = read_u32(&directory, offset + 16)?;
+
+        let mut compressed_size = read_u32(&directory, offset + 20)? as u64;
+        let mut uncompressed_size = read_u32(&directory, offset + 24)? as u64;
+
+        let name_length = read_u16(&directory, offset + 28)? as usize;
+        let extra_length = read_u16(&directory, offset + 30)? as usize;
+        let comment_length = read_u16(&directory, offset + 32)? as usize;
+
+        let attributes = read_u32(&directory, offset + 38)?;
+
+        let mut local_offset = read_u32(&directory, offset + 42)? as u64;
+
+        let name = directory.get(offset + 46..offset + 46 + name_length)
+            .ok_or_else(|| anyhow::anyhow!("Unexpected end of central directory"))?;
+
+        let extra = directory.get(offset + 46 + name_length..offset + 46 + name_length + extra_length)
+            .ok_or_else(|| anyhow::anyhow!("Unexpected end of central directory"))?;
+
+        // Values which don't fit 32 bits are stored in the zip64 extra field
+        // in this exact order, only if their original fields are overflowed
+        let mut i = 0;
+
+        while i + 4 <= extra.len() {
+            let id = read_u16(extra, i)?;
+            let size = read_u16(extra, i + 2)? as usize;
+
+            if id == ZIP64_EXTRA_FIELD {
+                let mut field = i + 4;
+
+                for value in [&mut uncompressed_size, &mut compressed_size, &mut local_offset] {
+                    if *value == u32::MAX as u64 {
+                        *value = read_u64(extra, field)?;
+
+                        field += 8;
+                    }
+                }
+            }
+
+            i += 4 + size;
+        }
+
+        entries.push(ZipEntry {
+            name: String::from_utf8_lossy(name).to_string(),
+            method,
+            encrypted: flags & 1 == 1,
+            crc32,
+            compressed_size,
+            uncompressed_size,
+            offset: local_offset,
+
+            // Upper byte of "version made by" field is 3 for unix
+            mode: (made_by >> 8 == 3).then_some(attributes >> 16)
+        });
+
+        offset += 46 + name_length + extra_length + comment_length;
+    }
+
+    Ok(entries)
+}
+
+/// Check if all the entries can be extracted
+pub fn is_supported(entries: &[ZipEntry]) -> bool {
+    entries.iter().all(|entry| {
+        !entry.encrypted && [METHOD_STORED, METHOD_DEFLATE].contains(&entry.method)
+    })
+}
+
+/// Skip given amount of bytes from the reader
+fn skip(reader: &mut impl Read, length: u64) -> anyhow::Result<()> {
+    let skipped = std::io::copy(&mut (&mut *reader).take(length), &mut std::io::sink())?;
+
+    if skipped != length {
+        anyhow::bail!("Unexpected end of zip archive");
+    }
+
+    Ok(())
+}
+
+/// Check that the symlink target doesn't point outside of the extraction folder
+fn is_safe_symlink(relative_path: &Path, target: &Path) -> bool {
+    let mut depth = relative_path.parent()
+        .map(|parent| {
+            parent.components()
+                .filter(|component| matches!(component, Component::Normal(_)))
+                .count()
+        })
+        .unwrap_or_default();
+
+    for component in target.components() {
+        match component {
+            Component::Normal(_) => depth += 1,
+            Component::CurDir => (),
+
+            Component::ParentDir if depth > 0 => depth -= 1,
+
+            // Absolute paths and going above the extraction folder
+            _ => return false
+        }
+    }
+
+    true
+}
+
+/// Check that none of the entry's parent folders is a symlink
+/// so we don't write through symlinks made by previous entries
+fn check_parents(path: &Path, relative_path: &Path) -> anyhow::Result<()> {
+    let mut parent = path.to_path_buf();
+
+    if let Some(folders) = relative_path.parent() {
+        for component in folders.components() {
+            parent.push(component);
+
+            if matches!(parent.symlink_metadata(), Ok(metadata) if metadata.file_type().is_symlink()) {
+                anyhow::bail!("Zip archive entry is placed inside of a symlink: {}", relative_path.display());
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// Extract zip archive entries from the reader to the given folder
+///
+/// Reader must start from the beginning of the archive.
+/// Entries are read in order of their position in the archive
+pub fn extract(reader: &mut impl Read, mut entries: Vec<ZipEntry>, path: impl AsRef<Path>) -> anyhow::Result<()> {
+    let path = path.as_ref();
+
+    entries.sort_by_key(|entry| entry.offset);
+
+    let mut position = 0;
+    let mut buffer = vec![0; super::BUFFER_SIZE];
+
+    for entry in entries {
+        // Prevent writing files outside of the extraction folder
+        let relative_path = Path::new(&entry.name);
+
+        if relative_path.components().any(|component| !matches!(component, Component::Normal(_) | Component::CurDir)) {
+            anyhow::bail!("Zip archive contains wrong entry path: {}", entry.name);
+        }
+
+        if entry.offset < position {
+            anyhow::bail!("Zip archive contains overlapping entries: {}", entry.name);
+        }
+
+        // Read local file header
+
+        skip(reader, entry.offset - position)?;
+
+        let mut header = [0; 30];
+
+        reader.read_exact(&mut header)?;
+
+        if read_u32(&header, 0)? != LOCAL_HEADER_SIGNATURE {
+            anyhow::bail!("Wrong local file header signature: {}", entry.name);
+        }
+
+        let name_length = read_u16(&header, 26)? as u64;
+        let extra_length = read_u16(&header, 28)? as u64;
+
+        skip(reader, name_length + extra_length)?;
+
+        position = entry.offset + 30 + name_length + extra_length + entry.compressed_size;
+
+        // Extract entry
+
+        let entry_path = path.join(relative_path);
+
+        check_parents(path, relative_path)?;
+
+        if let Some(parent) = entry_path.parent() {
+            if !parent.exists() {
+                std::fs::create_dir_all(parent)?;
+            }
+        }
+
+        let mut data = (&mut *reader).take(entry.compressed_size);
+
+        if entry.is_dir() {
+            std::fs::create_dir_all(&entry_path)?;
+        }
+
+        else {
+            // Remove the file instead of truncating it
+            // so we don't write through hard links
+            if entry_path.symlink_metadata().is_ok() {
+                std::fs::remove_file(&entry_path)?;
+            }
+
+            let mut decoder: Box<dyn Read> = match entry.method {
+                METHOD_STORED => Box::new(&mut data),
+                METHOD_DEFLATE => Box::new(DeflateDecoder::new(&mut data)),
+
+                method => anyhow::bail!("Unsupported zip compression method: {method}")
+            };
+
+            if entry.is_symlink() {
+                let mut target = String::new();
+
+                decoder.read_to_string(&mut target)?;
+
+                if !is_safe_symlink(relative_path, Path::new(&target)) {
+                    anyhow::bail!("Zip archive contains symlink pointing outside of the extraction folder: {}", entry.name);
+                }
+
+                std::os::unix::fs::symlink(target, &entry_path)?;
+            }
+
+            else {
+                let mut file = BufWriter::new(File::create(&entry_path)?);
+                let mut hasher = crc32fast::Hasher::new();
+                let mut size = 0;
+
+                loop {
+                    let read = decoder.read(&mut buffer)?;
+
+                    if read == 0 {
+                        break;
+                    }
+
+                    hasher.update(&buffer[..read]);
+                    file.write_all(&buffer[..read])?;
+
+                    size += read as u64;
+                }
+
+                file.flush()?;
+
+                if size != entry.uncompressed_size || hasher.finalize() != entry.crc32 {
+                    anyhow::bail!("Zip archive entry is corrupted: {}", entry.name);
+                }
+
+                if let Some(mode) = entry.mode {
+                    use std::os::unix::fs::PermissionsExt;
+
+                    if mode & 0o777 != 0 {
+                        std::fs::set_permissions(&entry_path, std::fs::Permissions::from_mode(mode & 0o777))?;
+                    }
+                }
+            }
+        }
+
+        // Decoder can stop before the end of compressed data
+        std::io::copy(&mut data, &mut std::io::sink())?;
+    }
+
+    Ok(())
+}
@@ -28,7 +28,7 @@ impl Driver {
                         None => (RequestMethod::Get, None, None, None)
                     };
 
-                    let mut request = anime_game_core::network::minreq::Request::new(method, uri);
+                    let mut request = crate::network::request(method, uri);
 
                     if let Some(headers) = headers {
                         for (key, value) in headers {
@@ -44,33 +44,42 @@ impl Driver {
                         request = request.with_timeout(timeout);
                     }
 
-                    request.send()
-                        .map(|response| {
-                            let result = lua.create_table()?;
-                            let headers = lua.create_table()?;
-
-                            for (key, value) in &response.headers {
-                                headers.set(key.as_str(), value.as_str())?;
-                            }
-
-                            result.set("url", response.url.as_str())?;
-                            result.set("status", response.status_code)?;
-                            result.set("statusText", response.reason_phrase.as_str())?;
-                            result.set("ok", (200..300).contains(&response.status_code))?;
-                            result.set("headers", headers)?;
-                            result.set("body", lua.create_string(response.as_bytes())?)?;
-
-                            result.set("json", lua.create_function(move |lua, _: ()| {
-                                response.json::<Json>()
-                                    .map(|value| lua.to_value(&value))
-                                    .map_err(LuaError::external)
-                            })?)?;
-
-                            Ok::<LuaTable<'_>, mlua::Error>(result)
-                        })
-                        .map_err(LuaError::external)
+                    let response = request.send()
+                        .map_err(LuaError::external)?;
+
+                    let result = lua.create_table()?;
+                    let headers = lua.create_table()?;
+
+                    for (key, value) in &response.headers {
+                        headers.set(key.as_str(), value.as_str())?;
+                    }
+
+                    result.set("url", response.url.as_str())?;
+                    result.set("status", response.status_code)?;
+                    result.set("statusText", response.reason_phrase.as_str())?;
+                    result.set("ok", response.is_ok())?;
+                    result.set("headers", headers)?;
+
+                    let body = response.into_bytes()
+                        .map_err(LuaError::external)?;
+
+                    result.set("body", lua.create_string(&body)?)?;
+
+                    result.set("json", lua.create_function(move |lua, _: ()| {
+                        serde_json::from_slice::<Json>(&body)
+                            .map(|value| lua.to_value(&value))
+                            .map_err(LuaError::external)
+                    })?)?;
+
+                    Ok(result)
                 })?)?;
         
+                lua.globals().set("v1_network_get_proxy", lua.create_function(|_, uri: String| {
+                    let proxy = crate::config::get().general.network.proxy;
+
+                    Ok(proxy.get_proxy(&uri).map(String::from))
+                })?)?;
+
                 lua.globals().set("v1_json_decode", lua.create_function(|lua, json: String| {
                     serde_json::from_str::<Json>(&json)
                         .map(|value| lua.to_value(&value))
@@ -233,6 +242,26 @@ impl Driver {
         }
     }
 
+    #[inline]
+    #[tracing::instrument(level = "trace", ret)]
+    pub fn get_game_predownload(&self, path: &str, edition: &str) -> anyhow::Result<Option<Download>> {
+        match self.standard {
+            IntegrationStandard::V1 => {
+                if !self.lua.globals().contains_key("v1_game_get_predownload")? {
+                    return Ok(None);
+                }
+
+                let download = self.lua.globals()
+                    .call_function("v1_game_get_predownload", (path, edition))?;
+
+                match download {
+                    Some(download) => Ok(Some(Download::from_table(download, self.standard)?)),
+                    None => Ok(None)
+                }
+            }
+        }
+    }
+
     #[inline]
     #[tracing::instrument(level = "trace", ret)]
     pub fn get_game_status(&self, path: &str, edition: &str) -> anyhow::Result<Option<GameStatus>> {
@@ -570,4 +599,45 @@ impl Driver {
                 ))?)
         }
     }
+
+    #[inline]
+    #[tracing::instrument(level = "trace", ret)]
+    pub fn has_integrity_hasher(&self) -> anyhow::Result<bool> {
+        match self.standard {
+            IntegrationStandard::V1 => Ok(self.lua.globals().contains_key("v1_integrity_hasher")?)
+        }
+    }
+
+    #[inline]
+    #[tracing::instrument(level = "trace")]
+    pub fn integrity_hasher(&self, algorithm: &str) -> anyhow::Result<IntegrityHasher<'_>> {
+        match self.standard {
+            IntegrationStandard::V1 => Ok(IntegrityHasher {
+                lua: &self.lua,
+                hasher: self.lua.globals().call_function("v1_integrity_hasher", algorithm)?
+            })
+        }
+    }
+}
+
+/// Streaming hasher made by the integration script
+#[derive(Debug)]
+pub struct IntegrityHasher<'lua> {
+    lua: &'lua Lua,
+    hasher: LuaTable<'lua>
+}
+
+impl<'lua> IntegrityHasher<'lua> {
+    /// Pass next chunk of data to the hasher
+    pub fn update(&self, data: impl AsRef<[u8]>) -> anyhow::Result<()> {
+        self.hasher.get::<_, LuaFunction>("update")?
+            .call::<_, ()>(self.lua.create_string(data)?)?;
+
+        Ok(())
+    }
+
+    /// Get hash of all the passed data
+    pub fn finalize(self) -> anyhow::Result<String> {
+        Ok(self.hasher.get::<_, LuaFunction>("finalize")?.call(())?)
+    }
 }
@@ -58,7 +58,13 @@ pub struct Addon {
     pub name: String,
     pub title: String,
     pub version: String,
-    pub required: bool
+    pub required: bool,
+
+    /// Addons which must be enabled together with this one
+    pub depends_on: Vec<AddonReference>,
+
+    /// Addons which can't be enabled together with this one
+    pub conflicts_with: Vec<AddonReference>
 }
 
 impl Addon {
@@ -70,7 +76,10 @@ impl Addon {
                     name: table.get::<_, String>("name")?,
                     title: table.get::<_, String>("title")?,
                     version: table.get::<_, String>("version")?,
-                    required: table.get::<_, bool>("required")?
+                    required: table.get::<_, bool>("required")?,
+
+                    depends_on: AddonReference::list_from_table(&table, "depends_on", standard)?,
+                    conflicts_with: AddonReference::list_from_table(&table, "conflicts_with", standard)?
                 })
             }
         }
@@ -87,6 +96,9 @@ impl Addon {
                 table.set("version", self.version.as_str())?;
                 table.set("required", self.required)?;
 
+                table.set("depends_on", AddonReference::list_to_table(&self.depends_on, lua, standard)?)?;
+                table.set("conflicts_with", AddonReference::list_to_table(&self.conflicts_with, lua, standard)?)?;
+
                 Ok(table)
             }
         }
@@ -116,6 +128,59 @@ impl Addon {
     }
 }
 
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct AddonReference {
+    pub group: String,
+    pub name: String
+}
+
+impl AddonReference {
+    pub fn from_table(table: LuaTable, standard: IntegrationStandard) -> anyhow::Result<Self> {
+        match standard {
+            IntegrationStandard::V1 => {
+                Ok(Self {
+                    group: table.get::<_, String>("group")?,
+                    name: table.get::<_, String>("name")?
+                })
+            }
+        }
+    }
+
+    pub fn to_table<'a>(&self, lua: &'a Lua, standard: IntegrationStandard) -> anyhow::Result<LuaTable<'a>> {
+        match standard {
+            IntegrationStandard::V1 => {
+                let table = lua.create_table()?;
+
+                table.set("group", self.group.as_str())?;
+                table.set("name", self.name.as_str())?;
+
+                Ok(table)
+            }
+        }
+    }
+
+    /// Read optional list of references from the given table's field
+    fn list_from_table(table: &LuaTable, key: &str, standard: IntegrationStandard) -> anyhow::Result<Vec<Self>> {
+        match table.get::<_, Option<LuaTable>>(key)? {
+            Some(list) => list.sequence_values()
+                .map(|reference| Self::from_table(reference?, standard))
+                .collect(),
+
+            None => Ok(Vec::new())
+        }
+    }
+
+    fn list_to_table<'a>(list: &[Self], lua: &'a Lua, standard: IntegrationStandard) -> anyhow::Result<LuaTable<'a>> {
+        let table = lua.create_table()?;
+
+        for reference in list {
+            table.push(reference.to_table(lua, standard)?)?;
+        }
+
+        Ok(table)
+    }
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum AddonType {
     Module,
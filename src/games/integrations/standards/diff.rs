@@ -1,6 +1,8 @@
 use mlua::prelude::*;
 
 use super::IntegrationStandard;
+use super::mirrors::Mirrors;
+use super::integrity::FileHash;
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct Diff {
@@ -91,15 +93,21 @@ impl DiffStatus {
 pub enum DiffInfo {
     Archive {
         size: u64,
-        uri: String
+        unpacked_size: Option<u64>,
+        uri: Mirrors
     },
     Segments {
         size: u64,
-        segments: Vec<String>
+        unpacked_size: Option<u64>,
+        segments: Vec<Mirrors>
     },
     Files {
         size: u64,
         files: Vec<DiffFileDownload>
+    },
+    Patch {
+        size: u64,
+        patches: Vec<DiffPatch>
     }
 }
 
@@ -108,19 +116,23 @@ impl DiffInfo {
         match standard {
             IntegrationStandard::V1 => {
                 let size = table.get::<_, u64>("size")?;
+                let unpacked_size = table.get::<_, Option<u64>>("unpacked_size")?;
 
                 match table.get::<_, String>("type")?.as_str() {
                     "archive" => Ok(Self::Archive {
                         size,
-                        uri: table.get::<_, String>("uri")?
+                        unpacked_size,
+                        uri: Mirrors::from_lua(table.get::<_, LuaValue>("uri")?, standard)?
                     }),
 
                     "segments" => Ok(Self::Segments {
                         size,
+                        unpacked_size,
                         segments: table.get::<_, LuaTable>("segments")?
-                            .sequence_values::<String>()
+                            .sequence_values::<LuaValue>()
                             .flatten()
-                            .collect()
+                            .map(|segment| Mirrors::from_lua(segment, standard))
+                            .collect::<Result<Vec<_>, _>>()?
                     }),
 
                     "files" => Ok(Self::Files {
@@ -132,6 +144,15 @@ impl DiffInfo {
                             .collect::<Result<Vec<_>, _>>()?
                     }),
 
+                    "patch" => Ok(Self::Patch {
+                        size,
+                        patches: table.get::<_, LuaTable>("patches")?
+                            .sequence_values::<LuaTable>()
+                            .flatten()
+                            .map(|patch| DiffPatch::from_table(patch, standard))
+                            .collect::<Result<Vec<_>, _>>()?
+                    }),
+
                     value => anyhow::bail!("Wrong v1 diff type: '{value}'")
                 }
             }
@@ -144,21 +165,23 @@ impl DiffInfo {
                 let table = lua.create_table()?;
 
                 match self {
-                    Self::Archive { size, uri } => {
+                    Self::Archive { size, unpacked_size, uri } => {
                         table.set("type", "archive")?;
                         table.set("size", *size)?;
-                        table.set("uri", uri.as_str())?;
+                        table.set("unpacked_size", *unpacked_size)?;
+                        table.set("uri", uri.to_lua(lua, standard)?)?;
                     }
 
-                    Self::Segments { size, segments } => {
+                    Self::Segments { size, unpacked_size, segments } => {
                         let segments_lua = lua.create_table()?;
 
                         for segment in segments {
-                            segments_lua.push(segment.as_str())?;
+                            segments_lua.push(segment.to_lua(lua, standard)?)?;
                         }
 
                         table.set("type", "segments")?;
                         table.set("size", *size)?;
+                        table.set("unpacked_size", *unpacked_size)?;
                         table.set("segments", segments_lua)?;
                     }
 
@@ -173,19 +196,45 @@ impl DiffInfo {
                         table.set("size", *size)?;
                         table.set("files", files_lua)?;
                     }
+
+                    Self::Patch { size, patches } => {
+                        let patches_lua = lua.create_table()?;
+
+                        for patch in patches {
+                            patches_lua.push(patch.to_table(lua, standard)?)?;
+                        }
+
+                        table.set("type", "patch")?;
+                        table.set("size", *size)?;
+                        table.set("patches", patches_lua)?;
+                    }
                 }
 
                 Ok(table)
             }
         }
     }
+
+    #[inline]
+    /// Get size of the files which will be downloaded
+    pub fn size(&self) -> u64 {
+        match self {
+            Self::Archive { size, .. } |
+            Self::Segments { size, .. } |
+            Self::Files { size, .. } |
+            Self::Patch { size, .. } => *size
+        }
+    }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct DiffFileDownload {
     pub path: String,
-    pub uri: String,
-    pub size: u64
+    pub uri: Mirrors,
+    pub size: u64,
+
+    /// Expected hash of the downloaded file
+    pub hash: Option<FileHash>
 }
 
 impl DiffFileDownload {
@@ -194,8 +243,15 @@ impl DiffFileDownload {
             IntegrationStandard::V1 => {
                 Ok(Self {
                     path: table.get::<_, String>("path")?,
-                    uri: table.get::<_, String>("uri")?,
-                    size: table.get::<_, u64>("size")?
+                    uri: Mirrors::from_lua(table.get::<_, LuaValue>("uri")?, standard)?,
+                    size: table.get::<_, u64>("size")?,
+                    hash: {
+                        if table.contains_key::<_>("hash")? {
+                            Some(FileHash::from_table(table.get::<_, LuaTable>("hash")?, standard)?)
+                        } else {
+                            None
+                        }
+                    }
                 })
             }
         }
@@ -207,11 +263,101 @@ impl DiffFileDownload {
                 let table = lua.create_table()?;
 
                 table.set("path", self.path.as_str())?;
-                table.set("uri", self.uri.as_str())?;
+                table.set("uri", self.uri.to_lua(lua, standard)?)?;
                 table.set("size", self.size)?;
 
+                if let Some(hash) = &self.hash {
+                    table.set("hash", hash.to_table(lua, standard)?)?;
+                }
+
                 Ok(table)
             }
         }
     }
 }
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct DiffPatch {
+    /// Relative path to the patched file
+    pub path: String,
+
+    /// Patch file URI
+    pub uri: Mirrors,
+
+    /// Patch file size
+    pub size: u64,
+
+    pub format: PatchFormat,
+
+    /// Hash of the file before patching
+    pub source_hash: FileHash,
+
+    /// Hash of the file after patching
+    pub target_hash: FileHash
+}
+
+impl DiffPatch {
+    pub fn from_table(table: LuaTable, standard: IntegrationStandard) -> anyhow::Result<Self> {
+        match standard {
+            IntegrationStandard::V1 => {
+                Ok(Self {
+                    path: table.get::<_, String>("path")?,
+                    uri: Mirrors::from_lua(table.get::<_, LuaValue>("uri")?, standard)?,
+                    size: table.get::<_, u64>("size")?,
+                    format: PatchFormat::from_str(table.get::<_, String>("format")?, standard)?,
+                    source_hash: FileHash::from_table(table.get::<_, LuaTable>("source_hash")?, standard)?,
+                    target_hash: FileHash::from_table(table.get::<_, LuaTable>("target_hash")?, standard)?
+                })
+            }
+        }
+    }
+
+    pub fn to_table<'a>(&self, lua: &'a Lua, standard: IntegrationStandard) -> anyhow::Result<LuaTable<'a>> {
+        match standard {
+            IntegrationStandard::V1 => {
+                let table = lua.create_table()?;
+
+                table.set("path", self.path.as_str())?;
+                table.set("uri", self.uri.to_lua(lua, standard)?)?;
+                table.set("size", self.size)?;
+                table.set("format", self.format.to_str(standard))?;
+                table.set("source_hash", self.source_hash.to_table(lua, standard)?)?;
+                table.set("target_hash", self.target_hash.to_table(lua, standard)?)?;
+
+                Ok(table)
+            }
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum PatchFormat {
+    Bsdiff,
+    Hdiff
+}
+
+impl PatchFormat {
+    pub fn from_str(value: impl AsRef<str>, standard: IntegrationStandard) -> anyhow::Result<Self> {
+        match standard {
+            IntegrationStandard::V1 => {
+                match value.as_ref() {
+                    "bsdiff" => Ok(Self::Bsdiff),
+                    "hdiff"  => Ok(Self::Hdiff),
+
+                    _ => anyhow::bail!("Wrong v1 patch format: '{}'", value.as_ref())
+                }
+            }
+        }
+    }
+
+    pub fn to_str(&self, standard: IntegrationStandard) -> &str {
+        match standard {
+            IntegrationStandard::V1 => {
+                match self {
+                    Self::Bsdiff => "bsdiff",
+                    Self::Hdiff  => "hdiff"
+                }
+            }
+        }
+    }
+}
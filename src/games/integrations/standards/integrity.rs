@@ -1,8 +1,20 @@
+use std::path::Path;
+use std::io::Read;
+use std::fs::File;
+
 use mlua::prelude::*;
 
+use crate::games::integrations::driver::{Driver, IntegrityHasher};
+
 use super::IntegrationStandard;
 use super::diff::DiffFileDownload;
 
+/// Size of the chunks files are hashed by
+///
+/// Files are never read to memory entirely so
+/// memory usage doesn't depend on their size
+pub const HASH_BUFFER_SIZE: usize = 1024 * 1024;
+
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct IntegrityInfo {
     pub hash: HashType,
@@ -38,10 +50,55 @@ impl IntegrityInfo {
     }
 }
 
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct FileHash {
+    pub r#type: HashType,
+    pub value: String
+}
+
+impl FileHash {
+    pub fn from_table(table: LuaTable, standard: IntegrationStandard) -> anyhow::Result<Self> {
+        match standard {
+            IntegrationStandard::V1 => {
+                Ok(Self {
+                    r#type: HashType::from_str(table.get::<_, String>("type")?, standard)?,
+                    value: table.get::<_, String>("value")?
+                })
+            }
+        }
+    }
+
+    pub fn to_table<'a>(&self, lua: &'a Lua, standard: IntegrationStandard) -> anyhow::Result<LuaTable<'a>> {
+        match standard {
+            IntegrationStandard::V1 => {
+                let table = lua.create_table()?;
+
+                table.set("type", self.r#type.to_str(standard))?;
+                table.set("value", self.value.as_str())?;
+
+                Ok(table)
+            }
+        }
+    }
+
+    #[inline]
+    /// Check if the given data has the same hash
+    pub fn verify(&self, data: impl AsRef<[u8]>, driver: &Driver) -> anyhow::Result<bool> {
+        Ok(self.r#type.hash(data, driver)? == self.value)
+    }
+
+    #[inline]
+    /// Check if the given file has the same hash
+    pub fn verify_file(&self, path: impl AsRef<Path>, driver: &Driver) -> anyhow::Result<bool> {
+        Ok(self.r#type.hash_file(path, driver)? == self.value)
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub enum HashType {
     Md5,
     Sha1,
+    Sha256,
     Crc32,
 
     // Older generation xxhash-es
@@ -52,6 +109,8 @@ pub enum HashType {
     Xxhash3_64,
     Xxhash3_128,
 
+    Blake3,
+
     Custom(String)
 }
 
@@ -62,11 +121,13 @@ impl HashType {
                 match value.as_ref() {
                     "md5"         => Ok(Self::Md5),
                     "sha1"        => Ok(Self::Sha1),
+                    "sha256"      => Ok(Self::Sha256),
                     "crc32"       => Ok(Self::Crc32),
                     "xxhash32"    => Ok(Self::Xxhash32),
                     "xxhash64"    => Ok(Self::Xxhash64),
                     "xxhash3/64"  => Ok(Self::Xxhash3_64),
                     "xxhash3/128" => Ok(Self::Xxhash3_128),
+                    "blake3"      => Ok(Self::Blake3),
 
                     name => Ok(Self::Custom(name.to_string()))
                 }
@@ -80,15 +141,196 @@ impl HashType {
                 match self {
                     Self::Md5         => "md5",
                     Self::Sha1        => "sha1",
+                    Self::Sha256      => "sha256",
                     Self::Crc32       => "crc32",
                     Self::Xxhash32    => "xxhash32",
                     Self::Xxhash64    => "xxhash64",
                     Self::Xxhash3_64  => "xxhash3/64",
                     Self::Xxhash3_128 => "xxhash3/128",
+                    Self::Blake3      => "blake3",
 
                     Self::Custom(name) => name
                 }
             }
         }
     }
+
+    /// Make new streaming hasher
+    /// 
+    /// Custom hashes are calculated by the `v1_integrity_hasher` function
+    /// of the integration script, or by the `v1_integrity_hash` function
+    /// with all the data at once if the script doesn't have the first one
+    pub fn hasher<'a>(&self, driver: &'a Driver) -> anyhow::Result<Hasher<'a>> {
+        let hasher = match self {
+            Self::Md5    => Hasher::Md5(Default::default()),
+            Self::Sha1   => Hasher::Sha1(Default::default()),
+            Self::Sha256 => Hasher::Sha256(Default::default()),
+
+            Self::Crc32 => Hasher::Crc32(crc32fast::Hasher::new()),
+
+            Self::Xxhash32    => Hasher::Xxhash32(xxhash_rust::xxh32::Xxh32::new(0)),
+            Self::Xxhash64    => Hasher::Xxhash64(xxhash_rust::xxh64::Xxh64::new(0)),
+            Self::Xxhash3_64  => Hasher::Xxhash3_64(Box::default()),
+            Self::Xxhash3_128 => Hasher::Xxhash3_128(Box::default()),
+
+            Self::Blake3 => Hasher::Blake3(Box::default()),
+
+            Self::Custom(name) if driver.has_integrity_hasher()? => {
+                Hasher::Script(driver.integrity_hasher(name)?)
+            }
+
+            Self::Custom(name) if driver.has_integrity_hash()? => Hasher::Buffered {
+                name: name.to_string(),
+                data: Vec::new(),
+                driver
+            },
+
+            Self::Custom(name) => anyhow::bail!("Unsupported hash type: '{name}'")
+        };
+
+        Ok(hasher)
+    }
+
+    /// Calculate hash of the given data
+    pub fn hash(&self, data: impl AsRef<[u8]>, driver: &Driver) -> anyhow::Result<String> {
+        let mut hasher = self.hasher(driver)?;
+
+        hasher.update(data)?;
+        hasher.finalize()
+    }
+
+    /// Calculate hash of the data from the given reader
+    /// 
+    /// Data is read and hashed by `HASH_BUFFER_SIZE` chunks
+    pub fn hash_reader(&self, mut reader: impl Read, driver: &Driver) -> anyhow::Result<String> {
+        let mut hasher = self.hasher(driver)?;
+        let mut buffer = vec![0; HASH_BUFFER_SIZE];
+
+        loop {
+            let read = reader.read(&mut buffer)?;
+
+            if read == 0 {
+                break;
+            }
+
+            hasher.update(&buffer[..read])?;
+        }
+
+        hasher.finalize()
+    }
+
+    #[inline]
+    /// Calculate hash of the given file
+    pub fn hash_file(&self, path: impl AsRef<Path>, driver: &Driver) -> anyhow::Result<String> {
+        self.hash_reader(File::open(path)?, driver)
+    }
+}
+
+/// Streaming hasher of the `HashType`
+pub enum Hasher<'a> {
+    Md5(md5::Md5),
+    Sha1(sha1::Sha1),
+    Sha256(sha2::Sha256),
+    Crc32(crc32fast::Hasher),
+
+    Xxhash32(xxhash_rust::xxh32::Xxh32),
+    Xxhash64(xxhash_rust::xxh64::Xxh64),
+
+    // xxh3 state is pretty big so it's stored on the heap
+    Xxhash3_64(Box<xxhash_rust::xxh3::Xxh3>),
+    Xxhash3_128(Box<xxhash_rust::xxh3::Xxh3>),
+
+    Blake3(Box<blake3::Hasher>),
+
+    Script(IntegrityHasher<'a>),
+
+    /// Custom hash of the script which can only hash all the data at once
+    Buffered {
+        name: String,
+        data: Vec<u8>,
+        driver: &'a Driver
+    }
+}
+
+impl<'a> Hasher<'a> {
+    /// Pass next chunk of data to the hasher
+    pub fn update(&mut self, data: impl AsRef<[u8]>) -> anyhow::Result<()> {
+        let data = data.as_ref();
+
+        match self {
+            Self::Md5(hasher) => {
+                use md5::Digest;
+
+                hasher.update(data);
+            }
+
+            Self::Sha1(hasher) => {
+                use sha1::Digest;
+
+                hasher.update(data);
+            }
+
+            Self::Sha256(hasher) => {
+                use sha2::Digest;
+
+                hasher.update(data);
+            }
+
+            Self::Crc32(hasher) => hasher.update(data),
+
+            Self::Xxhash32(hasher) => hasher.update(data),
+            Self::Xxhash64(hasher) => hasher.update(data),
+
+            Self::Xxhash3_64(hasher) |
+            Self::Xxhash3_128(hasher) => hasher.update(data),
+
+            Self::Blake3(hasher) => {
+                hasher.update(data);
+            }
+
+            Self::Script(hasher) => hasher.update(data)?,
+
+            Self::Buffered { data: buffer, .. } => buffer.extend_from_slice(data)
+        }
+
+        Ok(())
+    }
+
+    /// Get hash of all the passed data
+    pub fn finalize(self) -> anyhow::Result<String> {
+        let hash = match self {
+            Self::Md5(hasher) => {
+                use md5::Digest;
+
+                format!("{:x}", hasher.finalize())
+            }
+
+            Self::Sha1(hasher) => {
+                use sha1::Digest;
+
+                format!("{:x}", hasher.finalize())
+            }
+
+            Self::Sha256(hasher) => {
+                use sha2::Digest;
+
+                format!("{:x}", hasher.finalize())
+            }
+
+            Self::Crc32(hasher) => hasher.finalize().to_string(),
+
+            Self::Xxhash32(hasher)    => format!("{:x}", hasher.digest()),
+            Self::Xxhash64(hasher)    => format!("{:x}", hasher.digest()),
+            Self::Xxhash3_64(hasher)  => format!("{:x}", hasher.digest()),
+            Self::Xxhash3_128(hasher) => format!("{:x}", hasher.digest128()),
+
+            Self::Blake3(hasher) => hasher.finalize().to_hex().to_string(),
+
+            Self::Script(hasher) => hasher.finalize()?,
+
+            Self::Buffered { name, data, driver } => driver.integrity_hash(&name, data)?
+        };
+
+        Ok(hash)
+    }
 }
@@ -0,0 +1,74 @@
+use mlua::prelude::*;
+
+use super::IntegrationStandard;
+
+/// List of URIs pointing to the same file
+///
+/// The first URI is considered the main one,
+/// others are used as fallbacks
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Mirrors {
+    pub uris: Vec<String>
+}
+
+impl Mirrors {
+    pub fn from_lua(value: LuaValue, standard: IntegrationStandard) -> anyhow::Result<Self> {
+        match standard {
+            IntegrationStandard::V1 => {
+                let uris = match value {
+                    LuaValue::String(uri) => vec![uri.to_str()?.to_string()],
+
+                    LuaValue::Table(table) => table.sequence_values::<String>()
+                        .collect::<Result<Vec<_>, _>>()?,
+
+                    value => anyhow::bail!("Wrong v1 mirrors type: '{}'", value.type_name())
+                };
+
+                if uris.is_empty() {
+                    anyhow::bail!("Mirrors list must contain at least one URI");
+                }
+
+                Ok(Self { uris })
+            }
+        }
+    }
+
+    pub fn to_lua<'a>(&self, lua: &'a Lua, standard: IntegrationStandard) -> anyhow::Result<LuaValue<'a>> {
+        match standard {
+            IntegrationStandard::V1 => {
+                // Keep single URIs as plain strings for older scripts
+                if let [uri] = self.uris.as_slice() {
+                    return Ok(LuaValue::String(lua.create_string(uri)?));
+                }
+
+                let table = lua.create_table()?;
+
+                for uri in &self.uris {
+                    table.push(uri.as_str())?;
+                }
+
+                Ok(LuaValue::Table(table))
+            }
+        }
+    }
+
+    #[inline]
+    /// Get the main URI
+    pub fn primary(&self) -> &str {
+        &self.uris[0]
+    }
+
+    /// Get file name from the main URI
+    pub fn file_name(&self) -> String {
+        crate::network::get_file_name(self.primary())
+    }
+}
+
+impl From<String> for Mirrors {
+    #[inline]
+    fn from(uri: String) -> Self {
+        Self {
+            uris: vec![uri]
+        }
+    }
+}
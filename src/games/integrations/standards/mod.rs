@@ -1,6 +1,7 @@
 pub mod game;
 pub mod addons;
 pub mod diff;
+pub mod mirrors;
 pub mod download;
 pub mod integrity;
 pub mod network;
@@ -14,6 +15,7 @@ pub mod prelude {
 
     pub use super::addons::*;
     pub use super::diff::*;
+    pub use super::mirrors::*;
     pub use super::download::*;
     pub use super::integrity::*;
     pub use super::network::*;
@@ -2,7 +2,7 @@ use std::collections::HashMap;
 
 use mlua::prelude::*;
 
-pub use anime_game_core::network::minreq::Method as RequestMethod;
+pub use crate::network::backend::Method as RequestMethod;
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct RequestOptions {
@@ -0,0 +1,129 @@
+use std::collections::{HashMap, HashSet};
+use std::path::{Path, PathBuf};
+
+use crate::config::games::settings::edition_addons::GameEditionAddon;
+
+/// File of the layer addon which replaces the same file
+/// of the game or of the previously merged layer
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct LayerOverride {
+    /// Path relative to the game folder
+    pub path: PathBuf,
+
+    /// Layer which provides the file
+    pub layer: GameEditionAddon,
+
+    /// Layer which file is overridden, or `None` if it's a game file
+    pub overridden: Option<GameEditionAddon>
+}
+
+/// Files of the layer addon
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct LayerFiles {
+    /// Paths relative to the layer folder
+    pub files: Vec<PathBuf>,
+
+    /// Files which exist in the game folder as well
+    pub game_files: HashSet<PathBuf>
+}
+
+/// Sort layers according to the given load order
+///
+/// Layers which are not mentioned in the order are kept
+/// in their original order after the mentioned ones
+pub fn sort_layers<T>(order: &[GameEditionAddon], layers: Vec<(GameEditionAddon, T)>) -> Vec<(GameEditionAddon, T)> {
+    let (mut ordered, unordered): (Vec<_>, Vec<_>) = layers.into_iter()
+        .partition(|(layer, _)| order.contains(layer));
+
+    ordered.sort_by_key(|(layer, _)| order.iter().position(|entry| entry == layer));
+    ordered.extend(unordered);
+
+    ordered
+}
+
+/// Get relative paths of all the files in the given folder
+///
+/// Symlinks are listed as files and are never followed
+fn get_files(root: &Path, folder: &Path, files: &mut Vec<PathBuf>) -> anyhow::Result<()> {
+    for entry in folder.read_dir()?.flatten() {
+        let path = entry.path();
+
+        if entry.file_type()?.is_dir() {
+            get_files(root, &path, files)?;
+        }
+
+        else {
+            files.push(path.strip_prefix(root)?.to_path_buf());
+        }
+    }
+
+    Ok(())
+}
+
+/// Scan files of the layer folder
+///
+/// Empty list is returned if the layer is not installed
+pub fn get_layer_files(game_path: impl AsRef<Path>, layer_path: impl AsRef<Path>) -> anyhow::Result<LayerFiles> {
+    let game_path = game_path.as_ref();
+    let layer_path = layer_path.as_ref();
+
+    let mut files = Vec::new();
+
+    if layer_path.is_dir() {
+        get_files(layer_path, layer_path, &mut files)?;
+    }
+
+    let game_files = files.iter()
+        .filter(|path| game_path.join(path).symlink_metadata().is_ok())
+        .cloned()
+        .collect();
+
+    Ok(LayerFiles {
+        files,
+        game_files
+    })
+}
+
+/// Find files which are overridden by the layers with already scanned files
+///
+/// Layers must be given in their load order
+pub fn find_overrides<'a>(layers: impl IntoIterator<Item = (&'a GameEditionAddon, &'a LayerFiles)>) -> Vec<LayerOverride> {
+    let mut owners = HashMap::<&Path, &GameEditionAddon>::new();
+    let mut overrides = Vec::new();
+
+    for (layer, files) in layers {
+        for path in &files.files {
+            let overridden = match owners.get(path.as_path()) {
+                Some(owner) => Some(Some((*owner).clone())),
+                None if files.game_files.contains(path) => Some(None),
+                None => None
+            };
+
+            if let Some(overridden) = overridden {
+                overrides.push(LayerOverride {
+                    path: path.clone(),
+                    layer: layer.clone(),
+                    overridden
+                });
+            }
+
+            owners.insert(path.as_path(), layer);
+        }
+    }
+
+    overrides
+}
+
+/// Find files which are overridden by the layers
+///
+/// Layers must be given in their load order.
+/// Layers which are not installed are skipped
+pub fn get_overrides(game_path: impl AsRef<Path>, layers: &[(GameEditionAddon, PathBuf)]) -> anyhow::Result<Vec<LayerOverride>> {
+    let game_path = game_path.as_ref();
+
+    let files = layers.iter()
+        .map(|(_, layer_path)| get_layer_files(game_path, layer_path))
+        .collect::<anyhow::Result<Vec<_>>>()?;
+
+    Ok(find_overrides(layers.iter().map(|(layer, _)| layer).zip(&files)))
+}
@@ -4,6 +4,9 @@ use crate::config;
 
 pub mod integrations;
 pub mod metadata;
+pub mod predownload;
+pub mod layers;
+pub mod verify_cache;
 
 static mut GAMES_SINGLETON: Option<HashMap<String, integrations::Game>> = None;
 
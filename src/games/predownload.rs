@@ -0,0 +1,69 @@
+use std::path::{Path, PathBuf};
+
+use crate::games::integrations::standards::diff::DiffInfo;
+
+/// Name of the file which marks finished pre-download
+const FINISHED_MARKER: &str = ".finished";
+
+#[inline]
+/// Get path to the staging folder of the given game version
+pub fn get_staging_path(predownload_path: impl AsRef<Path>, version: impl AsRef<str>) -> PathBuf {
+    predownload_path.as_ref().join(version.as_ref())
+}
+
+#[inline]
+/// Check if pre-download to the given staging folder was finished
+pub fn is_finished(staging_path: impl AsRef<Path>) -> bool {
+    staging_path.as_ref().join(FINISHED_MARKER).exists()
+}
+
+#[inline]
+/// Mark pre-download to the given staging folder as finished
+pub fn mark_finished(staging_path: impl AsRef<Path>) -> anyhow::Result<()> {
+    Ok(std::fs::write(staging_path.as_ref().join(FINISHED_MARKER), [])?)
+}
+
+/// Get relative paths of the diff files within the staging folder
+pub fn get_staged_files(diff: &DiffInfo) -> Vec<String> {
+    match diff {
+        DiffInfo::Archive { uri, .. } => vec![uri.file_name()],
+
+        DiffInfo::Segments { segments, .. } => segments.iter()
+            .map(|segment| segment.file_name())
+            .collect(),
+
+        DiffInfo::Files { files, .. } => files.iter()
+            .map(|file| file.path.clone())
+            .collect(),
+
+        DiffInfo::Patch { patches, .. } => patches.iter()
+            .map(|patch| format!("{}.patch", patch.path))
+            .collect()
+    }
+}
+
+/// Move staged file to the given path if it exists
+///
+/// Returns `true` if the file was moved
+pub fn take_staged_file(staging_path: impl AsRef<Path>, relative_path: impl AsRef<Path>, path: impl AsRef<Path>) -> anyhow::Result<bool> {
+    let staged_path = staging_path.as_ref().join(relative_path);
+    let path = path.as_ref();
+
+    if !staged_path.is_file() {
+        return Ok(false);
+    }
+
+    if let Some(parent) = path.parent() {
+        if !parent.exists() {
+            std::fs::create_dir_all(parent)?;
+        }
+    }
+
+    // Staging folder can be located on another filesystem
+    if std::fs::rename(&staged_path, path).is_err() {
+        std::fs::copy(&staged_path, path)?;
+        std::fs::remove_file(&staged_path)?;
+    }
+
+    Ok(true)
+}
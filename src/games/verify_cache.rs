@@ -0,0 +1,154 @@
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::fs::Metadata;
+use std::os::unix::fs::MetadataExt;
+
+use serde::{Serialize, Deserialize};
+
+use crate::games::integrations::standards::IntegrationStandard;
+use crate::games::integrations::standards::integrity::FileHash;
+
+use crate::LAUNCHER_FOLDER;
+
+/// File which was successfully verified
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct VerifiedFile {
+    pub size: u64,
+
+    /// Modification time of the file, in seconds and nanoseconds
+    pub mtime: i64,
+    pub mtime_nsec: i64,
+
+    pub inode: u64,
+
+    pub hash_type: String,
+    pub hash: String
+}
+
+impl VerifiedFile {
+    pub fn new(metadata: &Metadata, hash: &FileHash) -> Self {
+        Self {
+            size: metadata.len(),
+            mtime: metadata.mtime(),
+            mtime_nsec: metadata.mtime_nsec(),
+            inode: metadata.ino(),
+            hash_type: hash.r#type.to_str(IntegrationStandard::V1).to_string(),
+            hash: hash.value.clone()
+        }
+    }
+
+    /// Check if the file wasn't changed since its verification
+    /// and it was verified with the given hash
+    pub fn matches(&self, metadata: &Metadata, hash: &FileHash) -> bool {
+        self.size == metadata.len() &&
+        self.mtime == metadata.mtime() &&
+        self.mtime_nsec == metadata.mtime_nsec() &&
+        self.inode == metadata.ino() &&
+        self.hash_type == hash.r#type.to_str(IntegrationStandard::V1) &&
+        self.hash == hash.value
+    }
+}
+
+/// Hashes of the game edition files from the last verification
+///
+/// Used to skip hashing of the files which
+/// weren't changed since they were verified
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct VerifyCache {
+    /// Path to the game folder
+    pub path: PathBuf,
+
+    /// Verified files, by their relative paths
+    pub files: HashMap<String, VerifiedFile>
+}
+
+impl VerifyCache {
+    #[inline]
+    /// Make empty cache of the game installed in the given folder
+    pub fn new(path: impl Into<PathBuf>) -> Self {
+        Self {
+            path: path.into(),
+            files: HashMap::new()
+        }
+    }
+
+    #[inline]
+    fn get_game_file(game: &str, edition: &str) -> PathBuf {
+        LAUNCHER_FOLDER
+            .join("games")
+            .join(game)
+            .join(edition)
+            .join("verify_cache.json")
+    }
+
+    #[inline]
+    fn get_addon_file(game: &str, edition: &str, group: &str, addon: &str) -> PathBuf {
+        LAUNCHER_FOLDER
+            .join("games")
+            .join(game)
+            .join(edition)
+            .join("addons")
+            .join(group)
+            .join(addon)
+            .join("verify_cache.json")
+    }
+
+    #[inline]
+    /// Load verification cache of the game edition installed in the given folder
+    ///
+    /// Empty cache is returned if the game was verified in another folder
+    pub fn load_for_game(game: impl AsRef<str>, edition: impl AsRef<str>, path: impl AsRef<Path>) -> anyhow::Result<Self> {
+        Self::load(Self::get_game_file(game.as_ref(), edition.as_ref()), path.as_ref())
+    }
+
+    #[inline]
+    /// Load verification cache of the game edition addon installed in the given folder
+    ///
+    /// Empty cache is returned if the addon was verified in another folder
+    pub fn load_for_addon(game: impl AsRef<str>, edition: impl AsRef<str>, group: impl AsRef<str>, addon: impl AsRef<str>, path: impl AsRef<Path>) -> anyhow::Result<Self> {
+        Self::load(Self::get_addon_file(game.as_ref(), edition.as_ref(), group.as_ref(), addon.as_ref()), path.as_ref())
+    }
+
+    #[inline]
+    pub fn save_for_game(&self, game: impl AsRef<str>, edition: impl AsRef<str>) -> anyhow::Result<()> {
+        self.save(Self::get_game_file(game.as_ref(), edition.as_ref()))
+    }
+
+    #[inline]
+    pub fn save_for_addon(&self, game: impl AsRef<str>, edition: impl AsRef<str>, group: impl AsRef<str>, addon: impl AsRef<str>) -> anyhow::Result<()> {
+        self.save(Self::get_addon_file(game.as_ref(), edition.as_ref(), group.as_ref(), addon.as_ref()))
+    }
+
+    fn load(file: PathBuf, path: &Path) -> anyhow::Result<Self> {
+        if !file.exists() {
+            return Ok(Self::new(path));
+        }
+
+        let cache = serde_json::from_slice::<Self>(&std::fs::read(file)?)?;
+
+        if cache.path != path {
+            return Ok(Self::new(path));
+        }
+
+        Ok(cache)
+    }
+
+    fn save(&self, file: PathBuf) -> anyhow::Result<()> {
+        if let Some(parent) = file.parent() {
+            if !parent.exists() {
+                std::fs::create_dir_all(parent)?;
+            }
+        }
+
+        std::fs::write(file, serde_json::to_vec(self)?)?;
+
+        Ok(())
+    }
+
+    /// Check if the file wasn't changed since it was verified with the given hash
+    pub fn is_verified(&self, relative_path: &str, metadata: &Metadata, hash: &FileHash) -> bool {
+        self.files.get(relative_path)
+            .map(|file| file.matches(metadata, hash))
+            .unwrap_or(false)
+    }
+}
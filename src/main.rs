@@ -5,6 +5,10 @@ use relm4::prelude::*;
 pub mod i18n;
 pub mod utils;
 pub mod config;
+pub mod network;
+pub mod patch;
+pub mod extract;
+pub mod cache;
 pub mod games;
 pub mod components;
 pub mod ui;
@@ -0,0 +1,249 @@
+use std::collections::HashMap;
+use std::fs::File;
+use std::io::{Read, Seek, SeekFrom};
+use std::path::{Path, PathBuf};
+
+use serde_json::Value as Json;
+
+use super::{NetworkBackend, Request, Response, Method};
+
+/// Backend which serves responses from a local folder
+///
+/// Response body of `https://example.com/path/file.json` is read from
+/// `<folder>/example.com/path/file.json`, which is the same layout
+/// `wget --force-directories` saves files with. URIs ending with `/`
+/// are served from `index` files. If the URI has a query then
+/// `file.json?<query>` is tried before `file.json`.
+///
+/// Status code and headers can be overridden by the
+/// `file.json.response.json` file:
+///
+/// ```json
+/// {
+///     "status": 404,
+///     "reason": "Not Found",
+///     "headers": {
+///         "content-type": "text/plain"
+///     }
+/// }
+/// ```
+///
+/// Missing fixtures are served as 404 responses.
+/// `Range` header is supported for files with 200 status code
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct FixturesBackend {
+    folder: PathBuf
+}
+
+impl FixturesBackend {
+    #[inline]
+    pub fn new(folder: impl Into<PathBuf>) -> Self {
+        Self {
+            folder: folder.into()
+        }
+    }
+
+    #[inline]
+    pub fn folder(&self) -> &Path {
+        &self.folder
+    }
+
+    /// Get paths to the fixtures which can serve the given URI,
+    /// from the most to the least specific one
+    pub fn get_paths(&self, uri: &str) -> Vec<PathBuf> {
+        let uri = uri.split('#')
+            .next()
+            .unwrap_or(uri);
+
+        let (uri, query) = match uri.split_once('?') {
+            Some((uri, query)) => (uri, Some(query)),
+            None => (uri, None)
+        };
+
+        let host = crate::network::get_host(uri);
+
+        // user:password@example.com -> example.com
+        let host = host.rsplit_once('@')
+            .map(|(_, host)| host)
+            .unwrap_or(host);
+
+        let path = uri.split_once("://")
+            .map(|(_, uri)| uri)
+            .unwrap_or(uri);
+
+        let path = path.split_once('/')
+            .map(|(_, path)| path)
+            .unwrap_or_default();
+
+        let mut file = self.folder.join(host);
+
+        // Skip empty and relative segments so fixtures can't be read from outside the folder
+        for segment in path.split('/') {
+            if !segment.is_empty() && segment != "." && segment != ".." {
+                file.push(segment);
+            }
+        }
+
+        if path.is_empty() || path.ends_with('/') {
+            file.push("index");
+        }
+
+        let mut paths = Vec::with_capacity(2);
+
+        if let Some(query) = query {
+            let mut with_query = file.clone().into_os_string();
+
+            with_query.push(format!("?{query}"));
+
+            paths.push(PathBuf::from(with_query));
+        }
+
+        paths.push(file);
+
+        paths
+    }
+}
+
+/// Status code, reason and headers of the fixture
+fn read_metadata(path: &Path) -> anyhow::Result<Option<(i32, String, HashMap<String, String>)>> {
+    let mut metadata_path = path.as_os_str().to_owned();
+
+    metadata_path.push(".response.json");
+
+    let metadata_path = PathBuf::from(metadata_path);
+
+    if !metadata_path.exists() {
+        return Ok(None);
+    }
+
+    let metadata = serde_json::from_slice::<Json>(&std::fs::read(metadata_path)?)?;
+
+    let status = metadata.get("status")
+        .and_then(Json::as_i64)
+        .unwrap_or(200) as i32;
+
+    let reason = metadata.get("reason")
+        .and_then(Json::as_str)
+        .map(String::from)
+        .unwrap_or_else(|| get_reason(status).to_string());
+
+    let headers = metadata.get("headers")
+        .and_then(Json::as_object)
+        .map(|headers| {
+            headers.iter()
+                .filter_map(|(key, value)| {
+                    value.as_str().map(|value| (key.to_ascii_lowercase(), value.to_string()))
+                })
+                .collect()
+        })
+        .unwrap_or_default();
+
+    Ok(Some((status, reason, headers)))
+}
+
+fn get_reason(status: i32) -> &'static str {
+    match status {
+        200 => "OK",
+        206 => "Partial Content",
+        404 => "Not Found",
+        416 => "Range Not Satisfiable",
+
+        _ => ""
+    }
+}
+
+/// Parse `Range` header value into the inclusive range of bytes
+///
+/// `None` if the range is not satisfiable
+fn parse_range(range: &str, size: u64) -> Option<(u64, u64)> {
+    let (start, end) = range.trim()
+        .strip_prefix("bytes=")?
+        .split_once('-')?;
+
+    // bytes=-100
+    if start.is_empty() {
+        let length = end.parse::<u64>().ok()?.min(size);
+
+        if length == 0 {
+            return None;
+        }
+
+        return Some((size - length, size - 1));
+    }
+
+    let start = start.parse::<u64>().ok()?;
+
+    // bytes=100-
+    let end = match end {
+        "" => size.saturating_sub(1),
+        end => end.parse::<u64>().ok()?.min(size.saturating_sub(1))
+    };
+
+    (start < size && start <= end).then_some((start, end))
+}
+
+impl NetworkBackend for FixturesBackend {
+    fn send(&self, request: Request) -> anyhow::Result<Response> {
+        for path in self.get_paths(&request.uri) {
+            let metadata = read_metadata(&path)?;
+
+            if !path.is_file() && metadata.is_none() {
+                continue;
+            }
+
+            tracing::trace!("Serving {} from fixture {path:?}", request.uri);
+
+            let (status, reason, mut headers) = metadata.unwrap_or_else(|| (200, String::from("OK"), HashMap::new()));
+
+            if !path.is_file() {
+                headers.insert(String::from("content-length"), String::from("0"));
+
+                return Ok(Response::new(request.uri, status, reason, headers, std::io::empty()));
+            }
+
+            let mut file = File::open(&path)?;
+
+            let size = file.metadata()?.len();
+
+            let range = request.header("range")
+                .filter(|_| status == 200);
+
+            let (status, reason, start, length) = match range {
+                Some(range) => match parse_range(range, size) {
+                    Some((start, end)) => {
+                        headers.insert(String::from("content-range"), format!("bytes {start}-{end}/{size}"));
+
+                        (206, String::from(get_reason(206)), start, end - start + 1)
+                    }
+
+                    None => {
+                        headers.insert(String::from("content-range"), format!("bytes */{size}"));
+                        headers.insert(String::from("content-length"), String::from("0"));
+
+                        return Ok(Response::new(request.uri, 416, get_reason(416), headers, std::io::empty()));
+                    }
+                }
+
+                None => (status, reason, 0, size)
+            };
+
+            headers.insert(String::from("content-length"), length.to_string());
+
+            if request.method == Method::Head {
+                return Ok(Response::new(request.uri, status, reason, headers, std::io::empty()));
+            }
+
+            file.seek(SeekFrom::Start(start))?;
+
+            return Ok(Response::new(request.uri, status, reason, headers, file.take(length)));
+        }
+
+        tracing::warn!("Fixture not found for {}", request.uri);
+
+        let mut headers = HashMap::new();
+
+        headers.insert(String::from("content-length"), String::from("0"));
+
+        Ok(Response::new(request.uri, 404, get_reason(404), headers, std::io::empty()))
+    }
+}
@@ -0,0 +1,123 @@
+use std::collections::HashMap;
+use std::time::Duration;
+
+use anime_game_core::network::minreq;
+
+use crate::config::general::prelude::Proxy;
+
+use super::{NetworkBackend, Request, Response};
+
+/// Backend which sends requests to the internet
+///
+/// Requests are sent using minreq, or using ureq
+/// if they should go through a SOCKS proxy
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HttpBackend {
+    proxy: Proxy
+}
+
+impl HttpBackend {
+    #[inline]
+    pub fn new(proxy: Proxy) -> Self {
+        Self {
+            proxy
+        }
+    }
+}
+
+#[inline]
+/// Check if the proxy URI uses SOCKS protocol
+fn is_socks_proxy(proxy: &str) -> bool {
+    ["socks4://", "socks4a://", "socks5://"].iter()
+        .any(|protocol| proxy.starts_with(protocol))
+}
+
+/// Send request using minreq and optional HTTP proxy
+fn send_http(request: Request, proxy: Option<&str>) -> anyhow::Result<Response> {
+    let mut http_request = minreq::Request::new(request.method, &request.uri);
+
+    if let Some(proxy) = proxy {
+        http_request = http_request.with_proxy(minreq::Proxy::new(proxy)?);
+    }
+
+    for (key, value) in request.headers {
+        http_request = http_request.with_header(key, value);
+    }
+
+    if let Some(body) = request.body {
+        http_request = http_request.with_body(body);
+    }
+
+    if let Some(timeout) = request.timeout {
+        http_request = http_request.with_timeout(timeout);
+    }
+
+    let response = http_request.send_lazy()?;
+
+    Ok(Response::new(
+        response.url.clone(),
+        response.status_code,
+        response.reason_phrase.clone(),
+        response.headers.clone(),
+        response
+    ))
+}
+
+/// Send request using ureq and SOCKS proxy
+fn send_socks(request: Request, proxy: &str) -> anyhow::Result<Response> {
+    let mut agent = ureq::AgentBuilder::new()
+        .proxy(ureq::Proxy::new(proxy)?);
+
+    if let Some(timeout) = request.timeout {
+        agent = agent.timeout(Duration::from_secs(timeout));
+    }
+
+    let mut socks_request = agent.build()
+        .request(&request.method.to_string(), &request.uri);
+
+    for (key, value) in &request.headers {
+        socks_request = socks_request.set(key, value);
+    }
+
+    let response = match request.body {
+        Some(body) => socks_request.send_bytes(&body),
+        None => socks_request.call()
+    };
+
+    // ureq returns responses with 4xx and 5xx status codes as errors
+    let response = match response {
+        Ok(response) | Err(ureq::Error::Status(_, response)) => response,
+        Err(err) => return Err(err.into())
+    };
+
+    let headers = response.headers_names()
+        .into_iter()
+        .filter_map(|name| {
+            let value = response.header(&name)?.to_string();
+
+            Some((name.to_ascii_lowercase(), value))
+        })
+        .collect::<HashMap<_, _>>();
+
+    Ok(Response::new(
+        response.get_url().to_string(),
+        response.status() as i32,
+        response.status_text().to_string(),
+        headers,
+        response.into_reader()
+    ))
+}
+
+impl NetworkBackend for HttpBackend {
+    fn send(&self, request: Request) -> anyhow::Result<Response> {
+        match self.proxy.get_proxy(&request.uri) {
+            Some(proxy) if is_socks_proxy(proxy) => send_socks(request, proxy),
+
+            Some(proxy) if !proxy.starts_with("http://") && proxy.contains("://") => {
+                anyhow::bail!("Unsupported proxy protocol, only HTTP and SOCKS proxies are supported: {proxy}");
+            }
+
+            proxy => send_http(request, proxy)
+        }
+    }
+}
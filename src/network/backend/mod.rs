@@ -0,0 +1,182 @@
+use std::collections::HashMap;
+use std::io::Read;
+use std::sync::{Arc, RwLock};
+
+use serde::de::DeserializeOwned;
+
+use anime_game_core::network::minreq;
+
+use crate::config;
+
+pub mod http;
+pub mod fixtures;
+
+pub use minreq::Method;
+
+pub use http::HttpBackend;
+pub use fixtures::FixturesBackend;
+
+lazy_static::lazy_static! {
+    static ref BACKEND: RwLock<Option<Arc<dyn NetworkBackend>>> = RwLock::new(None);
+}
+
+/// Backend which sends all the launcher and integration scripts requests
+pub trait NetworkBackend: Send + Sync {
+    /// Send request and return response with not yet read body
+    ///
+    /// Error is returned only if the response couldn't be received,
+    /// so responses with any status codes are returned as is
+    fn send(&self, request: Request) -> anyhow::Result<Response>;
+}
+
+/// Get network backend used to send requests
+///
+/// Backend set with `set_backend` is returned if available.
+/// Otherwise fixtures backend is used if the fixtures folder
+/// is set in the config, and the HTTP backend if it's not
+pub fn get_backend() -> Arc<dyn NetworkBackend> {
+    if let Some(backend) = BACKEND.read().ok().and_then(|backend| backend.clone()) {
+        return backend;
+    }
+
+    let config = config::get().general.network;
+
+    match config.fixtures {
+        Some(path) => Arc::new(FixturesBackend::new(path)),
+        None => Arc::new(HttpBackend::new(config.proxy))
+    }
+}
+
+/// Override network backend chosen from the config
+///
+/// `None` restores the default behavior
+pub fn set_backend(backend: Option<Arc<dyn NetworkBackend>>) {
+    if let Ok(mut current) = BACKEND.write() {
+        *current = backend;
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Request {
+    pub method: Method,
+    pub uri: String,
+    pub headers: Vec<(String, String)>,
+    pub body: Option<Vec<u8>>,
+
+    /// Request timeout, in seconds
+    pub timeout: Option<u64>
+}
+
+impl Request {
+    #[inline]
+    pub fn new(method: Method, uri: impl ToString) -> Self {
+        Self {
+            method,
+            uri: uri.to_string(),
+            headers: Vec::new(),
+            body: None,
+            timeout: None
+        }
+    }
+
+    #[inline]
+    pub fn with_header(mut self, key: impl ToString, value: impl ToString) -> Self {
+        self.headers.push((key.to_string(), value.to_string()));
+
+        self
+    }
+
+    #[inline]
+    pub fn with_body(self, body: impl Into<Vec<u8>>) -> Self {
+        Self {
+            body: Some(body.into()),
+            ..self
+        }
+    }
+
+    #[inline]
+    pub fn with_timeout(self, timeout: u64) -> Self {
+        Self {
+            timeout: Some(timeout),
+            ..self
+        }
+    }
+
+    /// Get value of the header with the given case-insensitive name
+    pub fn header(&self, key: &str) -> Option<&str> {
+        self.headers.iter()
+            .find(|(name, _)| name.eq_ignore_ascii_case(key))
+            .map(|(_, value)| value.as_str())
+    }
+
+    #[inline]
+    /// Send request using the current network backend
+    pub fn send(self) -> anyhow::Result<Response> {
+        get_backend().send(self)
+    }
+}
+
+pub struct Response {
+    /// Final URI of the response
+    pub url: String,
+
+    pub status_code: i32,
+    pub reason_phrase: String,
+
+    /// Response headers with lowercase names
+    pub headers: HashMap<String, String>,
+
+    body: Box<dyn Read + Send>
+}
+
+impl Response {
+    #[inline]
+    pub fn new(url: impl ToString, status_code: i32, reason_phrase: impl ToString, headers: HashMap<String, String>, body: impl Read + Send + 'static) -> Self {
+        Self {
+            url: url.to_string(),
+            status_code,
+            reason_phrase: reason_phrase.to_string(),
+            headers,
+            body: Box::new(body)
+        }
+    }
+
+    #[inline]
+    /// Check if the status code is 2xx
+    pub fn is_ok(&self) -> bool {
+        (200..300).contains(&self.status_code)
+    }
+
+    /// Read the whole response body
+    pub fn into_bytes(mut self) -> anyhow::Result<Vec<u8>> {
+        let mut body = Vec::new();
+
+        self.body.read_to_end(&mut body)?;
+
+        Ok(body)
+    }
+
+    #[inline]
+    /// Read the whole response body and decode it as JSON
+    pub fn json<T: DeserializeOwned>(self) -> anyhow::Result<T> {
+        Ok(serde_json::from_slice(&self.into_bytes()?)?)
+    }
+}
+
+impl Read for Response {
+    #[inline]
+    fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+        self.body.read(buf)
+    }
+}
+
+impl std::fmt::Debug for Response {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Response")
+            .field("url", &self.url)
+            .field("status_code", &self.status_code)
+            .field("reason_phrase", &self.reason_phrase)
+            .field("headers", &self.headers)
+            .finish()
+    }
+}
@@ -0,0 +1,176 @@
+use std::path::Path;
+use std::io::{Read, Write, BufWriter};
+use std::fs::OpenOptions;
+use std::time::{Duration, Instant};
+
+use crate::utils::pretty_bytes;
+
+/// Size of the buffer used to read response body
+const BUFFER_SIZE: usize = 64 * 1024;
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Downloader {
+    uri: String,
+    continue_downloading: bool,
+    min_speed: u64,
+    min_speed_timeout: Duration
+}
+
+impl Downloader {
+    #[inline]
+    pub fn new(uri: impl ToString) -> Self {
+        Self {
+            uri: uri.to_string(),
+            continue_downloading: true,
+            min_speed: 0,
+            min_speed_timeout: Duration::from_secs(30)
+        }
+    }
+
+    #[inline]
+    /// Continue downloading of the existing file instead of downloading it from scratch
+    pub fn with_continue_downloading(self, continue_downloading: bool) -> Self {
+        Self {
+            continue_downloading,
+            ..self
+        }
+    }
+
+    #[inline]
+    /// Abort downloading if the average speed during the given timeout is lower than `min_speed` bytes per second
+    pub fn with_min_speed(self, min_speed: u64, timeout: Duration) -> Self {
+        Self {
+            min_speed,
+            min_speed_timeout: timeout,
+            ..self
+        }
+    }
+
+    #[inline]
+    pub fn uri(&self) -> &str {
+        &self.uri
+    }
+
+    #[inline]
+    pub fn file_name(&self) -> String {
+        super::get_file_name(&self.uri)
+    }
+
+    /// Download file to the given path
+    ///
+    /// `progress` callback is called with `(current, total)` downloaded bytes.
+    /// `total` is 0 if the server didn't report the file size.
+    /// Downloading is stopped if the callback returns an error
+    pub fn download(&self, path: impl AsRef<Path>, mut progress: impl FnMut(u64, u64) -> anyhow::Result<()>) -> anyhow::Result<()> {
+        let path = path.as_ref();
+
+        if let Some(parent) = path.parent() {
+            if !parent.exists() {
+                std::fs::create_dir_all(parent)?;
+            }
+        }
+
+        let mut offset = 0;
+
+        if path.exists() {
+            if self.continue_downloading {
+                offset = path.metadata()?.len();
+            }
+
+            // Remove the file instead of truncating it
+            // so we don't write through hard links
+            else {
+                std::fs::remove_file(path)?;
+            }
+        }
+
+        let mut request = super::get(&self.uri);
+
+        if offset > 0 {
+            request = request.with_header("Range", format!("bytes={offset}-"));
+        }
+
+        let mut response = request.send()?;
+
+        match response.status_code {
+            // Server ignored the range header so we have to start over
+            200 => offset = 0,
+
+            206 => (),
+
+            // Requested range is not satisfiable which means the file is already downloaded
+            416 if offset > 0 => {
+                return progress(offset, offset);
+            }
+
+            code => anyhow::bail!("Failed to download file from {}: {code} {}", self.uri, response.reason_phrase)
+        }
+
+        let total = response.headers.get("content-length")
+            .and_then(|length| length.parse::<u64>().ok())
+            .map(|length| length + offset)
+            .unwrap_or(0);
+
+        let file = OpenOptions::new()
+            .create(true)
+            .write(true)
+            .append(offset > 0)
+            .truncate(offset == 0)
+            .open(path)?;
+
+        let mut file = BufWriter::new(file);
+        let mut buffer = vec![0; BUFFER_SIZE];
+
+        let mut current = offset;
+
+        let mut window_start = Instant::now();
+        let mut window_bytes = 0;
+
+        progress(current, total)?;
+
+        loop {
+            let read = response.read(&mut buffer)?;
+
+            if read == 0 {
+                break;
+            }
+
+            file.write_all(&buffer[..read])?;
+
+            current += read as u64;
+            window_bytes += read as u64;
+
+            if let Err(err) = progress(current, total) {
+                file.flush()?;
+
+                return Err(err);
+            }
+
+            // Check download speed
+            if self.min_speed > 0 {
+                let elapsed = window_start.elapsed();
+
+                if elapsed >= self.min_speed_timeout {
+                    let speed = window_bytes / elapsed.as_secs().max(1);
+
+                    if speed < self.min_speed {
+                        file.flush()?;
+
+                        anyhow::bail!("Download speed from {} is too low: {}/s", self.uri, pretty_bytes(speed));
+                    }
+
+                    window_start = Instant::now();
+                    window_bytes = 0;
+                }
+            }
+        }
+
+        file.flush()?;
+
+        if total > 0 && current != total {
+            anyhow::bail!("Connection to {} was closed before the file was downloaded: {current} of {total} bytes", self.uri);
+        }
+
+        Ok(())
+    }
+}
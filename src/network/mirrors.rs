@@ -0,0 +1,192 @@
+use std::path::{Path, PathBuf};
+use std::collections::HashMap;
+use std::time::{Duration, Instant};
+use std::sync::Mutex;
+
+use serde::{Serialize, Deserialize};
+
+use crate::games::integrations::standards::mirrors::Mirrors;
+
+use crate::config;
+use crate::LAUNCHER_FOLDER;
+
+use super::downloader::Downloader;
+
+lazy_static::lazy_static! {
+    /// Path to the file with mirrors statistics
+    pub static ref MIRRORS_FILE: PathBuf = LAUNCHER_FOLDER.join("mirrors.json");
+
+    static ref STATS: Mutex<HashMap<String, HostStats>> = Mutex::new({
+        std::fs::read(MIRRORS_FILE.as_path()).ok()
+            .and_then(|stats| serde_json::from_slice(&stats).ok())
+            .unwrap_or_default()
+    });
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct HostStats {
+    /// Smoothed download speed, in bytes per second
+    pub speed: u64,
+
+    /// Amount of failed downloads since the last successful one
+    pub failures: u64
+}
+
+/// Get statistics of the host the given URI belongs to
+pub fn get_stats(uri: &str) -> Option<HostStats> {
+    STATS.lock()
+        .expect("Failed to lock mirrors stats")
+        .get(super::get_host(uri))
+        .copied()
+}
+
+fn update_stats(uri: &str, callback: impl FnOnce(&mut HostStats)) {
+    let mut stats = STATS.lock()
+        .expect("Failed to lock mirrors stats");
+
+    callback(stats.entry(super::get_host(uri).to_string()).or_default());
+
+    let result = serde_json::to_vec_pretty(&*stats)
+        .map_err(anyhow::Error::from)
+        .and_then(|stats| Ok(std::fs::write(MIRRORS_FILE.as_path(), stats)?));
+
+    if let Err(err) = result {
+        tracing::warn!("Failed to save mirrors stats: {err}");
+    }
+}
+
+/// Remember successful download from the given URI
+pub fn report_success(uri: &str, speed: u64) {
+    update_stats(uri, |stats| {
+        stats.speed = if stats.speed == 0 {
+            speed
+        } else {
+            (stats.speed * 3 + speed) / 4
+        };
+
+        stats.failures = 0;
+    });
+}
+
+/// Remember failed download from the given URI
+pub fn report_failure(uri: &str) {
+    update_stats(uri, |stats| stats.failures += 1);
+}
+
+/// Sort mirrors from the best to the worst one
+///
+/// Hosts without failures go first, sorted by their download speed.
+/// Unknown hosts keep their original order
+pub fn sort(mirrors: &Mirrors) -> Vec<String> {
+    let mut uris = mirrors.uris.clone();
+
+    uris.sort_by_cached_key(|uri| {
+        let stats = get_stats(uri).unwrap_or_default();
+
+        (stats.failures, std::cmp::Reverse(stats.speed))
+    });
+
+    uris
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct MirrorsDownloader {
+    mirrors: Mirrors,
+    continue_downloading: bool
+}
+
+impl MirrorsDownloader {
+    #[inline]
+    pub fn new(mirrors: impl Into<Mirrors>) -> Self {
+        Self {
+            mirrors: mirrors.into(),
+            continue_downloading: true
+        }
+    }
+
+    #[inline]
+    /// Continue downloading of the existing file instead of downloading it from scratch
+    pub fn with_continue_downloading(self, continue_downloading: bool) -> Self {
+        Self {
+            continue_downloading,
+            ..self
+        }
+    }
+
+    #[inline]
+    pub fn file_name(&self) -> String {
+        self.mirrors.file_name()
+    }
+
+    /// Download file to the given path trying mirrors one by one
+    ///
+    /// The next mirror is used if the current one has failed
+    /// or its download speed was too low. The last mirror
+    /// is never dropped because of its speed.
+    /// Already downloaded data is kept between mirrors.
+    /// Downloading is stopped if the `progress` callback returns an error
+    pub fn download(&self, path: impl AsRef<Path>, mut progress: impl FnMut(u64, u64) -> anyhow::Result<()>) -> anyhow::Result<()> {
+        let config = config::get().general.network;
+
+        let path = path.as_ref();
+
+        let uris = sort(&self.mirrors);
+
+        let mut last_error = None;
+
+        for (i, uri) in uris.iter().enumerate() {
+            let mut downloader = Downloader::new(uri)
+                .with_continue_downloading(self.continue_downloading || i > 0);
+
+            // Slow mirror is worth leaving only if there's another one to try
+            if i + 1 < uris.len() {
+                downloader = downloader.with_min_speed(config.min_speed, Duration::from_secs(config.min_speed_timeout));
+            }
+
+            let begin = Instant::now();
+
+            let mut first = None;
+            let mut downloaded = 0;
+
+            // Progress callback errors are not mirror failures
+            let mut stopped = false;
+
+            let result = downloader.download(path, |current, total| {
+                let first = *first.get_or_insert(current);
+
+                downloaded = current - first;
+
+                progress(current, total).map_err(|err| {
+                    stopped = true;
+
+                    err
+                })
+            });
+
+            match result {
+                Ok(()) => {
+                    let elapsed = begin.elapsed().as_secs().max(1);
+
+                    report_success(uri, downloaded / elapsed);
+
+                    return Ok(());
+                }
+
+                Err(err) if stopped => return Err(err),
+
+                Err(err) => {
+                    tracing::warn!("Failed to download file from mirror {uri}: {err}");
+
+                    report_failure(uri);
+
+                    last_error = Some(err);
+                }
+            }
+        }
+
+        match last_error {
+            Some(err) => Err(err.context(format!("Failed to download file from all the mirrors: {:?}", self.mirrors.uris))),
+            None => anyhow::bail!("Mirrors list is empty")
+        }
+    }
+}
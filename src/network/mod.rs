@@ -0,0 +1,48 @@
+pub mod backend;
+pub mod downloader;
+pub mod mirrors;
+pub mod stream;
+
+use backend::{Request, Method};
+
+/// Get file name from the given URI
+/// 
+/// `https://example.com/path/file.zip?token=123` -> `file.zip`
+pub fn get_file_name(uri: impl AsRef<str>) -> String {
+    let uri = uri.as_ref();
+
+    let uri = uri.split(['?', '#'])
+        .next()
+        .unwrap_or(uri);
+
+    uri.trim_end_matches('/')
+        .rsplit('/')
+        .next()
+        .unwrap_or(uri)
+        .to_string()
+}
+
+/// Get host name from the given URI
+/// 
+/// `https://example.com/path/file.zip` -> `example.com`
+pub fn get_host(uri: &str) -> &str {
+    let uri = uri.split_once("://")
+        .map(|(_, uri)| uri)
+        .unwrap_or(uri);
+
+    uri.split(['/', '?', '#'])
+        .next()
+        .unwrap_or(uri)
+}
+
+#[inline]
+/// Make new request which is sent using the current network backend
+pub fn request(method: Method, uri: impl ToString) -> Request {
+    Request::new(method, uri)
+}
+
+#[inline]
+/// Make new GET request which is sent using the current network backend
+pub fn get(uri: impl ToString) -> Request {
+    Request::new(Method::Get, uri)
+}
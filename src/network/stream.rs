@@ -0,0 +1,270 @@
+use std::io::{Read, Error as IoError, ErrorKind};
+use std::time::{Duration, Instant};
+
+use crate::games::integrations::standards::mirrors::Mirrors;
+
+use crate::config;
+use crate::utils::pretty_bytes;
+
+use super::mirrors;
+use super::backend::Response;
+
+/// Amount of reconnections to every mirror
+/// without receiving any data before the stream fails
+const RECONNECT_ATTEMPTS: u64 = 3;
+
+/// Reader of the remote file
+///
+/// Connection is re-established from the current offset,
+/// using the next mirror, if the current one fails or
+/// its download speed is too low. Download speed
+/// is not checked if there's only one mirror
+pub struct MirrorsStream {
+    uris: Vec<String>,
+    current_uri: usize,
+
+    response: Option<Response>,
+
+    offset: u64,
+    total: u64,
+
+    /// Amount of failed connections since the last received data
+    failures: u64,
+
+    min_speed: u64,
+    min_speed_timeout: Duration,
+
+    window_start: Instant,
+    window_bytes: u64
+}
+
+impl MirrorsStream {
+    /// Open stream to the best available mirror
+    pub fn open(mirrors: &Mirrors) -> anyhow::Result<Self> {
+        let config = config::get().general.network;
+
+        let mut stream = Self {
+            uris: mirrors::sort(mirrors),
+            current_uri: 0,
+
+            response: None,
+
+            offset: 0,
+            total: 0,
+
+            failures: 0,
+
+            min_speed: config.min_speed,
+            min_speed_timeout: Duration::from_secs(config.min_speed_timeout),
+
+            window_start: Instant::now(),
+            window_bytes: 0
+        };
+
+        stream.reconnect()?;
+
+        Ok(stream)
+    }
+
+    #[inline]
+    /// Amount of already read bytes
+    pub fn offset(&self) -> u64 {
+        self.offset
+    }
+
+    #[inline]
+    /// Size of the remote file
+    pub fn total(&self) -> u64 {
+        self.total
+    }
+
+    fn connect(&mut self) -> anyhow::Result<()> {
+        let uri = &self.uris[self.current_uri];
+
+        let mut request = super::get(uri);
+
+        if self.offset > 0 {
+            request = request.with_header("Range", format!("bytes={}-", self.offset));
+        }
+
+        let response = request.send()?;
+
+        match response.status_code {
+            200 if self.offset == 0 => {
+                self.total = response.headers.get("content-length")
+                    .and_then(|length| length.parse::<u64>().ok())
+                    .ok_or_else(|| anyhow::anyhow!("Server didn't report size of the file: {uri}"))?;
+            }
+
+            206 if self.offset > 0 => (),
+
+            // Streamed data can't be downloaded again
+            // so servers which ignore ranges can't be used to continue streaming
+            200 => anyhow::bail!("Server doesn't support ranged requests: {uri}"),
+
+            code => anyhow::bail!("Failed to open stream from {uri}: {code} {}", response.reason_phrase)
+        }
+
+        self.response = Some(response);
+
+        self.window_start = Instant::now();
+        self.window_bytes = 0;
+
+        Ok(())
+    }
+
+    /// Connect to the current mirror, or to the next ones if it fails
+    fn reconnect(&mut self) -> anyhow::Result<()> {
+        self.response = None;
+
+        loop {
+            match self.connect() {
+                Ok(()) => return Ok(()),
+
+                Err(err) => {
+                    self.fail(&err)?;
+                }
+            }
+        }
+    }
+
+    /// Remember failure of the current mirror and switch to the next one
+    fn fail(&mut self, err: &anyhow::Error) -> anyhow::Result<()> {
+        let uri = &self.uris[self.current_uri];
+
+        tracing::warn!("Failed to stream file from mirror {uri}: {err}");
+
+        mirrors::report_failure(uri);
+
+        self.response = None;
+
+        self.failures += 1;
+        self.current_uri = (self.current_uri + 1) % self.uris.len();
+
+        if self.failures >= self.uris.len() as u64 * RECONNECT_ATTEMPTS {
+            anyhow::bail!("Failed to stream file from all the mirrors: {:?}", self.uris);
+        }
+
+        Ok(())
+    }
+
+    fn read_response(&mut self, buf: &mut [u8]) -> anyhow::Result<usize> {
+        let Some(response) = &mut self.response else {
+            anyhow::bail!("Stream is not connected");
+        };
+
+        let read = response.read(buf)?;
+
+        if read == 0 && self.offset < self.total {
+            anyhow::bail!("Connection was closed before the file was streamed: {} of {} bytes", self.offset, self.total);
+        }
+
+        self.offset += read as u64;
+        self.window_bytes += read as u64;
+
+        // Mirrors which send data are never counted as failed
+        if read > 0 {
+            self.failures = 0;
+        }
+
+        // Check download speed
+        let elapsed = self.window_start.elapsed();
+
+        if elapsed >= self.min_speed_timeout {
+            let speed = self.window_bytes / elapsed.as_secs().max(1);
+
+            // Slow mirror is worth leaving only if there's another one to try.
+            // Already read data is returned and the next read reconnects to the next mirror
+            if speed < self.min_speed && self.uris.len() > 1 {
+                self.fail(&anyhow::anyhow!("Download speed is too low: {}/s", pretty_bytes(speed)))?;
+            }
+
+            else {
+                mirrors::report_success(&self.uris[self.current_uri], speed);
+            }
+
+            self.window_start = Instant::now();
+            self.window_bytes = 0;
+        }
+
+        Ok(read)
+    }
+}
+
+impl Read for MirrorsStream {
+    fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+        if buf.is_empty() || self.offset >= self.total {
+            return Ok(0);
+        }
+
+        loop {
+            if self.response.is_none() {
+                self.reconnect()
+                    .map_err(|err| IoError::new(ErrorKind::Other, err))?;
+            }
+
+            match self.read_response(buf) {
+                Ok(read) => return Ok(read),
+
+                Err(err) => self.fail(&err)
+                    .map_err(|err| IoError::new(ErrorKind::Other, err))?
+            }
+        }
+    }
+}
+
+impl std::fmt::Debug for MirrorsStream {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("MirrorsStream")
+            .field("uri", &self.uris[self.current_uri])
+            .field("offset", &self.offset)
+            .field("total", &self.total)
+            .finish()
+    }
+}
+
+/// Fetch part of the remote file trying mirrors one by one
+///
+/// `range` is the value of the `Range` header without `bytes=` prefix,
+/// e.g. `100-199` or `-100` for the last 100 bytes.
+///
+/// Returns fetched bytes and the size of the whole file
+pub fn fetch_range(mirrors: &Mirrors, range: impl AsRef<str>) -> anyhow::Result<(Vec<u8>, u64)> {
+    let range = range.as_ref();
+
+    let mut last_error = None;
+
+    for uri in mirrors::sort(mirrors) {
+        let result = super::get(&uri)
+            .with_header("Range", format!("bytes={range}"))
+            .send()
+            .and_then(|response| {
+                if response.status_code != 206 {
+                    anyhow::bail!("Server doesn't support ranged requests: {} {}", response.status_code, response.reason_phrase);
+                }
+
+                // Content-Range: bytes 100-199/1000
+                let total = response.headers.get("content-range")
+                    .and_then(|range| range.rsplit_once('/'))
+                    .and_then(|(_, total)| total.parse::<u64>().ok())
+                    .ok_or_else(|| anyhow::anyhow!("Server didn't report size of the file"))?;
+
+                Ok((response.into_bytes()?, total))
+            });
+
+        match result {
+            Ok(result) => return Ok(result),
+
+            Err(err) => {
+                tracing::warn!("Failed to fetch range {range} from mirror {uri}: {err}");
+
+                last_error = Some(err);
+            }
+        }
+    }
+
+    match last_error {
+        Some(err) => Err(err.context(format!("Failed to fetch range {range} from all the mirrors: {:?}", mirrors.uris))),
+        None => anyhow::bail!("Mirrors list is empty")
+    }
+}
@@ -0,0 +1,130 @@
+use std::path::{Path, PathBuf};
+use std::fs::File;
+use std::io::BufWriter;
+use std::process::Command;
+
+use crate::games::integrations::standards::diff::{
+    DiffInfo,
+    DiffPatch,
+    PatchFormat
+};
+
+/// Binary used to apply hdiff patches
+const HPATCHZ_BINARY: &str = "hpatchz";
+
+/// Max size of the files loaded to memory by patches applied at the same time
+pub const PATCHES_MEMORY_LIMIT: u64 = 1024 * 1024 * 1024;
+
+/// Find `hpatchz` binary in `PATH`
+fn find_hpatchz() -> Option<PathBuf> {
+    std::env::var_os("PATH")
+        .and_then(|paths| {
+            std::env::split_paths(&paths)
+                .map(|path| path.join(HPATCHZ_BINARY))
+                .find(|path| path.is_file())
+        })
+}
+
+/// Check that all the diff patches can be applied
+pub fn check_diff(diff: &DiffInfo) -> anyhow::Result<()> {
+    if let DiffInfo::Patch { patches, .. } = diff {
+        let has_hdiff = patches.iter()
+            .any(|patch| patch.format == PatchFormat::Hdiff);
+
+        if has_hdiff && find_hpatchz().is_none() {
+            anyhow::bail!("hdiff patches require `{HPATCHZ_BINARY}` binary which is not found in PATH");
+        }
+    }
+
+    Ok(())
+}
+
+/// Get amount of memory needed to apply the patch
+pub fn get_required_memory(patch: &DiffPatch, source: impl AsRef<Path>, patch_file: impl AsRef<Path>) -> u64 {
+    match patch.format {
+        // Both the source file and the patch are loaded to memory
+        PatchFormat::Bsdiff => {
+            let source = source.as_ref().metadata().map(|metadata| metadata.len()).unwrap_or(0);
+            let patch_file = patch_file.as_ref().metadata().map(|metadata| metadata.len()).unwrap_or(patch.size);
+
+            source + patch_file
+        }
+
+        // Patching is done by an external process
+        PatchFormat::Hdiff => 0
+    }
+}
+
+/// Split patches to batches applied at the same time
+///
+/// Batches have at most `batch_size` patches. Memory needed to apply
+/// them doesn't exceed `PATCHES_MEMORY_LIMIT` unless a single patch needs more,
+/// in which case it's applied alone
+pub fn get_batches(patches: Vec<DiffPatch>, batch_size: usize, required_memory: impl Fn(&DiffPatch) -> u64) -> Vec<Vec<DiffPatch>> {
+    let mut batches: Vec<Vec<DiffPatch>> = Vec::new();
+    let mut batch_memory = 0;
+
+    for patch in patches {
+        let memory = required_memory(&patch);
+
+        let new_batch = match batches.last() {
+            Some(batch) => batch.len() >= batch_size.max(1) || batch_memory + memory > PATCHES_MEMORY_LIMIT,
+            None => true
+        };
+
+        if new_batch {
+            batches.push(Vec::new());
+
+            batch_memory = 0;
+        }
+
+        batch_memory += memory;
+
+        if let Some(batch) = batches.last_mut() {
+            batch.push(patch);
+        }
+    }
+
+    batches
+}
+
+/// Apply binary patch to the source file and save result to the target file
+///
+/// hdiff patches are applied by the `hpatchz` binary which should be available in `PATH`.
+/// Use `check_diff` to verify it before applying patches
+pub fn apply(format: PatchFormat, source: impl AsRef<Path>, patch: impl AsRef<Path>, target: impl AsRef<Path>) -> anyhow::Result<()> {
+    let target = target.as_ref();
+
+    if let Some(parent) = target.parent() {
+        if !parent.exists() {
+            std::fs::create_dir_all(parent)?;
+        }
+    }
+
+    match format {
+        PatchFormat::Bsdiff => {
+            let source = std::fs::read(source)?;
+            let patch = std::fs::read(patch)?;
+
+            let target = BufWriter::new(File::create(target)?);
+
+            qbsdiff::Bspatch::new(&patch)?
+                .apply(&source, target)?;
+        }
+
+        PatchFormat::Hdiff => {
+            let output = Command::new("hpatchz")
+                .arg("-f")
+                .arg(source.as_ref())
+                .arg(patch.as_ref())
+                .arg(target)
+                .output()?;
+
+            if !output.status.success() {
+                anyhow::bail!("Failed to apply hdiff patch: {}", String::from_utf8_lossy(&output.stderr));
+            }
+        }
+    }
+
+    Ok(())
+}
@@ -3,6 +3,8 @@ use std::collections::HashSet;
 use relm4::prelude::*;
 use adw::prelude::*;
 
+use crate::tr;
+
 use crate::config::games::settings::edition_addons::GameEditionAddon;
 
 use crate::games::integrations::standards::addons::{
@@ -14,7 +16,8 @@ use crate::ui::components::game_card::CardInfo;
 
 use super::addon_row::{
     AddonRowComponent,
-    AddonRowComponentInit
+    AddonRowComponentInit,
+    AddonRowComponentMsg
 };
 
 pub struct AddonsGroupComponentInit {
@@ -29,13 +32,22 @@ pub struct AddonsGroupComponent {
     pub addons_widgets: Vec<AsyncController<AddonRowComponent>>,
 
     pub addons_group: AddonsGroup,
-    pub game_info: CardInfo
+    pub game_info: CardInfo,
+
+    /// Addons of the group which are installed
+    pub installed_addons: Vec<Addon>
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum AddonsGroupComponentInput {
     InstallAddon(Addon),
     UninstallAddon(Addon),
+    VerifyAddon(Addon),
+
+    /// Verify all the installed addons of the group
+    VerifyGroup,
+
+    SetEnabledAddons(HashSet<GameEditionAddon>),
 
     ToggleAddon {
         addon: Addon,
@@ -55,6 +67,11 @@ pub enum AddonsGroupComponentOutput {
         group: AddonsGroup
     },
 
+    VerifyAddon {
+        addon: Addon,
+        group: AddonsGroup
+    },
+
     ToggleAddon {
         addon: GameEditionAddon,
         enabled: bool
@@ -70,7 +87,23 @@ impl SimpleAsyncComponent for AddonsGroupComponent {
     view! {
         #[root]
         group = adw::PreferencesGroup {
-            set_title: &model.addons_group.title
+            set_title: &model.addons_group.title,
+
+            #[wrap(Some)]
+            set_header_suffix = &gtk::Button {
+                set_valign: gtk::Align::Center,
+
+                set_visible: !model.installed_addons.is_empty(),
+
+                add_css_class: "flat",
+
+                adw::ButtonContent {
+                    set_icon_name: "drive-harddisk-ieee1394-symbolic",
+                    set_label: &tr!("addon-verify-all")
+                },
+
+                connect_clicked => AddonsGroupComponentInput::VerifyGroup
+            }
         }
     }
 
@@ -101,6 +134,11 @@ impl SimpleAsyncComponent for AddonsGroupComponent {
             }
         });
 
+        let installed_addons = addons.iter()
+            .filter(|(_, _, installed)| *installed)
+            .map(|(addon, _, _)| addon.clone())
+            .collect();
+
         let model = Self {
             addons_widgets: addons
                 .into_iter()
@@ -119,7 +157,8 @@ impl SimpleAsyncComponent for AddonsGroupComponent {
                 .collect(),
 
             addons_group: init.addons_group,
-            game_info: init.game_info
+            game_info: init.game_info,
+            installed_addons
         };
 
         let widgets = view_output!();
@@ -147,6 +186,28 @@ impl SimpleAsyncComponent for AddonsGroupComponent {
                 }).unwrap();
             }
 
+            AddonsGroupComponentInput::VerifyAddon(addon) => {
+                sender.output(AddonsGroupComponentOutput::VerifyAddon {
+                    addon,
+                    group: self.addons_group.clone()
+                }).unwrap();
+            }
+
+            AddonsGroupComponentInput::VerifyGroup => {
+                for addon in self.installed_addons.iter().cloned() {
+                    sender.output(AddonsGroupComponentOutput::VerifyAddon {
+                        addon,
+                        group: self.addons_group.clone()
+                    }).unwrap();
+                }
+            }
+
+            AddonsGroupComponentInput::SetEnabledAddons(enabled_addons) => {
+                for widget in &self.addons_widgets {
+                    widget.emit(AddonRowComponentMsg::SetEnabledAddons(enabled_addons.clone()));
+                }
+            }
+
             AddonsGroupComponentInput::ToggleAddon { addon, enabled } => {
                 sender.output(AddonsGroupComponentOutput::ToggleAddon {
                     addon: GameEditionAddon {
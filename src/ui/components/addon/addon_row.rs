@@ -1,8 +1,12 @@
+use std::collections::HashSet;
+
 use relm4::prelude::*;
 use adw::prelude::*;
 
 use crate::tr;
 
+use crate::config::games::settings::edition_addons::GameEditionAddon;
+
 use crate::games::integrations::standards::addons::{
     Addon,
     AddonsGroup
@@ -36,7 +40,11 @@ pub struct AddonRowComponent {
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum AddonRowComponentMsg {
     PerformAction,
-    ToggleAddon
+    ToggleAddon,
+    VerifyAddon,
+
+    /// Update switch state from the list of enabled addons
+    SetEnabledAddons(HashSet<GameEditionAddon>)
 }
 
 #[relm4::component(pub, async)]
@@ -56,6 +64,19 @@ impl SimpleAsyncComponent for AddonRowComponent {
                 String::new()
             },
 
+            add_suffix = &gtk::Button {
+                set_valign: gtk::Align::Center,
+
+                set_icon_name: "drive-harddisk-ieee1394-symbolic",
+                set_tooltip: &tr!("addon-verify"),
+
+                set_visible: model.installed,
+
+                add_css_class: "flat",
+
+                connect_clicked => AddonRowComponentMsg::VerifyAddon
+            },
+
             add_suffix = &gtk::Button {
                 set_valign: gtk::Align::Center,
 
@@ -142,6 +163,16 @@ impl SimpleAsyncComponent for AddonRowComponent {
                     enabled: self.enabled
                 }).unwrap();
             }
+
+            AddonRowComponentMsg::VerifyAddon => {
+                sender.output(AddonsGroupComponentInput::VerifyAddon(self.addon_info.clone())).unwrap();
+            }
+
+            AddonRowComponentMsg::SetEnabledAddons(enabled_addons) => {
+                self.enabled = enabled_addons.iter().any(|enabled_addon| {
+                    enabled_addon.group == self.addons_group.name && enabled_addon.name == self.addon_info.name
+                });
+            }
         }
     }
 }
@@ -0,0 +1,251 @@
+use std::path::PathBuf;
+use std::collections::{HashMap, HashSet};
+
+use relm4::prelude::*;
+use adw::prelude::*;
+
+use crate::tr;
+
+use crate::config::games::settings::edition_addons::GameEditionAddon;
+
+use crate::games::layers::{self, LayerFiles};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LayerInfo {
+    pub addon: GameEditionAddon,
+    pub title: String,
+    pub path: PathBuf
+}
+
+#[derive(Debug)]
+pub struct LayersOrderComponent {
+    pub group: adw::PreferencesGroup,
+    pub rows: Vec<adw::ActionRow>,
+
+    pub game_path: PathBuf,
+    pub layers: Vec<LayerInfo>,
+
+    /// Scanned files of the layers
+    ///
+    /// Layers are scanned in background only once
+    /// because it can take a lot of time for big addons
+    pub files: HashMap<GameEditionAddon, LayerFiles>,
+
+    /// Layers which are being scanned
+    pub scanning: HashSet<GameEditionAddon>,
+
+    /// Incremented when the cache is cleared
+    /// so outdated scan results are ignored
+    pub cache_id: u64
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum LayersOrderComponentInput {
+    SetLayers {
+        game_path: PathBuf,
+        layers: Vec<LayerInfo>
+    },
+
+    MoveLayer {
+        from: usize,
+        to: usize
+    },
+
+    SetLayersFiles {
+        cache_id: u64,
+        files: HashMap<GameEditionAddon, LayerFiles>
+    },
+
+    /// Scan layers again next time they're set
+    ClearCache
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum LayersOrderComponentOutput {
+    SetLayersOrder(Vec<GameEditionAddon>)
+}
+
+impl LayersOrderComponent {
+    /// Recreate layers rows
+    fn update_rows(&mut self, sender: &AsyncComponentSender<Self>) {
+        for row in self.rows.drain(..) {
+            self.group.remove(&row);
+        }
+
+        // Count files overridden by each layer
+        // Layers which are not scanned yet are skipped
+        let mut overrides = HashMap::<GameEditionAddon, u64>::new();
+
+        let layers = self.layers.iter()
+            .filter_map(|layer| Some((&layer.addon, self.files.get(&layer.addon)?)));
+
+        for entry in layers::find_overrides(layers) {
+            *overrides.entry(entry.layer).or_default() += 1;
+        }
+
+        for (i, layer) in self.layers.iter().enumerate() {
+            let row = adw::ActionRow::new();
+
+            row.set_title(&layer.title);
+
+            if let Some(files) = overrides.get(&layer.addon) {
+                row.set_subtitle(&tr!("addon-layer-overrides", {
+                    "files" = *files
+                }));
+            }
+
+            row.add_prefix(&gtk::Image::from_icon_name("list-drag-handle-symbolic"));
+
+            let drag_source = gtk::DragSource::new();
+
+            drag_source.set_actions(gtk::gdk::DragAction::MOVE);
+            drag_source.set_content(Some(&gtk::gdk::ContentProvider::for_value(&(i as u32).to_value())));
+
+            let drop_target = gtk::DropTarget::new(u32::static_type(), gtk::gdk::DragAction::MOVE);
+
+            drop_target.connect_drop(gtk::glib::clone!(@strong sender => move |_, value, _, _| {
+                let Ok(from) = value.get::<u32>() else {
+                    return false;
+                };
+
+                sender.input(LayersOrderComponentInput::MoveLayer {
+                    from: from as usize,
+                    to: i
+                });
+
+                true
+            }));
+
+            row.add_controller(drag_source);
+            row.add_controller(drop_target);
+
+            self.group.add(&row);
+            self.rows.push(row);
+        }
+
+        self.group.set_visible(self.layers.len() > 1);
+    }
+
+    /// Scan files of the layers which are not scanned yet in background
+    fn scan_layers(&mut self, sender: &AsyncComponentSender<Self>) {
+        let layers = self.layers.iter()
+            .filter(|layer| !self.files.contains_key(&layer.addon) && !self.scanning.contains(&layer.addon))
+            .map(|layer| (layer.addon.clone(), layer.path.clone()))
+            .collect::<Vec<_>>();
+
+        if layers.is_empty() {
+            return;
+        }
+
+        self.scanning.extend(layers.iter().map(|(addon, _)| addon.clone()));
+
+        let game_path = self.game_path.clone();
+        let cache_id = self.cache_id;
+        let sender = sender.clone();
+
+        std::thread::spawn(move || {
+            let files = layers.into_iter()
+                .map(|(addon, path)| {
+                    let files = layers::get_layer_files(&game_path, path).unwrap_or_else(|err| {
+                        tracing::warn!("Failed to scan {}/{} layer files: {err}", addon.group, addon.name);
+
+                        LayerFiles::default()
+                    });
+
+                    (addon, files)
+                })
+                .collect();
+
+            sender.input(LayersOrderComponentInput::SetLayersFiles {
+                cache_id,
+                files
+            });
+        });
+    }
+}
+
+#[relm4::component(pub, async)]
+impl SimpleAsyncComponent for LayersOrderComponent {
+    type Init = ();
+    type Input = LayersOrderComponentInput;
+    type Output = LayersOrderComponentOutput;
+
+    view! {
+        #[root]
+        group = adw::PreferencesGroup {
+            set_title: &tr!("addon-layers-order"),
+            set_description: Some(&tr!("addon-layers-order-description")),
+
+            set_visible: false
+        }
+    }
+
+    async fn init(_init: Self::Init, root: Self::Root, _sender: AsyncComponentSender<Self>) -> AsyncComponentParts<Self> {
+        let model = Self {
+            group: root.clone(),
+            rows: Vec::new(),
+
+            game_path: PathBuf::new(),
+            layers: Vec::new(),
+
+            files: HashMap::new(),
+            scanning: HashSet::new(),
+            cache_id: 0
+        };
+
+        let widgets = view_output!();
+
+        AsyncComponentParts { model, widgets }
+    }
+
+    async fn update(&mut self, msg: Self::Input, sender: AsyncComponentSender<Self>) {
+        match msg {
+            LayersOrderComponentInput::SetLayers { game_path, layers } => {
+                self.game_path = game_path;
+                self.layers = layers;
+
+                self.scan_layers(&sender);
+                self.update_rows(&sender);
+            }
+
+            LayersOrderComponentInput::MoveLayer { from, to } => {
+                if from == to || from >= self.layers.len() || to >= self.layers.len() {
+                    return;
+                }
+
+                let layer = self.layers.remove(from);
+
+                self.layers.insert(to, layer);
+
+                self.update_rows(&sender);
+
+                sender.output(LayersOrderComponentOutput::SetLayersOrder({
+                    self.layers.iter()
+                        .map(|layer| layer.addon.clone())
+                        .collect()
+                })).unwrap();
+            }
+
+            LayersOrderComponentInput::SetLayersFiles { cache_id, files } => {
+                if cache_id != self.cache_id {
+                    return;
+                }
+
+                for addon in files.keys() {
+                    self.scanning.remove(addon);
+                }
+
+                self.files.extend(files);
+
+                self.update_rows(&sender);
+            }
+
+            LayersOrderComponentInput::ClearCache => {
+                self.files.clear();
+                self.scanning.clear();
+
+                self.cache_id += 1;
+            }
+        }
+    }
+}
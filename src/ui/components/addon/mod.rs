@@ -1,2 +1,3 @@
 pub mod addon_row;
 pub mod addon_group;
+pub mod layers_order;
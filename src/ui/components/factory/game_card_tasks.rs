@@ -1,43 +1,98 @@
 use relm4::prelude::*;
+use gtk::prelude::*;
+
+use crate::tr;
 
 use crate::ui::components::game_card::{
     CardInfo,
     CardComponent,
-    CardComponentInput,
-    CardComponentOutput
+    CardComponentInput
 };
 
 #[derive(Debug)]
 pub struct CardFactory {
-    pub component: AsyncController<CardComponent>
+    pub component: AsyncController<CardComponent>,
+    pub index: DynamicIndex
+}
+
+#[derive(Debug)]
+pub enum CardFactoryOutput {
+    MoveEarlier(DynamicIndex),
+    MoveLater(DynamicIndex),
+    Cancel(DynamicIndex)
 }
 
 #[relm4::factory(pub)]
 impl FactoryComponent for CardFactory {
     type Init = CardInfo;
     type Input = CardComponentInput;
-    type Output = CardComponentOutput;
+    type Output = CardFactoryOutput;
     type CommandOutput = ();
     type ParentWidget = gtk::FlowBox;
 
     view! {
         root = gtk::Box {
+            set_orientation: gtk::Orientation::Vertical,
+            set_spacing: 4,
+
             self.component.widget(),
+
+            gtk::Box {
+                set_halign: gtk::Align::Center,
+
+                add_css_class: "linked",
+
+                gtk::Button {
+                    set_icon_name: "go-previous-symbolic",
+                    set_tooltip: &tr!("tasks-move-earlier"),
+
+                    add_css_class: "flat",
+
+                    connect_clicked[sender, index = self.index.clone()] => move |_| {
+                        sender.output(CardFactoryOutput::MoveEarlier(index.clone())).unwrap();
+                    }
+                },
+
+                gtk::Button {
+                    set_icon_name: "go-next-symbolic",
+                    set_tooltip: &tr!("tasks-move-later"),
+
+                    add_css_class: "flat",
+
+                    connect_clicked[sender, index = self.index.clone()] => move |_| {
+                        sender.output(CardFactoryOutput::MoveLater(index.clone())).unwrap();
+                    }
+                },
+
+                gtk::Button {
+                    set_icon_name: "window-close-symbolic",
+                    set_tooltip: &tr!("tasks-cancel"),
+
+                    add_css_class: "flat",
+
+                    connect_clicked[sender, index = self.index.clone()] => move |_| {
+                        sender.output(CardFactoryOutput::Cancel(index.clone())).unwrap();
+                    }
+                }
+            }
         }
     }
 
     #[inline]
-    fn init_model(init: Self::Init, _index: &DynamicIndex, sender: FactorySender<Self>) -> Self {
+    fn init_model(init: Self::Init, index: &DynamicIndex, _sender: FactorySender<Self>) -> Self {
         let component = CardComponent::builder()
             .launch(init)
-            .forward(sender.output_sender(), std::convert::identity);
+            .detach();
 
         component.emit(CardComponentInput::SetWidth(60));
         component.emit(CardComponentInput::SetHeight(84));
         component.emit(CardComponentInput::SetClickable(false));
         component.emit(CardComponentInput::SetDisplayTitle(false));
 
-        Self { component }
+        Self {
+            component,
+            index: index.clone()
+        }
     }
 
     #[inline]
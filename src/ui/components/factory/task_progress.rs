@@ -0,0 +1,206 @@
+use relm4::prelude::*;
+use gtk::prelude::*;
+
+use crate::tr;
+
+use crate::ui::components::game_card::{
+    CardInfo,
+    CardComponent,
+    CardComponentInput
+};
+
+use crate::ui::components::tasks_queue::UPDATER_TIMEOUT;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TaskProgressFactoryInit {
+    pub info: CardInfo,
+
+    /// Show task cancellation button
+    pub cancellable: bool
+}
+
+#[derive(Debug)]
+pub struct TaskProgressFactory {
+    pub card: AsyncController<CardComponent>,
+    pub info: CardInfo,
+    pub index: DynamicIndex,
+
+    pub cancellable: bool,
+
+    pub status: String,
+    pub pulse: bool,
+
+    pub progress_label: String,
+    pub progress_bar: gtk::ProgressBar,
+
+    pub speed: String,
+    pub eta: String
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum TaskProgressFactoryInput {
+    SetStatus {
+        status: String,
+        pulse: bool
+    },
+
+    SetProgress {
+        progress: f64,
+        speed: String,
+        eta: String
+    }
+}
+
+#[derive(Debug)]
+pub enum TaskProgressFactoryOutput {
+    Cancel(DynamicIndex)
+}
+
+#[relm4::factory(pub)]
+impl FactoryComponent for TaskProgressFactory {
+    type Init = TaskProgressFactoryInit;
+    type Input = TaskProgressFactoryInput;
+    type Output = TaskProgressFactoryOutput;
+    type CommandOutput = ();
+    type ParentWidget = gtk::Box;
+
+    view! {
+        root = gtk::Box {
+            set_spacing: 16,
+            set_margin_bottom: 24,
+
+            self.card.widget(),
+
+            gtk::Box {
+                set_orientation: gtk::Orientation::Vertical,
+                set_valign: gtk::Align::Center,
+                set_hexpand: true,
+
+                gtk::Box {
+                    gtk::Label {
+                        set_halign: gtk::Align::Start,
+                        set_hexpand: true,
+
+                        add_css_class: "title-4",
+
+                        set_label: self.info.get_title()
+                    },
+
+                    gtk::Button {
+                        set_icon_name: "process-stop-symbolic",
+                        set_tooltip: &tr!("tasks-cancel"),
+
+                        add_css_class: "flat",
+                        add_css_class: "circular",
+
+                        set_visible: self.cancellable,
+
+                        connect_clicked[sender, index = self.index.clone()] => move |button| {
+                            // Task can take some time to stop
+                            button.set_sensitive(false);
+
+                            sender.output(TaskProgressFactoryOutput::Cancel(index.clone())).unwrap();
+                        }
+                    }
+                },
+
+                gtk::CenterBox {
+                    set_margin_top: 8,
+
+                    #[wrap(Some)]
+                    set_start_widget = &gtk::Label {
+                        #[watch]
+                        set_label: &self.status
+                    },
+
+                    #[wrap(Some)]
+                    set_end_widget = &gtk::Label {
+                        set_margin_start: 16,
+
+                        #[watch]
+                        set_visible: !self.pulse,
+
+                        #[watch]
+                        set_label: &self.progress_label
+                    }
+                },
+
+                self.progress_bar.clone(),
+
+                gtk::Label {
+                    set_halign: gtk::Align::Start,
+
+                    set_margin_top: 8,
+
+                    #[watch]
+                    set_visible: !self.speed.is_empty() || !self.eta.is_empty(),
+
+                    #[watch]
+                    set_label: &[self.speed.as_str(), self.eta.as_str()]
+                        .into_iter()
+                        .filter(|label| !label.is_empty())
+                        .collect::<Vec<_>>()
+                        .join(" · ")
+                }
+            }
+        }
+    }
+
+    #[inline]
+    fn init_model(init: Self::Init, index: &DynamicIndex, _sender: FactorySender<Self>) -> Self {
+        let card = CardComponent::builder()
+            .launch(init.info.clone())
+            .detach();
+
+        card.emit(CardComponentInput::SetWidth(60));
+        card.emit(CardComponentInput::SetHeight(84));
+        card.emit(CardComponentInput::SetClickable(false));
+        card.emit(CardComponentInput::SetDisplayTitle(false));
+
+        let progress_bar = gtk::ProgressBar::new();
+
+        progress_bar.set_margin_top(8);
+        progress_bar.set_pulse_step(0.25 / UPDATER_TIMEOUT.as_millis() as f64);
+
+        Self {
+            card,
+            info: init.info,
+            index: index.clone(),
+
+            cancellable: init.cancellable,
+
+            status: String::new(),
+            pulse: true,
+
+            progress_label: String::new(),
+            progress_bar,
+
+            speed: String::new(),
+            eta: String::new()
+        }
+    }
+
+    fn update(&mut self, msg: Self::Input, _sender: FactorySender<Self>) {
+        match msg {
+            TaskProgressFactoryInput::SetStatus { status, pulse } => {
+                self.status = status;
+                self.pulse = pulse;
+
+                if pulse {
+                    self.progress_bar.pulse();
+                }
+            }
+
+            TaskProgressFactoryInput::SetProgress { progress, speed, eta } => {
+                if !self.pulse {
+                    self.progress_label = format!("{:.2}%", progress * 100.0);
+
+                    self.progress_bar.set_fraction(progress);
+                }
+
+                self.speed = speed;
+                self.eta = eta;
+            }
+        }
+    }
+}
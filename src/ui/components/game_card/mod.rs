@@ -1,7 +1,9 @@
 use relm4::prelude::*;
 use gtk::prelude::*;
 
-#[derive(Debug, Clone, Hash, PartialEq, Eq)]
+use serde::{Serialize, Deserialize};
+
+#[derive(Debug, Clone, Hash, PartialEq, Eq, Serialize, Deserialize)]
 pub enum CardInfo {
     Game {
         name: String,
@@ -3,6 +3,8 @@ use gtk::prelude::*;
 
 use crate::tr;
 
+use crate::config::general::prelude::UpdatePolicy;
+
 use crate::games::metadata::LauncherMetadata;
 use crate::games::integrations::standards::game::{
     Status,
@@ -15,6 +17,8 @@ use crate::ui::components::game_card::{
     CardComponentInput
 };
 
+use crate::ui::components::tasks_queue::verify_integrity_task::VerifyMode;
+
 #[derive(Debug)]
 pub struct GameDetailsComponent {
     pub game_card: AsyncController<CardComponent>,
@@ -24,9 +28,28 @@ pub struct GameDetailsComponent {
 
     pub installed: bool,
     pub running: bool,
-    pub status: Option<Status>
+    pub status: Option<Status>,
+
+    pub predownload_available: bool,
+    pub predownloaded: bool,
+
+    /// Game's own updates policy
+    pub update_policy: Option<UpdatePolicy>,
+
+    /// Names of the addons profiles of the game edition
+    pub addons_profiles: Vec<String>,
+    pub addons_profiles_model: gtk::StringList,
+    pub active_profile: String
 }
 
+/// Variants of the game updates policy dropdown
+const UPDATE_POLICIES: &[Option<UpdatePolicy>] = &[
+    None,
+    Some(UpdatePolicy::Never),
+    Some(UpdatePolicy::Notify),
+    Some(UpdatePolicy::Download)
+];
+
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum GameDetailsComponentInput {
     SetInfo(CardInfo),
@@ -35,13 +58,32 @@ pub enum GameDetailsComponentInput {
     SetRunning(bool),
     SetStatus(Option<Status>),
 
+    SetPredownload {
+        available: bool,
+        finished: bool
+    },
+
+    SetUpdatePolicy(Option<UpdatePolicy>),
+
+    SetAddonsProfiles {
+        names: Vec<String>,
+        active: String
+    },
+
     EditCard(CardComponentInput),
 
     EmitDownloadGame,
-    EmitVerifyGame,
+    EmitVerifyGame {
+        mode: VerifyMode,
+        dry_run: bool
+    },
+
+    EmitPredownloadGame,
     EmitLaunchGame,
     EmitKillGame,
-    EmitOpenAddonsManager
+    EmitOpenAddonsManager,
+    EmitSetUpdatePolicy(Option<UpdatePolicy>),
+    EmitSelectAddonsProfile(String)
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -50,11 +92,27 @@ pub enum GameDetailsComponentOutput {
     ShowTasksFlap,
 
     DownloadGame(CardInfo),
-    VerifyGame(CardInfo),
+    VerifyGame {
+        info: CardInfo,
+        mode: VerifyMode,
+        dry_run: bool
+    },
+
+    PredownloadGame(CardInfo),
     LaunchGame(CardInfo),
     KillGame(CardInfo),
     OpenAddonsManager(CardInfo),
 
+    SetUpdatePolicy {
+        info: CardInfo,
+        policy: Option<UpdatePolicy>
+    },
+
+    SelectAddonsProfile {
+        info: CardInfo,
+        profile: String
+    },
+
     ShowToast {
         title: String,
         message: Option<String>
@@ -187,18 +245,62 @@ impl SimpleAsyncComponent for GameDetailsComponent {
                             connect_clicked => GameDetailsComponentInput::EmitKillGame
                         },
 
-                        gtk::Button {
-                            adw::ButtonContent {
+                        adw::SplitButton {
+                            #[wrap(Some)]
+                            set_child = &adw::ButtonContent {
                                 set_icon_name: "drive-harddisk-ieee1394-symbolic",
                                 set_label: &tr!("details-verify")
                             },
 
-                            add_css_class: "pill",
+                            set_tooltip: &tr!("details-verify-quick-description"),
+
+                            #[wrap(Some)]
+                            #[name = "verify_popover"]
+                            set_popover = &gtk::Popover {
+                                gtk::Box {
+                                    set_orientation: gtk::Orientation::Vertical,
+
+                                    gtk::Button {
+                                        set_label: &tr!("details-verify-full"),
+                                        set_tooltip: &tr!("details-verify-full-description"),
+
+                                        add_css_class: "flat",
+
+                                        connect_clicked[sender, verify_popover] => move |_| {
+                                            verify_popover.popdown();
+
+                                            sender.input(GameDetailsComponentInput::EmitVerifyGame {
+                                                mode: VerifyMode::Full,
+                                                dry_run: false
+                                            });
+                                        }
+                                    },
+
+                                    gtk::Button {
+                                        set_label: &tr!("details-verify-dry-run"),
+                                        set_tooltip: &tr!("details-verify-dry-run-description"),
+
+                                        add_css_class: "flat",
+
+                                        connect_clicked[sender, verify_popover] => move |_| {
+                                            verify_popover.popdown();
+
+                                            sender.input(GameDetailsComponentInput::EmitVerifyGame {
+                                                mode: VerifyMode::Full,
+                                                dry_run: true
+                                            });
+                                        }
+                                    }
+                                }
+                            },
 
                             #[watch]
                             set_visible: !model.running,
 
-                            connect_clicked => GameDetailsComponentInput::EmitVerifyGame
+                            connect_clicked => GameDetailsComponentInput::EmitVerifyGame {
+                                mode: VerifyMode::Quick,
+                                dry_run: false
+                            }
                         }
                     },
 
@@ -221,6 +323,77 @@ impl SimpleAsyncComponent for GameDetailsComponent {
 
                             connect_clicked => GameDetailsComponentInput::EmitOpenAddonsManager
                         },
+
+                        gtk::Button {
+                            adw::ButtonContent {
+                                set_icon_name: "document-save-symbolic",
+
+                                #[watch]
+                                set_label: &if model.predownloaded {
+                                    tr!("details-predownloaded")
+                                } else {
+                                    tr!("details-predownload")
+                                }
+                            },
+
+                            add_css_class: "pill",
+
+                            #[watch]
+                            set_visible: model.predownload_available && !model.running,
+
+                            #[watch]
+                            set_sensitive: !model.predownloaded,
+
+                            connect_clicked => GameDetailsComponentInput::EmitPredownloadGame
+                        },
+
+                        gtk::DropDown {
+                            set_valign: gtk::Align::Center,
+                            set_tooltip: &tr!("details-update-policy"),
+
+                            set_model: Some(&gtk::StringList::new(&[
+                                &tr!("details-update-policy-default"),
+                                &tr!("details-update-policy-never"),
+                                &tr!("details-update-policy-notify"),
+                                &tr!("details-update-policy-download")
+                            ])),
+
+                            #[watch]
+                            #[block_signal(update_policy_handler)]
+                            set_selected: UPDATE_POLICIES.iter()
+                                .position(|policy| policy == &model.update_policy)
+                                .unwrap_or(0) as u32,
+
+                            connect_selected_notify[sender] => move |dropdown| {
+                                if let Some(policy) = UPDATE_POLICIES.get(dropdown.selected() as usize) {
+                                    sender.input(GameDetailsComponentInput::EmitSetUpdatePolicy(*policy));
+                                }
+                            } @update_policy_handler
+                        },
+
+                        gtk::DropDown {
+                            set_valign: gtk::Align::Center,
+                            set_tooltip: &tr!("details-addons-profile"),
+
+                            #[watch]
+                            set_visible: model.addons_profiles.len() > 1,
+
+                            #[watch]
+                            #[block_signal(addons_profile_handler)]
+                            set_model: Some(&model.addons_profiles_model),
+
+                            #[watch]
+                            #[block_signal(addons_profile_handler)]
+                            set_selected: model.addons_profiles.iter()
+                                .position(|name| name == &model.active_profile)
+                                .unwrap_or(0) as u32,
+
+                            connect_selected_notify[sender] => move |dropdown| {
+                                if let Some(name) = dropdown.selected_item().and_downcast::<gtk::StringObject>() {
+                                    sender.input(GameDetailsComponentInput::EmitSelectAddonsProfile(name.string().to_string()));
+                                }
+                            } @addons_profile_handler
+                        }
                     }
                 },
 
@@ -266,7 +439,16 @@ impl SimpleAsyncComponent for GameDetailsComponent {
 
             installed: false,
             running: false,
-            status: None
+            status: None,
+
+            predownload_available: false,
+            predownloaded: false,
+
+            update_policy: None,
+
+            addons_profiles: Vec::new(),
+            addons_profiles_model: gtk::StringList::new(&[]),
+            active_profile: String::new()
         };
 
         model.game_card.emit(CardComponentInput::SetClickable(false));
@@ -296,6 +478,25 @@ impl SimpleAsyncComponent for GameDetailsComponent {
             GameDetailsComponentInput::SetRunning(running) => self.running = running,
             GameDetailsComponentInput::SetStatus(status) => self.status = status,
 
+            GameDetailsComponentInput::SetPredownload { available, finished } => {
+                self.predownload_available = available;
+                self.predownloaded = finished;
+            }
+
+            GameDetailsComponentInput::SetUpdatePolicy(policy) => self.update_policy = policy,
+
+            GameDetailsComponentInput::SetAddonsProfiles { names, active } => {
+                if names != self.addons_profiles {
+                    self.addons_profiles_model = gtk::StringList::new(&names.iter()
+                        .map(String::as_str)
+                        .collect::<Vec<_>>());
+
+                    self.addons_profiles = names;
+                }
+
+                self.active_profile = active;
+            }
+
             GameDetailsComponentInput::EditCard(message) => self.game_card.emit(message),
 
             GameDetailsComponentInput::EmitDownloadGame => {
@@ -305,8 +506,19 @@ impl SimpleAsyncComponent for GameDetailsComponent {
                 sender.output(GameDetailsComponentOutput::ShowTasksFlap).unwrap();
             }
 
-            GameDetailsComponentInput::EmitVerifyGame => {
-                sender.output(GameDetailsComponentOutput::VerifyGame(self.info.clone())).unwrap();
+            GameDetailsComponentInput::EmitVerifyGame { mode, dry_run } => {
+                sender.output(GameDetailsComponentOutput::VerifyGame {
+                    info: self.info.clone(),
+                    mode,
+                    dry_run
+                }).unwrap();
+
+                sender.output(GameDetailsComponentOutput::HideDetails).unwrap();
+                sender.output(GameDetailsComponentOutput::ShowTasksFlap).unwrap();
+            }
+
+            GameDetailsComponentInput::EmitPredownloadGame => {
+                sender.output(GameDetailsComponentOutput::PredownloadGame(self.info.clone())).unwrap();
 
                 sender.output(GameDetailsComponentOutput::HideDetails).unwrap();
                 sender.output(GameDetailsComponentOutput::ShowTasksFlap).unwrap();
@@ -323,6 +535,28 @@ impl SimpleAsyncComponent for GameDetailsComponent {
             GameDetailsComponentInput::EmitOpenAddonsManager => {
                 sender.output(GameDetailsComponentOutput::OpenAddonsManager(self.info.clone())).unwrap();
             }
+
+            GameDetailsComponentInput::EmitSetUpdatePolicy(policy) => {
+                self.update_policy = policy;
+
+                sender.output(GameDetailsComponentOutput::SetUpdatePolicy {
+                    info: self.info.clone(),
+                    policy
+                }).unwrap();
+            }
+
+            GameDetailsComponentInput::EmitSelectAddonsProfile(profile) => {
+                if profile == self.active_profile {
+                    return;
+                }
+
+                self.active_profile = profile.clone();
+
+                sender.output(GameDetailsComponentOutput::SelectAddonsProfile {
+                    info: self.info.clone(),
+                    profile
+                }).unwrap();
+            }
         }
     }
 }
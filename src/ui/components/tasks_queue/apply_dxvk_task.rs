@@ -2,12 +2,6 @@ use std::path::PathBuf;
 
 use wincompatlib::prelude::*;
 
-use anime_game_core::updater::{
-    UpdaterExt,
-    BasicUpdater,
-    Status as BasicStatus
-};
-
 use crate::ui::components::game_card::CardInfo;
 
 use crate::components::wine::Wine;
@@ -16,9 +10,12 @@ use crate::components::dxvk::Dxvk;
 use super::{
     QueuedTask,
     ResolvedTask,
-    TaskStatus
+    TaskPriority,
+    TaskDescriptor
 };
 
+use super::pipeline::Pipeline;
+
 #[derive(Debug)]
 pub struct ApplyDxvkQueuedTask {
     pub card_info: CardInfo,
@@ -32,82 +29,57 @@ impl QueuedTask for ApplyDxvkQueuedTask {
         self.card_info.clone()
     }
 
-    fn resolve(self: Box<Self>) -> anyhow::Result<Box<dyn ResolvedTask>> {
-        let Some(wine) = Wine::from_config()?.to_wincompatlib() else {
-            anyhow::bail!("Failed to resolve wincompatlib wine descriptor");
-        };
-
-        Ok(Box::new(ApplyDxvkResolvedTask {
-            card_info: self.card_info.clone(),
-
-            updater: BasicUpdater::spawn(move |sender| {
-                Box::new(move || -> Result<(), anyhow::Error> {
-                    // Specify basic wine params
+    fn get_paths(&self) -> Vec<PathBuf> {
+        let mut paths = vec![
+            self.prefix_path.clone(),
+            self.dxvk_version.get_folder()
+        ];
 
-                    let wine = wine
-                        .with_arch(WineArch::Win64)
-                        .with_loader(WineLoader::Current)
-                        .with_prefix(&self.prefix_path);
-
-                    // Apply DXVK
-
-                    sender.send(((), 0, 1))?;
-
-                    wine.install_dxvk(self.dxvk_version.get_folder(), InstallParams {
-                        repair_dlls: false,
-                        ..InstallParams::default()
-                    })?;
-
-                    sender.send(((), 1, 1))?;
+        // Wine is used to apply dxvk
+        if let Ok(wine) = Wine::from_config() {
+            paths.push(wine.get_folder());
+        }
 
-                    Ok(())
-                })
-            })
-        }))
+        paths
     }
-}
-
-#[derive(Debug)]
-pub struct ApplyDxvkResolvedTask {
-    pub updater: BasicUpdater<(), (), anyhow::Error>,
-    pub card_info: CardInfo
-}
 
-impl ResolvedTask for ApplyDxvkResolvedTask {
     #[inline]
-    fn get_info(&self) -> CardInfo {
-        self.card_info.clone()
+    fn get_priority(&self) -> TaskPriority {
+        TaskPriority::Component
     }
 
     #[inline]
-    fn is_finished(&mut self) -> bool {
-        self.updater.is_finished()
+    fn get_descriptor(&self) -> TaskDescriptor {
+        TaskDescriptor::ApplyDxvk {
+            name: self.dxvk_version.name.clone(),
+            prefix_path: self.prefix_path.clone()
+        }
     }
 
-    #[inline]
-    fn get_current(&self) -> u64 {
-        self.updater.current()
-    }
+    fn resolve(self: Box<Self>) -> anyhow::Result<Box<dyn ResolvedTask>> {
+        let Some(wine) = Wine::from_config()?.to_wincompatlib() else {
+            anyhow::bail!("Failed to resolve wincompatlib wine descriptor");
+        };
 
-    #[inline]
-    fn get_total(&self) -> u64 {
-        self.updater.total()
-    }
+        let pipeline = Pipeline::new(self.card_info.clone())
+            .step("tasks-installing-dxvk", move |_| {
+                // Specify basic wine params
 
-    #[inline]
-    fn get_progress(&self) -> f64 {
-        self.updater.progress()
-    }
+                let wine = wine
+                    .with_arch(WineArch::Win64)
+                    .with_loader(WineLoader::Current)
+                    .with_prefix(&self.prefix_path);
 
-    fn get_status(&mut self) -> anyhow::Result<TaskStatus> {
-        match self.updater.status() {
-            Ok(status) => Ok(match status {
-                BasicStatus::Pending     => TaskStatus::Pending,
-                BasicStatus::Working(()) => TaskStatus::InstallingDxvk,
-                BasicStatus::Finished    => TaskStatus::Finished
-            }),
+                // Apply DXVK
 
-            Err(err) => anyhow::bail!(err.to_string())
-        }
+                wine.install_dxvk(self.dxvk_version.get_folder(), InstallParams {
+                    repair_dlls: false,
+                    ..InstallParams::default()
+                })?;
+
+                Ok(())
+            });
+
+        Ok(Box::new(pipeline.spawn()))
     }
 }
@@ -5,6 +5,8 @@ use std::sync::atomic::{
     Ordering
 };
 
+use serde::Serialize;
+
 use anime_game_core::updater::{
     UpdaterExt,
     BasicUpdater,
@@ -14,25 +16,35 @@ use anime_game_core::updater::{
 use anime_game_core::archive;
 use anime_game_core::filesystem::transition::Transition;
 
-use anime_game_core::network::downloader::{
-    DownloaderExt,
-    basic::Downloader
-};
-
 use crate::ui::components::game_card::CardInfo;
 
 use crate::games;
+use crate::games::predownload;
 use crate::games::integrations::standards::diff::DiffInfo;
 
+use crate::network::mirrors::MirrorsDownloader;
+
+use crate::extract;
+use crate::cache::FilesCache;
+
 use crate::config;
 
 use super::{
     QueuedTask,
     ResolvedTask,
-    TaskStatus
+    TaskStatus,
+    TaskPriority,
+    TaskDescriptor
 };
 
-#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+/// Amount of attempts to download every archive segment
+const SEGMENT_DOWNLOAD_ATTEMPTS: u64 = 3;
+
+/// Amount of attempts to download a file with wrong hash
+const FILE_DOWNLOAD_ATTEMPTS: u64 = 3;
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
 pub enum DiffOrigin {
     Game,
     Addon {
@@ -47,6 +59,7 @@ pub enum Status {
     RunPreTransitionCode,
     Downloading,
     Unpacking,
+    ApplyingPatches,
     RunTransitionCode,
     FinishingTransition,
     RunPostTransitionCode
@@ -57,7 +70,10 @@ pub struct DownloadDiffQueuedTask {
     pub card_info: CardInfo,
     pub diff_info: DiffInfo,
     pub diff_origin: DiffOrigin,
-    pub download_path: PathBuf
+    pub download_path: PathBuf,
+
+    /// Path to the folder with pre-downloaded diff files
+    pub staging_path: Option<PathBuf>
 }
 
 impl QueuedTask for DownloadDiffQueuedTask {
@@ -66,6 +82,39 @@ impl QueuedTask for DownloadDiffQueuedTask {
         self.card_info.clone()
     }
 
+    #[inline]
+    fn get_paths(&self) -> Vec<PathBuf> {
+        let mut paths = vec![self.download_path.clone()];
+
+        if let Some(staging_path) = &self.staging_path {
+            paths.push(staging_path.clone());
+        }
+
+        paths
+    }
+
+    #[inline]
+    fn get_priority(&self) -> TaskPriority {
+        match &self.diff_origin {
+            DiffOrigin::Game => TaskPriority::Game,
+            DiffOrigin::Addon { .. } => TaskPriority::Addon
+        }
+    }
+
+    fn get_descriptor(&self) -> TaskDescriptor {
+        match &self.diff_origin {
+            DiffOrigin::Game => TaskDescriptor::DownloadGame {
+                game_info: self.card_info.clone()
+            },
+
+            DiffOrigin::Addon { group_name, addon_name } => TaskDescriptor::DownloadAddon {
+                game_info: self.card_info.clone(),
+                group_name: group_name.clone(),
+                addon_name: addon_name.clone()
+            }
+        }
+    }
+
     fn resolve(self: Box<Self>) -> anyhow::Result<Box<dyn ResolvedTask>> {
         let config = config::get();
 
@@ -76,6 +125,7 @@ impl QueuedTask for DownloadDiffQueuedTask {
         let diff_origin = self.diff_origin.clone();
 
         let download_path = self.download_path.clone();
+        let staging_path = self.staging_path.clone();
 
         Ok(Box::new(DownloadDiffResolvedTask {
             card_info: self.card_info.clone(),
@@ -90,6 +140,9 @@ impl QueuedTask for DownloadDiffQueuedTask {
 
                     sender.send((Status::PreparingTransition, 0, 1))?;
 
+                    // Make sure the diff can be applied before changing anything
+                    crate::patch::check_diff(&diff_info)?;
+
                     let transition = Transition::get_in(
                         format!("download-diff:{game_name}:{game_edition}:{:?}:{:?}", diff_origin, diff_info),
                         &download_path,
@@ -131,75 +184,174 @@ impl QueuedTask for DownloadDiffQueuedTask {
                     // Download and extract diff files
 
                     match diff_info {
-                        DiffInfo::Archive { size: _, uri } => {
+                        DiffInfo::Archive { uri, .. } => {
                             // Download archive
 
-                            let downloader = Downloader::new(uri);
+                            let downloader = MirrorsDownloader::new(uri.clone());
 
                             let archive = transition.transition_path()
                                 .join(downloader.file_name());
 
-                            let mut updater = downloader.download(&archive)?;
+                            // Use pre-downloaded archive if it exists
+                            if let Some(staging_path) = &staging_path {
+                                predownload::take_staged_file(staging_path, downloader.file_name(), &archive)?;
+                            }
 
-                            while !updater.is_finished() {
-                                // TODO: add timeouts
+                            // Extract archive while downloading it.
+                            // Already downloaded archive (e.g. pre-downloaded one) is extracted as usual
+                            let streamed = config.general.stream_extraction && !archive.exists() && extract::is_streamable(downloader.file_name()) && {
+                                let result = extract::extract_stream(&uri, transition.transition_path(), |current, total| {
+                                    sender.send((
+                                        Status::Downloading,
+                                        current,
+                                        total
+                                    ))?;
 
-                                sender.send((
-                                    Status::Downloading,
-                                    updater.current(),
-                                    updater.total()
-                                ))?;
-                            }
+                                    Ok(())
+                                });
 
-                            // Extract archive
+                                match result {
+                                    Ok(streamed) => streamed,
 
-                            let Some(mut updater) = archive::extract(&archive, transition.transition_path()) else {
-                                anyhow::bail!("Failed to extract files from the archive: {:?}", archive);
+                                    // Partially extracted files will be overwritten
+                                    Err(err) => {
+                                        tracing::warn!("Failed to extract archive while downloading, falling back to the usual download: {err}");
+
+                                        false
+                                    }
+                                }
                             };
 
-                            while let Ok(false) = updater.status() {
-                                // TODO: add timeouts
+                            if !streamed {
+                                downloader.download(&archive, |current, total| {
+                                    sender.send((
+                                        Status::Downloading,
+                                        current,
+                                        total
+                                    ))?;
 
-                                sender.send((
-                                    Status::Unpacking,
-                                    updater.current(),
-                                    updater.total()
-                                ))?;
-                            }
+                                    Ok(())
+                                })?;
+
+                                // Extract archive
+
+                                let Some(mut updater) = archive::extract(&archive, transition.transition_path()) else {
+                                    anyhow::bail!("Failed to extract files from the archive: {:?}", archive);
+                                };
+
+                                while let Ok(false) = updater.status() {
+                                    // TODO: add timeouts
+
+                                    sender.send((
+                                        Status::Unpacking,
+                                        updater.current(),
+                                        updater.total()
+                                    ))?;
+                                }
 
-                            // Delete archive
+                                // Delete archive
 
-                            std::fs::remove_file(archive)?;
+                                std::fs::remove_file(archive)?;
+                            }
                         }
 
-                        DiffInfo::Segments { size, segments } => {
+                        DiffInfo::Segments { size, segments, .. } => {
                             // Download segments
 
-                            let mut archives = vec![];
-                            let mut downloaded = 0;
+                            let pool = rusty_pool::Builder::new()
+                                .name(String::from("download_segments"))
+                                .core_size(config.general.threads.number as usize)
+                                .build();
 
-                            for uri in segments {
-                                let downloader = Downloader::new(uri);
+                            let archives = segments.iter()
+                                .map(|segment| transition.transition_path().join(segment.file_name()))
+                                .collect::<Vec<_>>();
+
+                            // Downloaded bytes of every segment
+                            let progress = Arc::new(segments.iter()
+                                .map(|_| AtomicU64::new(0))
+                                .collect::<Vec<_>>());
+
+                            let tasks = segments.into_iter()
+                                .zip(archives.iter().cloned())
+                                .enumerate()
+                                .map(|(i, (uri, archive))| {
+                                    let staging_path = staging_path.clone();
+                                    let progress = progress.clone();
+                                    let sender = sender.clone();
 
-                                let archive = transition.transition_path()
-                                    .join(downloader.file_name());
+                                    pool.evaluate(move || -> anyhow::Result<u64> {
+                                        let downloader = MirrorsDownloader::new(uri);
 
-                                let mut updater = downloader.download(&archive)?;
+                                        // Use pre-downloaded segment if it exists
+                                        if let Some(staging_path) = &staging_path {
+                                            predownload::take_staged_file(staging_path, downloader.file_name(), &archive)?;
+                                        }
 
-                                archives.push(archive);
+                                        let mut attempt = 1;
+                                        let mut segment_size = 0;
 
-                                while !updater.is_finished() {
-                                    // TODO: add timeouts
+                                        // Already downloaded part of the segment is kept
+                                        // so every next attempt continues from where the previous one stopped
+                                        loop {
+                                            // Task updater was dropped so there's no reason to retry
+                                            let mut stopped = false;
 
-                                    sender.send((
-                                        Status::Downloading,
-                                        downloaded + updater.current(),
-                                        size
-                                        // updater.total()
-                                    ))?;
+                                            let result = downloader.download(&archive, |current, total| {
+                                                segment_size = total;
+
+                                                progress[i].store(current, Ordering::Relaxed);
+
+                                                let result = sender.send((
+                                                    Status::Downloading,
+                                                    progress.iter().map(|current| current.load(Ordering::Relaxed)).sum(),
+                                                    size
+                                                ));
+
+                                                stopped = result.is_err();
+
+                                                Ok(result?)
+                                            });
+
+                                            match result {
+                                                Ok(()) => return Ok(segment_size),
+
+                                                Err(err) if attempt < SEGMENT_DOWNLOAD_ATTEMPTS && !stopped => {
+                                                    tracing::warn!("Failed to download segment {archive:?} (attempt {attempt}): {err}");
+
+                                                    attempt += 1;
+                                                }
+
+                                                Err(err) => return Err(err)
+                                            }
+                                        }
+                                    })
+                                })
+                                .collect::<Vec<_>>();
+
+                            // Wait for all the segments to finish downloading
+                            let mut expected_sizes = Vec::with_capacity(tasks.len());
+
+                            for task in tasks {
+                                expected_sizes.push(task.await_complete()?);
+                            }
+
+                            // Verify segments sizes
+
+                            let mut downloaded = 0;
+
+                            for (archive, expected_size) in archives.iter().zip(expected_sizes) {
+                                let segment_size = archive.metadata()?.len();
+
+                                if expected_size > 0 && segment_size != expected_size {
+                                    anyhow::bail!("Segment {archive:?} has wrong size: expected {expected_size}, got {segment_size}");
                                 }
 
-                                downloaded += updater.total();
+                                downloaded += segment_size;
+                            }
+
+                            if size > 0 && downloaded != size {
+                                anyhow::bail!("Segmented archive has wrong size: expected {size}, got {downloaded}");
                             }
 
                             // Extract segments
@@ -233,6 +385,8 @@ impl QueuedTask for DownloadDiffQueuedTask {
 
                             let queue_size = config.general.threads.max_queue_size as usize;
 
+                            let cache = FilesCache::from_config(&config.general.cache);
+
                             let mut tasks = Vec::with_capacity(queue_size);
 
                             let downloaded = Arc::new(AtomicU64::new(0));
@@ -241,16 +395,76 @@ impl QueuedTask for DownloadDiffQueuedTask {
                                 for file in chunk {
                                     let download_path = transition.transition_path().join(&file.path);
                                     let download_uri = file.uri.clone();
+                                    let file_relative_path = file.path.clone();
+                                    let file_hash = file.hash.clone();
                                     let file_size = file.size;
 
+                                    let staging_path = staging_path.clone();
+                                    let cache = cache.clone();
                                     let downloaded = downloaded.clone();
                                     let sender = sender.clone();
 
                                     tasks.push(pool.evaluate(move || -> anyhow::Result<()> {
-                                        Downloader::new(download_uri)
-                                            .continue_downloading(false)
-                                            .download(download_path)?
-                                            .wait()?;
+                                        let downloader = MirrorsDownloader::new(download_uri)
+                                            .with_continue_downloading(false);
+
+                                        // Use pre-downloaded file if it has correct size
+                                        let mut staged = match &staging_path {
+                                            Some(staging_path) => predownload::take_staged_file(staging_path, &file_relative_path, &download_path)?
+                                                && download_path.metadata()?.len() == file_size,
+
+                                            None => false
+                                        };
+
+                                        // Use cached file if it has the same hash
+                                        let cached = match (&cache, &file_hash) {
+                                            (Some(cache), Some(hash)) if !staged => {
+                                                cache.get(hash, file_size, &download_path, &game.driver)
+                                                    .unwrap_or_else(|err| {
+                                                        tracing::warn!("Failed to get file {download_path:?} from the cache: {err}");
+
+                                                        false
+                                                    })
+                                            }
+
+                                            _ => false
+                                        };
+
+                                        let mut attempt = 1;
+
+                                        while !cached {
+                                            if !staged {
+                                                downloader.download(&download_path, |_, _| Ok(()))?;
+                                            }
+
+                                            staged = false;
+
+                                            // Verify downloaded file if its hash is known
+                                            let Some(hash) = &file_hash else {
+                                                break;
+                                            };
+
+                                            if hash.verify_file(&download_path, &game.driver)? {
+                                                break;
+                                            }
+
+                                            if attempt >= FILE_DOWNLOAD_ATTEMPTS {
+                                                anyhow::bail!("Downloaded file {download_path:?} has wrong hash: expected {}", hash.value);
+                                            }
+
+                                            tracing::warn!("Downloaded file {download_path:?} has wrong hash (attempt {attempt}), downloading it again");
+
+                                            attempt += 1;
+                                        }
+
+                                        // Put verified file to the cache
+                                        if let (Some(cache), Some(hash)) = (&cache, &file_hash) {
+                                            if !cached {
+                                                if let Err(err) = cache.put(hash, file_size, &download_path) {
+                                                    tracing::warn!("Failed to put file {download_path:?} to the cache: {err}");
+                                                }
+                                            }
+                                        }
 
                                         let prev = downloaded.fetch_add(file_size, Ordering::Relaxed);
 
@@ -269,6 +483,126 @@ impl QueuedTask for DownloadDiffQueuedTask {
                                 }
                             }
                         }
+
+                        DiffInfo::Patch { size, patches } => {
+                            let pool = rusty_pool::Builder::new()
+                                .name(String::from("download_patches"))
+                                .core_size(config.general.threads.number as usize)
+                                .build();
+
+                            let queue_size = config.general.threads.max_queue_size as usize;
+
+                            let patches_path = transition.transition_path().join(".patches");
+
+                            // Skip files which were already patched
+                            let mut outdated_patches = Vec::with_capacity(patches.len());
+
+                            for patch in patches {
+                                let source_path = transition.original_path().join(&patch.path);
+
+                                if !source_path.exists() || !patch.target_hash.verify_file(&source_path, &game.driver)? {
+                                    outdated_patches.push(patch);
+                                }
+                            }
+
+                            // Download patches
+
+                            let mut tasks = Vec::with_capacity(queue_size);
+
+                            let downloaded = Arc::new(AtomicU64::new(0));
+
+                            for chunk in outdated_patches.chunks(queue_size) {
+                                for patch in chunk.iter().cloned() {
+                                    let patch_path = patches_path.join(format!("{}.patch", patch.path));
+
+                                    let staging_path = staging_path.clone();
+                                    let downloaded = downloaded.clone();
+                                    let sender = sender.clone();
+
+                                    tasks.push(pool.evaluate(move || -> anyhow::Result<()> {
+                                        // Use pre-downloaded patch if it exists
+                                        if let Some(staging_path) = &staging_path {
+                                            predownload::take_staged_file(staging_path, format!("{}.patch", patch.path), &patch_path)?;
+                                        }
+
+                                        MirrorsDownloader::new(patch.uri)
+                                            .download(patch_path, |_, _| Ok(()))?;
+
+                                        let prev = downloaded.fetch_add(patch.size, Ordering::Relaxed);
+
+                                        sender.send((
+                                            Status::Downloading,
+                                            prev + patch.size,
+                                            size
+                                        ))?;
+
+                                        Ok(())
+                                    }));
+                                }
+
+                                for task in tasks.drain(..) {
+                                    task.await_complete()?;
+                                }
+                            }
+
+                            // Apply patches
+
+                            let total = outdated_patches.len() as u64;
+                            let current = Arc::new(AtomicU64::new(0));
+
+                            sender.send((Status::ApplyingPatches, 0, total))?;
+
+                            // Bsdiff patches load whole files to memory
+                            // so batches are limited by their size as well
+                            let batches = crate::patch::get_batches(outdated_patches, queue_size, |patch| {
+                                crate::patch::get_required_memory(
+                                    patch,
+                                    transition.original_path().join(&patch.path),
+                                    patches_path.join(format!("{}.patch", patch.path))
+                                )
+                            });
+
+                            for batch in batches {
+                                for patch in batch {
+                                    let source_path = transition.original_path().join(&patch.path);
+                                    let target_path = transition.transition_path().join(&patch.path);
+                                    let patch_path = patches_path.join(format!("{}.patch", patch.path));
+
+                                    let current = current.clone();
+                                    let sender = sender.clone();
+
+                                    tasks.push(pool.evaluate(move || -> anyhow::Result<()> {
+                                        if !patch.source_hash.verify_file(&source_path, &game.driver)? {
+                                            anyhow::bail!("Can't apply patch to {source_path:?}: file has wrong hash");
+                                        }
+
+                                        crate::patch::apply(patch.format, &source_path, &patch_path, &target_path)?;
+
+                                        if !patch.target_hash.verify_file(&target_path, &game.driver)? {
+                                            anyhow::bail!("Patched file {target_path:?} has wrong hash: expected {}", patch.target_hash.value);
+                                        }
+
+                                        sender.send((
+                                            Status::ApplyingPatches,
+                                            current.fetch_add(1, Ordering::Relaxed) + 1,
+                                            total
+                                        ))?;
+
+                                        Ok(())
+                                    }));
+                                }
+
+                                for task in tasks.drain(..) {
+                                    task.await_complete()?;
+                                }
+                            }
+
+                            // Delete patches
+
+                            if patches_path.exists() {
+                                std::fs::remove_dir_all(patches_path)?;
+                            }
+                        }
                     }
 
                     // Run transition code
@@ -339,6 +673,14 @@ impl QueuedTask for DownloadDiffQueuedTask {
                         _ => ()
                     }
 
+                    // Delete pre-downloaded files
+
+                    if let Some(staging_path) = staging_path {
+                        if staging_path.exists() {
+                            std::fs::remove_dir_all(staging_path)?;
+                        }
+                    }
+
                     Ok(())
                 })
             })
@@ -387,6 +729,7 @@ impl ResolvedTask for DownloadDiffResolvedTask {
                 BasicStatus::Working(Status::RunPreTransitionCode)  => TaskStatus::RunPreTransitionCode,
                 BasicStatus::Working(Status::Downloading)           => TaskStatus::Downloading,
                 BasicStatus::Working(Status::Unpacking)             => TaskStatus::Unpacking,
+                BasicStatus::Working(Status::ApplyingPatches)       => TaskStatus::ApplyingPatches,
                 BasicStatus::Working(Status::RunTransitionCode)     => TaskStatus::RunTransitionCode,
                 BasicStatus::Working(Status::FinishingTransition)   => TaskStatus::FinishingTransition,
                 BasicStatus::Working(Status::RunPostTransitionCode) => TaskStatus::RunPostTransitionCode,
@@ -0,0 +1,116 @@
+use std::path::{Path, PathBuf};
+
+use serde::{Serialize, Deserialize};
+
+use crate::ui::components::game_card::CardInfo;
+
+use crate::LAUNCHER_FOLDER;
+
+use super::TaskDescriptor;
+
+/// Max amount of entries stored in the history file
+///
+/// Older entries are removed when new ones are added
+pub const MAX_HISTORY_ENTRIES: usize = 1000;
+
+lazy_static::lazy_static! {
+    /// Path to the file with tasks history
+    pub static ref HISTORY_FILE: PathBuf = LAUNCHER_FOLDER.join("history.json");
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum TaskResult {
+    Finished,
+    Failed,
+    Cancelled
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct TaskHistoryEntry {
+    pub info: CardInfo,
+    pub descriptor: TaskDescriptor,
+    pub result: TaskResult,
+
+    /// UTC timestamp of the task start
+    pub started_at: i64,
+
+    /// UTC timestamp of the task end
+    pub finished_at: i64,
+
+    /// Amount of transferred bytes
+    ///
+    /// `None` if the task progress is not measured in bytes
+    pub transferred_bytes: Option<u64>,
+
+    /// Average speed in bytes per second
+    pub average_speed: Option<u64>,
+
+    /// Task error followed by all its causes
+    pub errors: Vec<String>
+}
+
+impl TaskHistoryEntry {
+    /// Make entry of the task which was not started
+    pub fn not_started(info: CardInfo, descriptor: TaskDescriptor, result: TaskResult, errors: Vec<String>) -> Self {
+        let now = chrono::Utc::now().timestamp();
+
+        Self {
+            info,
+            descriptor,
+            result,
+            started_at: now,
+            finished_at: now,
+            transferred_bytes: None,
+            average_speed: None,
+            errors
+        }
+    }
+}
+
+/// Get error message followed by messages of all its causes
+pub fn get_error_chain(err: &anyhow::Error) -> Vec<String> {
+    err.chain()
+        .map(|err| err.to_string())
+        .collect()
+}
+
+/// Read tasks history, from the oldest entry to the newest one
+pub fn get() -> anyhow::Result<Vec<TaskHistoryEntry>> {
+    if !HISTORY_FILE.exists() {
+        return Ok(vec![]);
+    }
+
+    Ok(serde_json::from_slice(&std::fs::read(HISTORY_FILE.as_path())?)?)
+}
+
+/// Add entry to the tasks history
+pub fn push(entry: TaskHistoryEntry) -> anyhow::Result<()> {
+    let mut history = get()?;
+
+    history.push(entry);
+
+    if history.len() > MAX_HISTORY_ENTRIES {
+        history.drain(..history.len() - MAX_HISTORY_ENTRIES);
+    }
+
+    std::fs::write(HISTORY_FILE.as_path(), serde_json::to_vec_pretty(&history)?)?;
+
+    Ok(())
+}
+
+/// Remove all the entries from the tasks history
+pub fn clear() -> anyhow::Result<()> {
+    if HISTORY_FILE.exists() {
+        std::fs::remove_file(HISTORY_FILE.as_path())?;
+    }
+
+    Ok(())
+}
+
+/// Save tasks history to the given file
+pub fn export(path: impl AsRef<Path>) -> anyhow::Result<()> {
+    std::fs::write(path, serde_json::to_vec_pretty(&get()?)?)?;
+
+    Ok(())
+}
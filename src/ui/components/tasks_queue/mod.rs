@@ -1,8 +1,9 @@
 use std::collections::VecDeque;
+use std::path::PathBuf;
 use std::sync::Arc;
 use std::sync::atomic::{AtomicBool, Ordering};
 use std::thread::JoinHandle;
-use std::time::{Instant, Duration};
+use std::time::Duration;
 
 use relm4::factory::FactoryVecDeque;
 use relm4::prelude::*;
@@ -11,13 +12,22 @@ use gtk::prelude::*;
 
 use crate::tr;
 
-use crate::ui::components::game_card::{
-    CardInfo,
-    CardComponent,
-    CardComponentInput
+use crate::config;
+use crate::LAUNCHER_FOLDER;
+
+use crate::ui::components::game_card::CardInfo;
+
+use crate::ui::components::factory::game_card_tasks::{
+    CardFactory,
+    CardFactoryOutput
 };
 
-use crate::ui::components::factory::game_card_tasks::CardFactory;
+use crate::ui::components::factory::task_progress::{
+    TaskProgressFactory,
+    TaskProgressFactoryInit,
+    TaskProgressFactoryInput,
+    TaskProgressFactoryOutput
+};
 
 use crate::utils::{
     pretty_bytes,
@@ -28,13 +38,39 @@ pub mod task;
 pub mod create_prefix_task;
 pub mod apply_dxvk_task;
 pub mod download_diff_task;
+pub mod predownload_task;
 pub mod verify_integrity_task;
 pub mod delete_files_task;
+pub mod history;
+pub mod progress;
+pub mod pipeline;
 
 pub use task::*;
 
+use progress::ProgressTracker;
+use verify_integrity_task::VerifyReport;
+
+use history::{
+    TaskHistoryEntry,
+    TaskResult
+};
+
 pub const UPDATER_TIMEOUT: Duration = Duration::from_millis(20);
 
+lazy_static::lazy_static! {
+    /// Path to the file with saved tasks queue
+    pub static ref QUEUE_FILE: PathBuf = LAUNCHER_FOLDER.join("queue.json");
+}
+
+/// Read tasks queue saved before the launcher was closed
+pub fn load_queue() -> anyhow::Result<Vec<TaskDescriptor>> {
+    if !QUEUE_FILE.exists() {
+        return Ok(vec![]);
+    }
+
+    Ok(serde_json::from_slice(&std::fs::read(QUEUE_FILE.as_path())?)?)
+}
+
 #[derive(Debug)]
 pub struct TasksQueueProgressUpdater {
     pub thread: JoinHandle<()>,
@@ -47,27 +83,49 @@ impl Drop for TasksQueueProgressUpdater {
     }
 }
 
+#[derive(Debug)]
+pub struct ActiveTask {
+    pub task: Box<dyn ResolvedTask>,
+    pub descriptor: TaskDescriptor,
+    pub paths: Vec<PathBuf>,
+    pub tracker: ProgressTracker,
+
+    /// UTC timestamp of the task start
+    pub started_at: i64
+}
+
 #[derive(Debug)]
 pub struct TasksQueueComponent {
-    pub current_task: Option<Box<dyn ResolvedTask>>,
-    pub current_task_card: AsyncController<CardComponent>,
-    pub current_task_status: String,
-    pub current_task_progress_start: Instant,
-    pub current_task_progress_pulse: bool,
+    pub active_tasks: Vec<ActiveTask>,
+    pub active_tasks_factory: FactoryVecDeque<TaskProgressFactory>,
 
     pub queued_tasks_factory: FactoryVecDeque<CardFactory>,
     pub queued_tasks: VecDeque<Box<dyn QueuedTask>>,
 
-    pub progress_label: gtk::Label,
-    pub progress_bar: gtk::ProgressBar,
-
     pub updater: Option<TasksQueueProgressUpdater>
 }
 
 #[derive(Debug)]
 pub enum TasksQueueComponentInput {
     AddTask(Box<dyn QueuedTask>),
-    UpdateCurrentTask,
+
+    /// Append tasks saved before the launcher was closed
+    /// without reordering them by priority
+    RestoreTasks(Vec<Box<dyn QueuedTask>>),
+
+    /// Swap queued task with its neighbour
+    MoveQueuedTask {
+        index: usize,
+        earlier: bool
+    },
+
+    /// Remove queued task without running it
+    CancelQueuedTask(usize),
+
+    /// Ask running task to stop
+    CancelActiveTask(usize),
+
+    UpdateTasks,
     StartUpdater,
     StopUpdater
 }
@@ -76,6 +134,12 @@ pub enum TasksQueueComponentInput {
 pub enum TasksQueueComponentOutput {
     TaskFinished(CardInfo),
 
+    /// Queued task was cancelled and there are no other tasks for this component
+    TaskCancelled(CardInfo),
+
+    /// Report made by the finished task
+    ShowReport(VerifyReport),
+
     HideTasksFlap,
 
     ShowToast {
@@ -84,150 +148,197 @@ pub enum TasksQueueComponentOutput {
     }
 }
 
-#[relm4::component(pub, async)]
-impl SimpleAsyncComponent for TasksQueueComponent {
-    type Init = CardInfo;
-    type Input = TasksQueueComponentInput;
-    type Output = TasksQueueComponentOutput;
+/// Check if any path from the first list is
+/// the same as, inside of, or a parent of any path from the second one
+pub fn paths_conflict(first: &[PathBuf], second: &[PathBuf]) -> bool {
+    first.iter().any(|first| {
+        second.iter().any(|second| first.starts_with(second) || second.starts_with(first))
+    })
+}
 
-    view! {
-        #[root]
-        gtk::Box {
-            set_orientation: gtk::Orientation::Vertical,
+#[inline]
+/// Check if the task progress is measured in bytes rather than in items
+pub fn is_bytes_progress(total: u64) -> bool {
+    total > 1024 * 512
+}
 
-            set_margin_all: 24,
+/// Add entry to the tasks history, logging failures
+fn record_history(entry: TaskHistoryEntry) {
+    if let Err(err) = history::push(entry) {
+        tracing::warn!("Failed to save tasks history: {err}");
+    }
+}
 
-            gtk::Box {
-                set_halign: gtk::Align::Center,
+/// Get `(pulse, title)` of the task status
+pub fn get_status_title(status: &TaskStatus) -> (bool, String) {
+    match status {
+        TaskStatus::Pending => (true, tr!("tasks-pending")),
 
-                #[watch]
-                set_visible: model.current_task.is_some(),
+        TaskStatus::PreparingTransition => (true, tr!("tasks-preparing-transition")),
+        TaskStatus::FinishingTransition => (true, tr!("tasks-finishing-transition")),
 
-                model.current_task_card.widget(),
-            },
+        TaskStatus::Downloading => (false, tr!("tasks-downloading")),
+        TaskStatus::Unpacking   => (false, tr!("tasks-unpacking")),
 
-            adw::Clamp {
-                set_maximum_size: 200,
+        TaskStatus::ApplyingPatches => (false, tr!("tasks-applying-patches")),
 
-                #[watch]
-                set_visible: model.current_task.is_none(),
+        TaskStatus::RunPreTransitionCode  => (true, tr!("tasks-pre-transition-code")),
+        TaskStatus::RunTransitionCode     => (true, tr!("tasks-transition-code")),
+        TaskStatus::RunPostTransitionCode => (true, tr!("tasks-post-transition-code")),
 
-                gtk::Picture {
-                    set_resource: Some(&format!("{}/icons/hicolor/scalable/apps/{}.png", crate::APP_RESOURCE_PREFIX, crate::APP_ID))
-                }
-            },
+        TaskStatus::VerifyingFiles => (false, tr!("tasks-verifying-files")),
+        TaskStatus::RepairingFiles => (false, tr!("tasks-repairing-files")),
 
-            gtk::Label {
-                set_halign: gtk::Align::Center,
+        TaskStatus::Step { name, pulse, .. } => (*pulse, tr!(*name)),
 
-                set_margin_top: 24,
+        TaskStatus::Finished => (true, tr!("tasks-finished"))
+    }
+}
 
-                add_css_class: "title-4",
+impl TasksQueueComponent {
+    /// Save running and queued tasks so they can be restored after the launcher restart
+    fn save_queue(&self) {
+        let descriptors = self.active_tasks.iter()
+            .map(|task| task.descriptor.clone())
+            .chain(self.queued_tasks.iter().map(|task| task.get_descriptor()))
+            .collect::<Vec<_>>();
+
+        let result = if descriptors.is_empty() {
+            if QUEUE_FILE.exists() {
+                std::fs::remove_file(QUEUE_FILE.as_path()).map_err(anyhow::Error::from)
+            } else {
+                Ok(())
+            }
+        } else {
+            serde_json::to_vec_pretty(&descriptors)
+                .map_err(anyhow::Error::from)
+                .and_then(|queue| Ok(std::fs::write(QUEUE_FILE.as_path(), queue)?))
+        };
 
-                #[watch]
-                set_label: &match &model.current_task {
-                    Some(task) => task.get_info().get_title().to_string(),
-                    None => tr!("tasks-nothing")
-                }
-            },
+        if let Err(err) = result {
+            tracing::warn!("Failed to save tasks queue: {err}");
+        }
+    }
 
-            gtk::CenterBox {
-                set_margin_top: 16,
+    /// Check if there's a queued or running task for the given component
+    fn has_task(&self, info: &CardInfo) -> bool {
+        self.queued_tasks.iter().any(|task| &task.get_info() == info) ||
+        self.active_tasks.iter().any(|task| &task.task.get_info() == info)
+    }
 
-                #[watch]
-                set_visible: model.current_task.is_some(),
+    /// Start queued tasks which don't conflict with the running ones
+    ///
+    /// Queued tasks never overtake earlier queued tasks with intersecting paths
+    fn start_tasks(&mut self, sender: &AsyncComponentSender<Self>) {
+        let max_concurrent = config::get().general.tasks.max_concurrent.max(1) as usize;
 
-                #[wrap(Some)]
-                set_start_widget = &gtk::Label {
-                    #[watch]
-                    set_label: &model.current_task_status
-                },
+        // Paths of the running tasks and of the tasks which are waiting for them
+        let mut busy_paths = self.active_tasks.iter()
+            .flat_map(|task| task.paths.clone())
+            .collect::<Vec<_>>();
 
-                #[wrap(Some)]
-                #[local_ref]
-                set_end_widget = progress_label -> gtk::Label {
-                    set_margin_start: 16,
+        let mut i = 0;
 
-                    #[watch]
-                    set_visible: !&model.current_task_progress_pulse
-                }
-            },
+        while i < self.queued_tasks.len() && self.active_tasks.len() < max_concurrent {
+            let paths = self.queued_tasks[i].get_paths();
 
-            #[local_ref]
-            progress_bar -> gtk::ProgressBar {
-                set_margin_top: 16,
+            if paths_conflict(&paths, &busy_paths) {
+                busy_paths.extend(paths);
 
-                #[watch]
-                set_visible: model.current_task.is_some(),
+                i += 1;
 
-                set_pulse_step: 0.25 / UPDATER_TIMEOUT.as_millis() as f64 // 0.0125
-            },
+                continue;
+            }
 
-            gtk::Label {
-                set_halign: gtk::Align::Start,
+            let Some(task) = self.queued_tasks.remove(i) else {
+                break;
+            };
 
-                set_margin_top: 16,
+            self.queued_tasks_factory.guard().remove(i);
 
-                #[watch]
-                set_visible: model.current_task.is_some(),
+            let info = task.get_info();
+            let descriptor = task.get_descriptor();
 
-                // TODO: update avg-s with some timeout
+            match task.resolve() {
+                Ok(task) => {
+                    self.active_tasks_factory.guard().push_back(TaskProgressFactoryInit {
+                        info: task.get_info(),
+                        cancellable: task.can_cancel()
+                    });
 
-                #[watch]
-                set_label: &match &model.current_task {
-                    Some(task) if task.get_current() > 0 => {
-                        let elapsed_time  = (Instant::now() - model.current_task_progress_start).as_secs_f64();
-                        let average_speed = (task.get_current() as f64 / elapsed_time).ceil() as u64;
+                    self.active_tasks.push(ActiveTask {
+                        task,
+                        descriptor,
+                        paths: paths.clone(),
+                        tracker: ProgressTracker::new(),
+                        started_at: chrono::Utc::now().timestamp()
+                    });
 
-                        if task.get_total() > 1024 * 512 {
-                            tr!("tasks-avg-speed", {
-                                "format" = "data",
-                                "speed" = pretty_bytes(average_speed)
-                            })
-                        }
+                    busy_paths.extend(paths);
+                }
 
-                        else {
-                            tr!("tasks-avg-speed", {
-                                "format" = "items",
-                                "speed" = average_speed
-                            })
-                        }
-                    }
+                Err(err) => {
+                    record_history(TaskHistoryEntry::not_started(
+                        info,
+                        descriptor,
+                        TaskResult::Failed,
+                        history::get_error_chain(&err)
+                    ));
+
+                    sender.output(TasksQueueComponentOutput::ShowToast {
+                        title: tr!("tasks-resolve-queued-failed"),
+                        message: Some(err.to_string())
+                    }).unwrap();
+                }
+            }
+        }
+    }
+}
+
+#[relm4::component(pub, async)]
+impl SimpleAsyncComponent for TasksQueueComponent {
+    type Init = ();
+    type Input = TasksQueueComponentInput;
+    type Output = TasksQueueComponentOutput;
 
-                    _ => String::new()
+    view! {
+        #[root]
+        gtk::Box {
+            set_orientation: gtk::Orientation::Vertical,
+
+            set_margin_all: 24,
+
+            adw::Clamp {
+                set_maximum_size: 200,
+
+                #[watch]
+                set_visible: model.active_tasks.is_empty(),
+
+                gtk::Picture {
+                    set_resource: Some(&format!("{}/icons/hicolor/scalable/apps/{}.png", crate::APP_RESOURCE_PREFIX, crate::APP_ID))
                 }
             },
 
             gtk::Label {
-                set_halign: gtk::Align::Start,
+                set_halign: gtk::Align::Center,
 
-                set_margin_top: 8,
+                set_margin_top: 24,
 
-                #[watch]
-                set_visible: model.current_task.is_some(),
+                add_css_class: "title-4",
 
                 #[watch]
-                set_label: &match &model.current_task {
-                    Some(task) if task.get_current() > 0 => {
-                        let elapsed_time = (Instant::now() - model.current_task_progress_start).as_secs_f64();
-                        let progress = task.get_progress();
-
-                        let expected_total_time = (elapsed_time / progress).ceil() as u64;
-                        let remaining_time = expected_total_time - elapsed_time as u64;
-
-                        if remaining_time < 24 * 60 * 60 {
-                            tr!("tasks-avg-eta", {
-                                "eta" = pretty_seconds(remaining_time)
-                            })
-                        }
+                set_visible: model.active_tasks.is_empty(),
 
-                        else {
-                            String::new()
-                        }
-                    }
+                set_label: &tr!("tasks-nothing")
+            },
 
-                    _ => String::new()
-                }
+            gtk::ScrolledWindow {
+                set_vexpand: true,
+
+                #[watch]
+                set_visible: !model.active_tasks.is_empty(),
+
+                model.active_tasks_factory.widget(),
             },
 
             gtk::ScrolledWindow {
@@ -238,7 +349,7 @@ impl SimpleAsyncComponent for TasksQueueComponent {
         }
     }
 
-    async fn init(init: Self::Init, root: Self::Root, _sender: AsyncComponentSender<Self>) -> AsyncComponentParts<Self> {
+    async fn init(_init: Self::Init, root: Self::Root, sender: AsyncComponentSender<Self>) -> AsyncComponentParts<Self> {
         let flow_box = gtk::FlowBox::new();
 
         flow_box.set_valign(gtk::Align::End);
@@ -247,38 +358,38 @@ impl SimpleAsyncComponent for TasksQueueComponent {
         flow_box.set_vexpand(true);
         flow_box.set_homogeneous(true);
 
-        let model = Self {
-            current_task: None,
+        let active_tasks_box = gtk::Box::new(gtk::Orientation::Vertical, 0);
 
-            current_task_card: CardComponent::builder()
-                .launch(init)
-                .detach(),
+        let model = Self {
+            active_tasks: Vec::new(),
 
-            current_task_status: String::new(),
-            current_task_progress_start: Instant::now(),
-            current_task_progress_pulse: false,
+            active_tasks_factory: FactoryVecDeque::builder()
+                .launch(active_tasks_box)
+                .forward(sender.input_sender(), |output| match output {
+                    TaskProgressFactoryOutput::Cancel(index) => TasksQueueComponentInput::CancelActiveTask(index.current_index())
+                }),
 
             queued_tasks_factory: FactoryVecDeque::builder()
                 .launch(flow_box)
-                .detach(),
+                .forward(sender.input_sender(), |output| match output {
+                    CardFactoryOutput::MoveEarlier(index) => TasksQueueComponentInput::MoveQueuedTask {
+                        index: index.current_index(),
+                        earlier: true
+                    },
 
-            queued_tasks: VecDeque::new(),
+                    CardFactoryOutput::MoveLater(index) => TasksQueueComponentInput::MoveQueuedTask {
+                        index: index.current_index(),
+                        earlier: false
+                    },
+
+                    CardFactoryOutput::Cancel(index) => TasksQueueComponentInput::CancelQueuedTask(index.current_index())
+                }),
 
-            progress_label: gtk::Label::new(None),
-            progress_bar: gtk::ProgressBar::new(),
+            queued_tasks: VecDeque::new(),
 
             updater: None
         };
 
-        model.current_task_card.emit(CardComponentInput::SetWidth(160));
-        model.current_task_card.emit(CardComponentInput::SetHeight(224));
-
-        model.current_task_card.emit(CardComponentInput::SetClickable(false));
-        model.current_task_card.emit(CardComponentInput::SetDisplayTitle(false));
-
-        let progress_label = &model.progress_label;
-        let progress_bar = &model.progress_bar;
-
         let widgets = view_output!();
 
         AsyncComponentParts { model, widgets }
@@ -287,131 +398,237 @@ impl SimpleAsyncComponent for TasksQueueComponent {
     async fn update(&mut self, msg: Self::Input, sender: AsyncComponentSender<Self>) {
         match msg {
             TasksQueueComponentInput::AddTask(task) => {
-                if self.current_task.is_none() {
-                    match task.resolve() {
-                        Ok(task) => {
-                            self.current_task_card.emit(CardComponentInput::SetInfo(task.get_info()));
+                let descriptor = task.get_descriptor();
 
-                            self.current_task = Some(task);
-                            self.current_task_progress_start = Instant::now();
-                        }
+                // Skip already queued tasks, e.g. restored ones
+                let queued = self.active_tasks.iter().any(|task| task.descriptor == descriptor) ||
+                    self.queued_tasks.iter().any(|task| task.get_descriptor() == descriptor);
 
-                        Err(err) => {
-                            sender.output(TasksQueueComponentOutput::ShowToast {
-                                title: tr!("tasks-resolve-queued-failed"),
-                                message: Some(err.to_string())
-                            }).unwrap();
-                        }
-                    }
+                if queued {
+                    return;
                 }
 
-                else {
+                // Put the task before all the tasks with lower priority
+                // but never before the tasks with intersecting paths
+                let priority = task.get_priority();
+                let paths = task.get_paths();
+
+                let earliest = self.queued_tasks.iter()
+                    .rposition(|task| paths_conflict(&task.get_paths(), &paths))
+                    .map(|index| index + 1)
+                    .unwrap_or_default();
+
+                let index = self.queued_tasks.iter()
+                    .skip(earliest)
+                    .position(|task| task.get_priority() < priority)
+                    .map(|index| index + earliest)
+                    .unwrap_or(self.queued_tasks.len());
+
+                self.queued_tasks_factory.guard().insert(index, task.get_info().to_owned());
+
+                self.queued_tasks.insert(index, task);
+
+                self.start_tasks(&sender);
+                self.save_queue();
+
+                // This will try to start an updater even if one is already running
+                // Adding a check here (e.g. is_none()) may lead to a race condition
+                sender.input(TasksQueueComponentInput::StartUpdater);
+            }
+
+            TasksQueueComponentInput::RestoreTasks(tasks) => {
+                for task in tasks {
+                    let descriptor = task.get_descriptor();
+
+                    let queued = self.active_tasks.iter().any(|task| task.descriptor == descriptor) ||
+                        self.queued_tasks.iter().any(|task| task.get_descriptor() == descriptor);
+
+                    if queued {
+                        continue;
+                    }
+
                     self.queued_tasks_factory.guard().push_back(task.get_info().to_owned());
 
                     self.queued_tasks.push_back(task);
                 }
 
-                // This will try to start an updater even if one is already running
-                // Adding a check here (e.g. is_none()) may lead to a race condition
+                self.start_tasks(&sender);
+                self.save_queue();
+
                 sender.input(TasksQueueComponentInput::StartUpdater);
             }
 
-            TasksQueueComponentInput::UpdateCurrentTask => {
-                if let Some(task) = &mut self.current_task {
-                    if task.is_finished() {
-                        if let Err(err) = task.get_status() {
-                            sender.output(TasksQueueComponentOutput::ShowToast {
-                                title: tr!("tasks-get-status-failed", {
-                                    "game-title" = task.get_info().get_title().to_string()
-                                }),
-                                message: Some(err.to_string())
-                            }).unwrap();
-                        }
+            TasksQueueComponentInput::MoveQueuedTask { index, earlier } => {
+                let target = if earlier {
+                    index.checked_sub(1)
+                } else {
+                    Some(index + 1).filter(|target| *target < self.queued_tasks.len())
+                };
+
+                // Tasks with intersecting paths must keep their order
+                let target = target.filter(|target| {
+                    match (self.queued_tasks.get(index), self.queued_tasks.get(*target)) {
+                        (Some(task), Some(neighbour)) => !paths_conflict(&task.get_paths(), &neighbour.get_paths()),
+                        _ => false
+                    }
+                });
+
+                if let Some(target) = target {
+                    self.queued_tasks.swap(index, target);
+                    self.queued_tasks_factory.guard().swap(index, target);
 
-                        let mut is_task_queued = false;
+                    self.save_queue();
+                }
+            }
 
-                        for queued_task in &self.queued_tasks {
-                            if queued_task.get_info() == task.get_info() {
-                                is_task_queued = true;
+            TasksQueueComponentInput::CancelQueuedTask(index) => {
+                if let Some(task) = self.queued_tasks.remove(index) {
+                    self.queued_tasks_factory.guard().remove(index);
 
-                                break;
-                            }
-                        }
+                    let info = task.get_info();
 
-                        if !is_task_queued {
-                            sender.output(TasksQueueComponentOutput::TaskFinished(task.get_info().to_owned())).unwrap();
-                        }
+                    record_history(TaskHistoryEntry::not_started(
+                        info.clone(),
+                        task.get_descriptor(),
+                        TaskResult::Cancelled,
+                        vec![]
+                    ));
 
-                        if let Some(queued_task) = self.queued_tasks.pop_front() {
-                            self.queued_tasks_factory.guard().pop_front();
+                    self.save_queue();
 
-                            match queued_task.resolve() {
-                                Ok(task) => {
-                                    self.current_task_card.emit(CardComponentInput::SetInfo(task.get_info().to_owned()));
+                    if !self.has_task(&info) {
+                        sender.output(TasksQueueComponentOutput::TaskCancelled(info)).unwrap();
+                    }
+                }
+            }
+
+            TasksQueueComponentInput::CancelActiveTask(index) => {
+                if let Some(active_task) = self.active_tasks.get_mut(index) {
+                    active_task.task.cancel();
+                }
+            }
 
-                                    self.current_task = Some(task);
-                                }
+            TasksQueueComponentInput::UpdateTasks => {
+                let mut finished = false;
 
-                                Err(err) => {
-                                    sender.output(TasksQueueComponentOutput::ShowToast {
-                                        title: tr!("tasks-resolve-queued-failed"),
-                                        message: Some(err.to_string())
-                                    }).unwrap();
-                                }
+                // Iterate in reverse order so finished tasks can be removed
+                for i in (0..self.active_tasks.len()).rev() {
+                    let active_task = &mut self.active_tasks[i];
+
+                    if active_task.task.is_finished() {
+                        let cancelled = active_task.task.is_cancelled();
+
+                        let mut errors = vec![];
+
+                        if let Err(err) = active_task.task.get_status() {
+                            errors = history::get_error_chain(&err);
+
+                            if !cancelled {
+                                sender.output(TasksQueueComponentOutput::ShowToast {
+                                    title: tr!("tasks-get-status-failed", {
+                                        "game-title" = active_task.task.get_info().get_title().to_string()
+                                    }),
+                                    message: Some(err.to_string())
+                                }).unwrap();
                             }
                         }
 
-                        else {
-                            self.current_task = None;
+                        let mut active_task = self.active_tasks.remove(i);
 
-                            sender.input(TasksQueueComponentInput::StopUpdater);
-                            sender.output(TasksQueueComponentOutput::HideTasksFlap).unwrap();
+                        if let Some(report) = active_task.task.take_report() {
+                            sender.output(TasksQueueComponentOutput::ShowReport(report)).unwrap();
                         }
 
-                        self.current_task_status.clear();
+                        // Tasks reset their progress when finished
+                        // so downloaded bytes are tracked while they're running
+                        let transferred_bytes = active_task.tracker.transferred();
+                        let average_speed = active_task.tracker.average_speed();
+
+                        record_history(TaskHistoryEntry {
+                            info: active_task.task.get_info(),
+                            descriptor: active_task.descriptor.clone(),
+                            result: match (cancelled, errors.is_empty()) {
+                                (true, _)      => TaskResult::Cancelled,
+                                (false, true)  => TaskResult::Finished,
+                                (false, false) => TaskResult::Failed
+                            },
+                            started_at: active_task.started_at,
+                            finished_at: chrono::Utc::now().timestamp(),
+                            transferred_bytes,
+                            average_speed,
+                            errors
+                        });
+
+                        self.active_tasks_factory.guard().remove(i);
+
+                        let info = active_task.task.get_info();
+
+                        if !self.has_task(&info) {
+                            if cancelled {
+                                sender.output(TasksQueueComponentOutput::TaskCancelled(info)).unwrap();
+                            } else {
+                                sender.output(TasksQueueComponentOutput::TaskFinished(info)).unwrap();
+                            }
+                        }
+
+                        finished = true;
                     }
 
                     else {
-                        if self.current_task_progress_pulse {
-                            self.progress_bar.pulse();
-                        }
+                        let task = &mut active_task.task;
 
-                        else {
-                            let progress = task.get_progress();
+                        let status = task.get_status();
 
-                            self.progress_label.set_text(&format!("{:.2}%", progress * 100.0));
-                            self.progress_bar.set_fraction(progress);
+                        if let Ok(status) = &status {
+                            let (pulse, status) = get_status_title(status);
+
+                            self.active_tasks_factory.send(i, TaskProgressFactoryInput::SetStatus {
+                                status,
+                                pulse
+                            });
                         }
 
-                        if let Ok(status) = task.get_status() {
-                            let (pulse, title) = match status {
-                                TaskStatus::Pending => (true, tr!("tasks-pending")),
+                        let total = task.get_total();
+
+                        active_task.tracker.update(task.get_current(), total, matches!(status, Ok(status) if status.is_downloading()));
 
-                                TaskStatus::PreparingTransition => (true, tr!("tasks-preparing-transition")),
-                                TaskStatus::FinishingTransition => (true, tr!("tasks-finishing-transition")),
+                        let speed = match active_task.tracker.speed() {
+                            Some(speed) if is_bytes_progress(total) => tr!("tasks-speed", {
+                                "format" = "data",
+                                "speed" = pretty_bytes(speed)
+                            }),
 
-                                TaskStatus::Downloading => (false, tr!("tasks-downloading")),
-                                TaskStatus::Unpacking   => (false, tr!("tasks-unpacking")),
+                            Some(speed) => tr!("tasks-speed", {
+                                "format" = "items",
+                                "speed" = speed
+                            }),
 
-                                TaskStatus::DeletingFiles => (true, tr!("tasks-deleting-files")),
+                            None => String::new()
+                        };
 
-                                TaskStatus::RunPreTransitionCode  => (true, tr!("tasks-pre-transition-code")),
-                                TaskStatus::RunTransitionCode     => (true, tr!("tasks-transition-code")),
-                                TaskStatus::RunPostTransitionCode => (true, tr!("tasks-post-transition-code")),
+                        let eta = match active_task.tracker.eta() {
+                            Some(eta) if eta.as_secs() < 24 * 60 * 60 => tr!("tasks-eta", {
+                                "eta" = pretty_seconds(eta.as_secs())
+                            }),
 
-                                TaskStatus::CreatingPrefix  => (true, tr!("tasks-creating-prefix")),
-                                TaskStatus::InstallingDxvk  => (true, tr!("tasks-installing-dxvk")),
-                                TaskStatus::InstallingFonts => (false, tr!("tasks-installing-fonts")),
+                            _ => String::new()
+                        };
 
-                                TaskStatus::VerifyingFiles => (false, tr!("tasks-verifying-files")),
-                                TaskStatus::RepairingFiles => (false, tr!("tasks-repairing-files")),
+                        self.active_tasks_factory.send(i, TaskProgressFactoryInput::SetProgress {
+                            progress: task.get_progress(),
+                            speed,
+                            eta
+                        });
+                    }
+                }
 
-                                TaskStatus::Finished => (true, tr!("tasks-finished"))
-                            };
+                if finished {
+                    self.start_tasks(&sender);
+                    self.save_queue();
 
-                            self.current_task_progress_pulse = pulse;
-                            self.current_task_status = title;
-                        }
+                    if self.active_tasks.is_empty() {
+                        sender.input(TasksQueueComponentInput::StopUpdater);
+                        sender.output(TasksQueueComponentOutput::HideTasksFlap).unwrap();
                     }
                 }
             }
@@ -424,7 +641,7 @@ impl SimpleAsyncComponent for TasksQueueComponent {
 
                     thread: std::thread::spawn(move || {
                         while running.load(Ordering::Relaxed) {
-                            sender.input(TasksQueueComponentInput::UpdateCurrentTask);
+                            sender.input(TasksQueueComponentInput::UpdateTasks);
 
                             std::thread::sleep(UPDATER_TIMEOUT);
                         }
@@ -0,0 +1,310 @@
+use std::cell::Cell;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::thread::JoinHandle;
+
+use anyhow::Context;
+
+use crate::ui::components::game_card::CardInfo;
+
+use super::{ResolvedTask, TaskStatus};
+
+/// Error returned by the steps of cancelled pipelines
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Cancelled;
+
+impl std::fmt::Display for Cancelled {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Task was cancelled")
+    }
+}
+
+impl std::error::Error for Cancelled {}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+struct PipelineUpdate {
+    step: usize,
+    current: u64,
+    total: u64
+}
+
+/// Progress reporter of the pipeline step
+///
+/// Can be cloned to report progress from other threads
+#[derive(Debug, Clone)]
+pub struct StepContext {
+    step: usize,
+    sender: flume::Sender<PipelineUpdate>,
+    cancelled: Arc<AtomicBool>
+}
+
+impl StepContext {
+    /// Report progress of the current step
+    ///
+    /// Returns `Cancelled` error if the pipeline was cancelled
+    pub fn set_progress(&self, current: u64, total: u64) -> anyhow::Result<()> {
+        self.check_cancelled()?;
+
+        self.sender.send(PipelineUpdate {
+            step: self.step,
+            current,
+            total
+        })?;
+
+        Ok(())
+    }
+
+    #[inline]
+    pub fn is_cancelled(&self) -> bool {
+        self.cancelled.load(Ordering::Relaxed)
+    }
+
+    /// Return `Cancelled` error if the pipeline was cancelled
+    pub fn check_cancelled(&self) -> anyhow::Result<()> {
+        if self.is_cancelled() {
+            return Err(Cancelled.into());
+        }
+
+        Ok(())
+    }
+}
+
+type StepFn = Box<dyn FnOnce(&StepContext) -> anyhow::Result<()> + Send>;
+
+struct Step {
+    name: &'static str,
+    downloading: bool,
+    run: StepFn
+}
+
+/// Task made of named steps which are run one after another
+///
+/// Step names are locale keys shown as the task status.
+/// Steps which never report their progress are shown
+/// with a pulsing progress bar
+pub struct Pipeline {
+    info: CardInfo,
+    steps: Vec<Step>
+}
+
+impl Pipeline {
+    #[inline]
+    pub fn new(info: CardInfo) -> Self {
+        Self {
+            info,
+            steps: Vec::new()
+        }
+    }
+
+    #[inline]
+    /// Add step to the end of the pipeline
+    pub fn step(mut self, name: &'static str, run: impl FnOnce(&StepContext) -> anyhow::Result<()> + Send + 'static) -> Self {
+        self.steps.push(Step {
+            name,
+            downloading: false,
+            run: Box::new(run)
+        });
+
+        self
+    }
+
+    #[inline]
+    /// Add step which reports amount of downloaded bytes as its progress
+    pub fn download_step(mut self, name: &'static str, run: impl FnOnce(&StepContext) -> anyhow::Result<()> + Send + 'static) -> Self {
+        self.steps.push(Step {
+            name,
+            downloading: true,
+            run: Box::new(run)
+        });
+
+        self
+    }
+
+    /// Run pipeline steps in a separate thread
+    pub fn spawn(self) -> PipelineTask {
+        let (sender, receiver) = flume::unbounded();
+
+        let cancelled = Arc::new(AtomicBool::new(false));
+
+        let Self { info, steps } = self;
+
+        let names = steps.iter()
+            .map(|step| (step.name, step.downloading))
+            .collect();
+
+        let worker = std::thread::spawn({
+            let cancelled = cancelled.clone();
+
+            move || -> anyhow::Result<()> {
+                for (i, step) in steps.into_iter().enumerate() {
+                    let context = StepContext {
+                        step: i,
+                        sender: sender.clone(),
+                        cancelled: cancelled.clone()
+                    };
+
+                    context.check_cancelled()?;
+
+                    // Switch status to the new step
+                    context.set_progress(0, 0)?;
+
+                    (step.run)(&context).with_context(|| format!("Failed to run task step: {}", step.name))?;
+                }
+
+                Ok(())
+            }
+        });
+
+        PipelineTask {
+            info,
+            steps: names,
+
+            step: Cell::new(None),
+            current: Cell::new(0),
+            total: Cell::new(0),
+
+            cancelled,
+
+            worker: Some(worker),
+            worker_result: None,
+            updates: receiver
+        }
+    }
+}
+
+impl std::fmt::Debug for Pipeline {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Pipeline")
+            .field("info", &self.info)
+            .field("steps", &self.steps.iter().map(|step| step.name).collect::<Vec<_>>())
+            .finish()
+    }
+}
+
+/// Make a copy of the error with the same messages chain
+fn copy_error(err: &anyhow::Error) -> anyhow::Error {
+    let mut chain = err.chain()
+        .map(|err| err.to_string())
+        .collect::<Vec<_>>();
+
+    let mut copy = anyhow::anyhow!(chain.pop().unwrap_or_default());
+
+    while let Some(context) = chain.pop() {
+        copy = copy.context(context);
+    }
+
+    copy
+}
+
+/// Running pipeline
+#[derive(Debug)]
+pub struct PipelineTask {
+    info: CardInfo,
+    /// Names of the steps and whether they're downloading files
+    steps: Vec<(&'static str, bool)>,
+
+    step: Cell<Option<usize>>,
+    current: Cell<u64>,
+    total: Cell<u64>,
+
+    cancelled: Arc<AtomicBool>,
+
+    worker: Option<JoinHandle<anyhow::Result<()>>>,
+    worker_result: Option<anyhow::Result<()>>,
+    updates: flume::Receiver<PipelineUpdate>
+}
+
+impl PipelineTask {
+    fn update(&self) {
+        while let Ok(update) = self.updates.try_recv() {
+            self.step.set(Some(update.step));
+            self.current.set(update.current);
+            self.total.set(update.total);
+        }
+    }
+
+    fn poll_worker(&mut self) {
+        self.update();
+
+        if let Some(worker) = self.worker.take() {
+            if !worker.is_finished() {
+                self.worker = Some(worker);
+
+                return;
+            }
+
+            self.worker_result = Some(worker.join().expect("Failed to join task pipeline thread"));
+        }
+    }
+}
+
+impl ResolvedTask for PipelineTask {
+    #[inline]
+    fn get_info(&self) -> CardInfo {
+        self.info.clone()
+    }
+
+    #[inline]
+    fn is_finished(&mut self) -> bool {
+        self.poll_worker();
+
+        self.worker_result.is_some()
+    }
+
+    #[inline]
+    fn get_current(&self) -> u64 {
+        self.update();
+
+        self.current.get()
+    }
+
+    #[inline]
+    fn get_total(&self) -> u64 {
+        self.update();
+
+        self.total.get()
+    }
+
+    fn get_progress(&self) -> f64 {
+        self.update();
+
+        match self.total.get() {
+            0 => 0.0,
+            total => self.current.get() as f64 / total as f64
+        }
+    }
+
+    fn get_status(&mut self) -> anyhow::Result<TaskStatus> {
+        self.poll_worker();
+
+        match &self.worker_result {
+            Some(Ok(())) => Ok(TaskStatus::Finished),
+            Some(Err(err)) => Err(copy_error(err)),
+
+            None => match self.step.get() {
+                Some(step) => Ok(TaskStatus::Step {
+                    name: self.steps[step].0,
+                    pulse: self.total.get() == 0,
+                    downloading: self.steps[step].1
+                }),
+
+                None => Ok(TaskStatus::Pending)
+            }
+        }
+    }
+
+    #[inline]
+    fn can_cancel(&self) -> bool {
+        true
+    }
+
+    #[inline]
+    fn cancel(&mut self) {
+        self.cancelled.store(true, Ordering::Relaxed);
+    }
+
+    #[inline]
+    fn is_cancelled(&self) -> bool {
+        self.cancelled.load(Ordering::Relaxed) && matches!(self.worker_result, Some(Err(_)))
+    }
+}
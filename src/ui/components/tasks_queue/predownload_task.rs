@@ -0,0 +1,207 @@
+use std::path::PathBuf;
+use std::sync::Arc;
+use std::sync::atomic::{
+    AtomicU64,
+    Ordering
+};
+
+use anime_game_core::updater::{
+    UpdaterExt,
+    BasicUpdater,
+    Status as BasicStatus
+};
+
+use crate::ui::components::game_card::CardInfo;
+
+use crate::games::predownload;
+use crate::games::integrations::standards::prelude::*;
+
+use crate::network::mirrors::MirrorsDownloader;
+
+use crate::config;
+
+use super::{
+    QueuedTask,
+    ResolvedTask,
+    TaskStatus,
+    TaskPriority,
+    TaskDescriptor
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Status {
+    Downloading
+}
+
+#[derive(Debug, Clone)]
+pub struct PredownloadQueuedTask {
+    pub card_info: CardInfo,
+    pub diff_info: DiffInfo,
+
+    /// Path to the staging folder
+    pub staging_path: PathBuf
+}
+
+impl QueuedTask for PredownloadQueuedTask {
+    #[inline]
+    fn get_info(&self) -> CardInfo {
+        self.card_info.clone()
+    }
+
+    #[inline]
+    fn get_paths(&self) -> Vec<PathBuf> {
+        vec![self.staging_path.clone()]
+    }
+
+    #[inline]
+    fn get_priority(&self) -> TaskPriority {
+        TaskPriority::Game
+    }
+
+    #[inline]
+    fn get_descriptor(&self) -> TaskDescriptor {
+        TaskDescriptor::PredownloadGame {
+            game_info: self.card_info.clone()
+        }
+    }
+
+    fn resolve(self: Box<Self>) -> anyhow::Result<Box<dyn ResolvedTask>> {
+        let config = config::get();
+
+        let diff_info = self.diff_info.clone();
+        let staging_path = self.staging_path.clone();
+
+        Ok(Box::new(PredownloadResolvedTask {
+            card_info: self.card_info.clone(),
+
+            updater: BasicUpdater::spawn(move |sender| {
+                Box::new(move || -> Result<(), anyhow::Error> {
+                    let size = match &diff_info {
+                        DiffInfo::Archive { size, .. } |
+                        DiffInfo::Segments { size, .. } |
+                        DiffInfo::Files { size, .. } |
+                        DiffInfo::Patch { size, .. } => *size
+                    };
+
+                    let uris = match diff_info.clone() {
+                        DiffInfo::Archive { uri, .. } => vec![uri],
+                        DiffInfo::Segments { segments, .. } => segments,
+
+                        DiffInfo::Files { files, .. } => files.into_iter()
+                            .map(|file| file.uri)
+                            .collect(),
+
+                        DiffInfo::Patch { patches, .. } => patches.into_iter()
+                            .map(|patch| patch.uri)
+                            .collect()
+                    };
+
+                    // Pairs of files URIs and their paths in the staging folder
+                    let files = uris.into_iter()
+                        .zip(predownload::get_staged_files(&diff_info))
+                        .collect::<Vec<_>>();
+
+                    let pool = rusty_pool::Builder::new()
+                        .name(String::from("predownload_files"))
+                        .core_size(config.general.threads.number as usize)
+                        .build();
+
+                    let queue_size = config.general.threads.max_queue_size as usize;
+
+                    let mut tasks = Vec::with_capacity(queue_size);
+
+                    // Downloaded bytes of every file
+                    let progress = Arc::new(files.iter()
+                        .map(|_| AtomicU64::new(0))
+                        .collect::<Vec<_>>());
+
+                    sender.send((Status::Downloading, 0, size))?;
+
+                    for (i, (uri, relative_path)) in files.into_iter().enumerate() {
+                        let path = staging_path.join(relative_path);
+
+                        let progress = progress.clone();
+                        let sender = sender.clone();
+
+                        // Already downloaded files are continued
+                        // so pre-download can be restarted at any moment
+                        tasks.push(pool.evaluate(move || -> anyhow::Result<()> {
+                            MirrorsDownloader::new(uri).download(path, |current, _| {
+                                progress[i].store(current, Ordering::Relaxed);
+
+                                sender.send((
+                                    Status::Downloading,
+                                    progress.iter().map(|current| current.load(Ordering::Relaxed)).sum(),
+                                    size
+                                ))?;
+
+                                Ok(())
+                            })
+                        }));
+
+                        if tasks.len() >= queue_size {
+                            for task in tasks.drain(..) {
+                                task.await_complete()?;
+                            }
+                        }
+                    }
+
+                    for task in tasks.drain(..) {
+                        task.await_complete()?;
+                    }
+
+                    predownload::mark_finished(&staging_path)?;
+
+                    Ok(())
+                })
+            })
+        }))
+    }
+}
+
+#[derive(Debug)]
+pub struct PredownloadResolvedTask {
+    pub updater: BasicUpdater<Status, (), anyhow::Error>,
+    pub card_info: CardInfo
+}
+
+impl ResolvedTask for PredownloadResolvedTask {
+    #[inline]
+    fn get_info(&self) -> CardInfo {
+        self.card_info.clone()
+    }
+
+    #[inline]
+    fn is_finished(&mut self) -> bool {
+        self.updater.is_finished()
+    }
+
+    #[inline]
+    fn get_current(&self) -> u64 {
+        self.updater.current()
+    }
+
+    #[inline]
+    fn get_total(&self) -> u64 {
+        self.updater.total()
+    }
+
+    #[inline]
+    fn get_progress(&self) -> f64 {
+        self.updater.progress()
+    }
+
+    fn get_status(&mut self) -> anyhow::Result<TaskStatus> {
+        match self.updater.status() {
+            Ok(status) => Ok(match status {
+                BasicStatus::Pending => TaskStatus::Pending,
+
+                BasicStatus::Working(Status::Downloading) => TaskStatus::Downloading,
+
+                BasicStatus::Finished => TaskStatus::Finished
+            }),
+
+            Err(err) => anyhow::bail!(err.to_string())
+        }
+    }
+}
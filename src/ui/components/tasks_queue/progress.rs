@@ -0,0 +1,148 @@
+use std::time::{Instant, Duration};
+
+/// Min time between speed samples
+///
+/// Tasks are polled much more often than they
+/// report progress, so shorter samples are mostly zeros
+pub const SAMPLE_INTERVAL: Duration = Duration::from_millis(500);
+
+/// Weight of the newest speed sample in the smoothed speed
+pub const SMOOTHING_FACTOR: f64 = 0.3;
+
+/// Speed and ETA calculator of the task progress
+///
+/// Works the same way for every task updater
+/// because it only needs current and total progress values
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ProgressTracker {
+    last_sample: Instant,
+    last_current: u64,
+
+    current: u64,
+    total: u64,
+
+    /// Time of the last update
+    updated: Instant,
+
+    /// Whether the task was downloading files during the last update
+    downloading: bool,
+
+    /// Bytes downloaded by the task in all the downloading stages
+    transferred: u64,
+
+    /// Time spent in the downloading stages
+    transfer_time: Duration,
+
+    /// Exponential moving average of the progress speed
+    speed: Option<f64>
+}
+
+impl Default for ProgressTracker {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ProgressTracker {
+    pub fn new() -> Self {
+        let now = Instant::now();
+
+        Self {
+            last_sample: now,
+            last_current: 0,
+
+            current: 0,
+            total: 0,
+
+            updated: now,
+
+            downloading: false,
+            transferred: 0,
+            transfer_time: Duration::ZERO,
+
+            speed: None
+        }
+    }
+
+    /// Update tracker with the current task progress
+    ///
+    /// `downloading` should be true if the progress
+    /// is the amount of downloaded bytes
+    pub fn update(&mut self, current: u64, total: u64, downloading: bool) {
+        let now = Instant::now();
+
+        // Tasks reset their progress when switching
+        // from one stage to another, e.g. from downloading to unpacking
+        let stage_changed = current < self.last_current || total != self.total;
+
+        if downloading && self.downloading {
+            if !stage_changed {
+                self.transferred += current.saturating_sub(self.current);
+            }
+
+            self.transfer_time += now.duration_since(self.updated);
+        }
+
+        self.updated = now;
+        self.downloading = downloading;
+
+        if stage_changed {
+            self.last_sample = now;
+            self.last_current = current;
+
+            self.speed = None;
+        }
+
+        self.current = current;
+        self.total = total;
+
+        let elapsed = now.duration_since(self.last_sample);
+
+        if elapsed >= SAMPLE_INTERVAL {
+            let sample = current.saturating_sub(self.last_current) as f64 / elapsed.as_secs_f64();
+
+            self.speed = Some(match self.speed {
+                Some(speed) => speed + SMOOTHING_FACTOR * (sample - speed),
+                None => sample
+            });
+
+            self.last_sample = now;
+            self.last_current = current;
+        }
+    }
+
+    #[inline]
+    /// Smoothed progress speed per second
+    ///
+    /// `None` if there's not enough samples yet
+    pub fn speed(&self) -> Option<u64> {
+        self.speed.map(|speed| speed.round() as u64)
+    }
+
+    /// Average downloading speed in bytes per second
+    ///
+    /// `None` if the task didn't download anything
+    pub fn average_speed(&self) -> Option<u64> {
+        let transferred = self.transferred()?;
+
+        Some((transferred as f64 / self.transfer_time.as_secs_f64().max(1.0)).ceil() as u64)
+    }
+
+    #[inline]
+    /// Amount of bytes downloaded by the task
+    ///
+    /// `None` if the task didn't download anything
+    pub fn transferred(&self) -> Option<u64> {
+        (self.transferred > 0).then_some(self.transferred)
+    }
+
+    /// Estimated time remaining
+    ///
+    /// `None` if there's not enough samples yet or the task doesn't progress
+    pub fn eta(&self) -> Option<Duration> {
+        let speed = self.speed.filter(|speed| *speed > 0.0)?;
+
+        Some(Duration::from_secs_f64(self.total.saturating_sub(self.current) as f64 / speed))
+    }
+}
@@ -1,5 +1,11 @@
+use std::path::PathBuf;
+
+use serde::{Serialize, Deserialize};
+
 use crate::ui::components::game_card::CardInfo;
 
+use super::verify_integrity_task::{VerifyMode, VerifyReport};
+
 #[derive(Debug, Clone, PartialEq, Eq)]
 /// All the possible tasks statuses in one enum
 pub enum TaskStatus {
@@ -8,22 +14,122 @@ pub enum TaskStatus {
     RunPreTransitionCode,
     Downloading,
     Unpacking,
+    ApplyingPatches,
     RunTransitionCode,
     FinishingTransition,
     RunPostTransitionCode,
-    CreatingPrefix,
-    InstallingDxvk,
-    InstallingFonts,
     VerifyingFiles,
     RepairingFiles,
-    DeletingFiles,
+
+    /// Step of the task pipeline
+    Step {
+        /// Locale key of the step title
+        name: &'static str,
+
+        /// Step doesn't report its progress
+        pulse: bool,
+
+        /// Step progress is the amount of downloaded bytes
+        downloading: bool
+    },
+
     Finished
 }
 
+impl TaskStatus {
+    #[inline]
+    /// Check if the task progress is the amount of downloaded bytes
+    pub fn is_downloading(&self) -> bool {
+        matches!(self, Self::Downloading | Self::Step { downloading: true, .. })
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+/// Queued tasks with higher priority are started first
+pub enum TaskPriority {
+    Game,
+    Addon,
+    Component
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+/// Information needed to queue the task again after the launcher restart
+pub enum TaskDescriptor {
+    DownloadGame {
+        game_info: CardInfo
+    },
+
+    VerifyGame {
+        game_info: CardInfo,
+
+        #[serde(default)]
+        mode: VerifyMode,
+
+        #[serde(default)]
+        dry_run: bool
+    },
+
+    PredownloadGame {
+        game_info: CardInfo
+    },
+
+    DownloadAddon {
+        game_info: CardInfo,
+        group_name: String,
+        addon_name: String
+    },
+
+    VerifyAddon {
+        game_info: CardInfo,
+        group_name: String,
+        addon_name: String,
+
+        #[serde(default)]
+        mode: VerifyMode,
+
+        #[serde(default)]
+        dry_run: bool
+    },
+
+    DeleteFiles {
+        paths: Vec<PathBuf>
+    },
+
+    DownloadWine {
+        name: String
+    },
+
+    DownloadDxvk {
+        name: String
+    },
+
+    ApplyDxvk {
+        name: String,
+        prefix_path: PathBuf
+    },
+
+    CreatePrefix {
+        path: PathBuf,
+        install_corefonts: bool
+    }
+}
+
 pub trait QueuedTask: Send + std::fmt::Debug {
     /// Get component info
     fn get_info(&self) -> CardInfo;
 
+    /// Get paths used by the task
+    ///
+    /// Tasks with intersecting paths are never run at the same time
+    fn get_paths(&self) -> Vec<PathBuf>;
+
+    /// Get task priority
+    fn get_priority(&self) -> TaskPriority;
+
+    /// Get task descriptor used to save the queue
+    fn get_descriptor(&self) -> TaskDescriptor;
+
     /// Resolve queued task and start downloading stuff
     fn resolve(self: Box<Self>) -> anyhow::Result<Box<dyn ResolvedTask>>;
 }
@@ -46,4 +152,26 @@ pub trait ResolvedTask: Send + std::fmt::Debug {
 
     /// Get task status
     fn get_status(&mut self) -> anyhow::Result<TaskStatus>;
+
+    #[inline]
+    /// Check if the task can be cancelled while running
+    fn can_cancel(&self) -> bool {
+        false
+    }
+
+    #[inline]
+    /// Ask the task to stop
+    fn cancel(&mut self) {}
+
+    #[inline]
+    /// Check if the task was stopped because of cancellation
+    fn is_cancelled(&self) -> bool {
+        false
+    }
+
+    #[inline]
+    /// Take report made by the finished task, e.g. by the dry run verification
+    fn take_report(&mut self) -> Option<VerifyReport> {
+        None
+    }
 }
@@ -1,10 +1,12 @@
-use std::path::PathBuf;
-use std::sync::Arc;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex};
 use std::sync::atomic::{
     AtomicU64,
     Ordering
 };
 
+use serde::{Serialize, Deserialize};
+
 use anime_game_core::filesystem::transition::Transition;
 
 use anime_game_core::updater::{
@@ -13,37 +15,117 @@ use anime_game_core::updater::{
     Status as BasicStatus
 };
 
-use anime_game_core::network::downloader::{
-    DownloaderExt,
-    basic::Downloader
-};
-
 use crate::ui::components::game_card::CardInfo;
 
 use crate::games;
 use crate::games::integrations::standards::prelude::*;
+use crate::games::verify_cache::{VerifyCache, VerifiedFile};
+
+use crate::network::mirrors::MirrorsDownloader;
+
+use crate::cache::FilesCache;
 
 use crate::config;
 
+use super::download_diff_task::DiffOrigin;
+
 use super::{
     QueuedTask,
     ResolvedTask,
-    TaskStatus
+    TaskStatus,
+    TaskPriority,
+    TaskDescriptor
 };
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum Status {
     PreparingTransition,
+    RunPreTransitionCode,
     VerifyingFiles,
     RepairingFiles,
-    FinishingTransition
+    RunTransitionCode,
+    FinishingTransition,
+    RunPostTransitionCode
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum VerifyMode {
+    /// Hash only files which were changed since the last verification
+    Quick,
+
+    /// Hash all the files
+    #[default]
+    Full
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize)]
+#[serde(tag = "problem", rename_all = "snake_case")]
+pub enum FileProblem {
+    Missing {
+        expected_size: u64
+    },
+
+    SizeMismatch {
+        expected_size: u64,
+        actual_size: u64
+    },
+
+    HashMismatch {
+        hash_type: String,
+        expected_hash: String,
+        actual_hash: String
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize)]
+pub struct BrokenFile {
+    /// Path relative to the game folder
+    pub path: String,
+
+    #[serde(flatten)]
+    pub problem: FileProblem
+}
+
+/// Result of the game verification without repairing
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct VerifyReport {
+    pub game: CardInfo,
+    pub origin: DiffOrigin,
+    pub path: PathBuf,
+
+    /// UTC timestamp of the report creation
+    pub created_at: i64,
+
+    /// Amount of verified files
+    pub total_files: u64,
+
+    pub broken_files: Vec<BrokenFile>,
+
+    /// Integrity info of the broken files, needed to repair them
+    #[serde(skip)]
+    pub integrity_info: Vec<IntegrityInfo>
+}
+
+impl VerifyReport {
+    /// Save report to the given file in JSON format
+    pub fn export(&self, path: impl AsRef<Path>) -> anyhow::Result<()> {
+        std::fs::write(path, serde_json::to_vec_pretty(self)?)?;
+
+        Ok(())
+    }
 }
 
 #[derive(Debug, Clone)]
 pub struct VerifyIntegrityQueuedTask {
     pub card_info: CardInfo,
+    pub origin: DiffOrigin,
     pub integrity_info: Vec<IntegrityInfo>,
-    pub path: PathBuf
+    pub path: PathBuf,
+    pub mode: VerifyMode,
+
+    /// Only make report of the broken files without repairing them
+    pub dry_run: bool
 }
 
 impl QueuedTask for VerifyIntegrityQueuedTask {
@@ -52,6 +134,37 @@ impl QueuedTask for VerifyIntegrityQueuedTask {
         self.card_info.clone()
     }
 
+    #[inline]
+    fn get_paths(&self) -> Vec<PathBuf> {
+        vec![self.path.clone()]
+    }
+
+    #[inline]
+    fn get_priority(&self) -> TaskPriority {
+        match &self.origin {
+            DiffOrigin::Game => TaskPriority::Game,
+            DiffOrigin::Addon { .. } => TaskPriority::Addon
+        }
+    }
+
+    fn get_descriptor(&self) -> TaskDescriptor {
+        match &self.origin {
+            DiffOrigin::Game => TaskDescriptor::VerifyGame {
+                game_info: self.card_info.clone(),
+                mode: self.mode,
+                dry_run: self.dry_run
+            },
+
+            DiffOrigin::Addon { group_name, addon_name } => TaskDescriptor::VerifyAddon {
+                game_info: self.card_info.clone(),
+                group_name: group_name.clone(),
+                addon_name: addon_name.clone(),
+                mode: self.mode,
+                dry_run: self.dry_run
+            }
+        }
+    }
+
     fn resolve(self: Box<Self>) -> anyhow::Result<Box<dyn ResolvedTask>> {
         let config = config::get();
 
@@ -59,11 +172,19 @@ impl QueuedTask for VerifyIntegrityQueuedTask {
         let game_edition = self.card_info.get_edition().to_string();
 
         let integrity_info = self.integrity_info.clone();
+        let origin = self.origin.clone();
 
         let path = self.path.clone();
+        let mode = self.mode;
+        let dry_run = self.dry_run;
+
+        let card_info = self.card_info.clone();
+
+        let report = Arc::new(Mutex::new(None));
 
         Ok(Box::new(VerifyIntegrityResolvedTask {
             card_info: self.card_info.clone(),
+            report: report.clone(),
 
             updater: BasicUpdater::spawn(move |sender| {
                 Box::new(move || -> Result<(), anyhow::Error> {
@@ -71,20 +192,44 @@ impl QueuedTask for VerifyIntegrityQueuedTask {
                         games::get_unsafe(&game_name)
                     };
 
-                    // Check if lua script support custom hashes
-                    let has_integrity_hash = game.driver.has_integrity_hash()?;
-
                     // Create transition
+                    // Dry run doesn't change files so it can be used on read-only folders
+
+                    let transition = if dry_run {
+                        None
+                    }
+
+                    else {
+                        sender.send((Status::PreparingTransition, 0, 1))?;
+
+                        let transition = Transition::get_in(
+                            format!("verify-integrity:{game_name}:{game_edition}:{:?}", integrity_info),
+                            &path,
+                            config.general.transitions.path
+                        )?;
+
+                        sender.send((Status::PreparingTransition, 1, 1))?;
+
+                        // Run pre-transition code
+                        // Game hooks are made for updates so only addon hooks are used here
 
-                    sender.send((Status::PreparingTransition, 0, 1))?;
+                        if let DiffOrigin::Addon { group_name, addon_name } = &origin {
+                            if game.driver.has_addons_diff_pre_transition()? {
+                                sender.send((Status::RunPreTransitionCode, 0, 1))?;
 
-                    let transition = Transition::get_in(
-                        format!("verify-integrity:{game_name}:{game_edition}:{:?}", integrity_info),
-                        &path,
-                        config.general.transitions.path
-                    )?;
+                                game.driver.run_addons_diff_pre_transition(
+                                    group_name,
+                                    addon_name,
+                                    &transition.original_path().to_string_lossy(),
+                                    &game_edition
+                                )?;
 
-                    sender.send((Status::PreparingTransition, 1, 1))?;
+                                sender.send((Status::RunPreTransitionCode, 1, 1))?;
+                            }
+                        }
+
+                        Some(transition)
+                    };
 
                     // Verify files
 
@@ -95,11 +240,31 @@ impl QueuedTask for VerifyIntegrityQueuedTask {
 
                     let queue_size = config.general.threads.max_queue_size as usize;
 
+                    let cache = FilesCache::from_config(&config.general.cache);
+
+                    // Full verification doesn't use the cache but still updates it
+                    // so entries of the files which aren't verified now (e.g. when
+                    // repairing only broken files) are kept
+                    let verify_cache = match &origin {
+                        DiffOrigin::Game
+                            => VerifyCache::load_for_game(&game_name, &game_edition, &path),
+
+                        DiffOrigin::Addon { group_name, addon_name }
+                            => VerifyCache::load_for_addon(&game_name, &game_edition, group_name, addon_name, &path)
+                    };
+
+                    let mut verify_cache = verify_cache.unwrap_or_else(|err| {
+                        tracing::warn!("Failed to load verification cache: {err}");
+
+                        VerifyCache::new(&path)
+                    });
+
                     let total = integrity_info.len() as u64;
                     let current = Arc::new(AtomicU64::new(0));
 
                     let mut tasks = Vec::with_capacity(queue_size);
                     let mut broken_files = Vec::new();
+                    let mut problems = Vec::new();
 
                     sender.send((
                         Status::VerifyingFiles,
@@ -111,12 +276,46 @@ impl QueuedTask for VerifyIntegrityQueuedTask {
                     for chunk in integrity_info.chunks(queue_size) {
                         for info in chunk.iter().cloned() {
                             let integrity_file = path.join(&info.file.path);
-    
+
+                            let metadata = integrity_file.metadata().ok();
+
                             // Stop immediately if the file doesn't exist
                             // or its size is different from the remote file
-                            if !integrity_file.exists() || integrity_file.metadata()?.len() != info.file.size {
-                                broken_files.push(info.file);
+                            let Some(metadata) = metadata.filter(|metadata| metadata.len() == info.file.size) else {
+                                verify_cache.files.remove(&info.file.path);
+
+                                problems.push(BrokenFile {
+                                    path: info.file.path.clone(),
+                                    problem: match metadata {
+                                        Some(metadata) => FileProblem::SizeMismatch {
+                                            expected_size: info.file.size,
+                                            actual_size: metadata.len()
+                                        },
+
+                                        None => FileProblem::Missing {
+                                            expected_size: info.file.size
+                                        }
+                                    }
+                                });
+
+                                broken_files.push(info);
+
+                                sender.send((
+                                    Status::VerifyingFiles,
+                                    current.fetch_add(1, Ordering::Relaxed) + 1,
+                                    total
+                                ))?;
+
+                                continue;
+                            }
 
+                            let file_hash = FileHash {
+                                r#type: info.hash.clone(),
+                                value: info.value.clone()
+                            };
+
+                            // Skip files which weren't changed since the last verification
+                            if mode == VerifyMode::Quick && verify_cache.is_verified(&info.file.path, &metadata, &file_hash) {
                                 sender.send((
                                     Status::VerifyingFiles,
                                     current.fetch_add(1, Ordering::Relaxed) + 1,
@@ -130,43 +329,9 @@ impl QueuedTask for VerifyIntegrityQueuedTask {
                             let sender = sender.clone();
 
                             // Otherwise verifying the file is a heavy task so we put it to the threads pool
-                            tasks.push(pool.evaluate(move || -> anyhow::Result<Option<DiffFileDownload>> {
-                                // Read existing file
-                                let data = std::fs::read(&integrity_file)?;
-
+                            tasks.push(pool.evaluate(move || -> anyhow::Result<(IntegrityInfo, Result<VerifiedFile, FileProblem>)> {
                                 // Get existing file hash
-                                let hash = match info.hash {
-                                    HashType::Md5 => {
-                                        use md5::{Md5, Digest};
-
-                                        format!("{:x}", Md5::digest(&data))
-                                    },
-
-                                    HashType::Sha1 => {
-                                        use sha1::{Sha1, Digest};
-
-                                        format!("{:x}", Sha1::digest(&data))
-                                    },
-
-                                    HashType::Crc32 => {
-                                        let mut hasher = crc32fast::Hasher::new();
-
-                                        hasher.update(&data);
-
-                                        hasher.finalize().to_string()
-                                    }
-
-                                    HashType::Xxhash32    => format!("{:x}", xxhash_rust::xxh32::xxh32(&data, 0)),
-                                    HashType::Xxhash64    => format!("{:x}", xxhash_rust::xxh64::xxh64(&data, 0)),
-                                    HashType::Xxhash3_64  => format!("{:x}", xxhash_rust::xxh3::xxh3_64(&data)),
-                                    HashType::Xxhash3_128 => format!("{:x}", xxhash_rust::xxh3::xxh3_128(&data)),
-
-                                    HashType::Custom(name) if has_integrity_hash => {
-                                        game.driver.integrity_hash(&name, data)?
-                                    }
-
-                                    _ => unimplemented!()
-                                };
+                                let hash = info.hash.hash_file(&integrity_file, &game.driver)?;
 
                                 sender.send((
                                     Status::VerifyingFiles,
@@ -176,21 +341,68 @@ impl QueuedTask for VerifyIntegrityQueuedTask {
 
                                 // Compare existing file hash with integrity info
                                 if info.value != hash {
-                                    return Ok(Some(info.file));
+                                    let problem = FileProblem::HashMismatch {
+                                        hash_type: info.hash.to_str(IntegrationStandard::V1).to_string(),
+                                        expected_hash: info.value.clone(),
+                                        actual_hash: hash
+                                    };
+
+                                    return Ok((info, Err(problem)));
                                 }
 
-                                Ok(None)
+                                // Metadata is taken before hashing so changes made
+                                // during the verification will be noticed next time
+                                Ok((info, Ok(VerifiedFile::new(&metadata, &file_hash))))
                             }));
                         }
 
                         // Wait for current chunk of files to finish verifying
                         for task in tasks.drain(..) {
-                            if let Some(file) = task.await_complete()? {
-                                broken_files.push(file);
+                            match task.await_complete()? {
+                                (info, Ok(verified)) => {
+                                    verify_cache.files.insert(info.file.path, verified);
+                                }
+
+                                (info, Err(problem)) => {
+                                    verify_cache.files.remove(&info.file.path);
+
+                                    problems.push(BrokenFile {
+                                        path: info.file.path.clone(),
+                                        problem
+                                    });
+
+                                    broken_files.push(info);
+                                }
                             }
                         }
                     }
 
+                    // Repaired files will be hashed during the next verification
+                    let saved = match &origin {
+                        DiffOrigin::Game => verify_cache.save_for_game(&game_name, &game_edition),
+
+                        DiffOrigin::Addon { group_name, addon_name }
+                            => verify_cache.save_for_addon(&game_name, &game_edition, group_name, addon_name)
+                    };
+
+                    if let Err(err) = saved {
+                        tracing::warn!("Failed to save verification cache: {err}");
+                    }
+
+                    let Some(transition) = transition else {
+                        *report.lock().unwrap() = Some(VerifyReport {
+                            game: card_info,
+                            origin,
+                            path,
+                            created_at: chrono::Utc::now().timestamp(),
+                            total_files: total,
+                            broken_files: problems,
+                            integrity_info: broken_files
+                        });
+
+                        return Ok(());
+                    };
+
                     sender.send((
                         Status::VerifyingFiles,
                         total,
@@ -212,9 +424,15 @@ impl QueuedTask for VerifyIntegrityQueuedTask {
 
                     // Go through the broken files list
                     for chunk in broken_files.chunks(queue_size) {
-                        for file in chunk.iter().cloned() {
-                            let file_path = path.join(&file.path);
+                        for info in chunk.iter().cloned() {
+                            let file_path = path.join(&info.file.path);
 
+                            let file_hash = FileHash {
+                                r#type: info.hash,
+                                value: info.value
+                            };
+
+                            let cache = cache.clone();
                             let current = current.clone();
                             let sender = sender.clone();
 
@@ -227,11 +445,33 @@ impl QueuedTask for VerifyIntegrityQueuedTask {
                                     }
                                 }
 
-                                // Download the file
-                                Downloader::new(file.uri)
-                                    .continue_downloading(false)
-                                    .download(file_path)?
-                                    .wait()?;
+                                // Use cached file if it has the same hash
+                                let cached = match &cache {
+                                    Some(cache) => cache.get(&file_hash, info.file.size, &file_path, &game.driver)
+                                        .unwrap_or_else(|err| {
+                                            tracing::warn!("Failed to get file {file_path:?} from the cache: {err}");
+
+                                            false
+                                        }),
+
+                                    None => false
+                                };
+
+                                if !cached {
+                                    // Download the file
+                                    MirrorsDownloader::new(info.file.uri)
+                                        .with_continue_downloading(false)
+                                        .download(&file_path, |_, _| Ok(()))?;
+
+                                    // Put the file to the cache if it's correct
+                                    if let Some(cache) = &cache {
+                                        if file_hash.verify_file(&file_path, &game.driver)? {
+                                            if let Err(err) = cache.put(&file_hash, info.file.size, &file_path) {
+                                                tracing::warn!("Failed to put file {file_path:?} to the cache: {err}");
+                                            }
+                                        }
+                                    }
+                                }
 
                                 sender.send((
                                     Status::RepairingFiles,
@@ -255,6 +495,23 @@ impl QueuedTask for VerifyIntegrityQueuedTask {
                         total
                     ))?;
 
+                    // Run transition code
+
+                    if let DiffOrigin::Addon { group_name, addon_name } = &origin {
+                        if game.driver.has_addons_diff_transition()? {
+                            sender.send((Status::RunTransitionCode, 0, 1))?;
+
+                            game.driver.run_addons_diff_transition(
+                                group_name,
+                                addon_name,
+                                &transition.transition_path().to_string_lossy(),
+                                &game_edition
+                            )?;
+
+                            sender.send((Status::RunTransitionCode, 1, 1))?;
+                        }
+                    }
+
                     // Finish transition
 
                     sender.send((Status::FinishingTransition, 0, 1))?;
@@ -263,6 +520,23 @@ impl QueuedTask for VerifyIntegrityQueuedTask {
 
                     sender.send((Status::FinishingTransition, 1, 1))?;
 
+                    // Run post-transition code
+
+                    if let DiffOrigin::Addon { group_name, addon_name } = &origin {
+                        if game.driver.has_addons_diff_post_transition()? {
+                            sender.send((Status::RunPostTransitionCode, 0, 1))?;
+
+                            game.driver.run_addons_diff_post_transition(
+                                group_name,
+                                addon_name,
+                                &transition.original_path().to_string_lossy(),
+                                &game_edition
+                            )?;
+
+                            sender.send((Status::RunPostTransitionCode, 1, 1))?;
+                        }
+                    }
+
                     Ok(())
                 })
             })
@@ -273,7 +547,10 @@ impl QueuedTask for VerifyIntegrityQueuedTask {
 #[derive(Debug)]
 pub struct VerifyIntegrityResolvedTask {
     pub updater: BasicUpdater<Status, (), anyhow::Error>,
-    pub card_info: CardInfo
+    pub card_info: CardInfo,
+
+    /// Report of the dry run
+    pub report: Arc<Mutex<Option<VerifyReport>>>
 }
 
 impl ResolvedTask for VerifyIntegrityResolvedTask {
@@ -307,10 +584,13 @@ impl ResolvedTask for VerifyIntegrityResolvedTask {
             Ok(status) => Ok(match status {
                 BasicStatus::Pending => TaskStatus::Pending,
 
-                BasicStatus::Working(Status::PreparingTransition) => TaskStatus::PreparingTransition,
-                BasicStatus::Working(Status::VerifyingFiles)      => TaskStatus::VerifyingFiles,
-                BasicStatus::Working(Status::RepairingFiles)      => TaskStatus::RepairingFiles,
-                BasicStatus::Working(Status::FinishingTransition) => TaskStatus::FinishingTransition,
+                BasicStatus::Working(Status::PreparingTransition)   => TaskStatus::PreparingTransition,
+                BasicStatus::Working(Status::RunPreTransitionCode)  => TaskStatus::RunPreTransitionCode,
+                BasicStatus::Working(Status::VerifyingFiles)        => TaskStatus::VerifyingFiles,
+                BasicStatus::Working(Status::RepairingFiles)        => TaskStatus::RepairingFiles,
+                BasicStatus::Working(Status::RunTransitionCode)     => TaskStatus::RunTransitionCode,
+                BasicStatus::Working(Status::FinishingTransition)   => TaskStatus::FinishingTransition,
+                BasicStatus::Working(Status::RunPostTransitionCode) => TaskStatus::RunPostTransitionCode,
 
                 BasicStatus::Finished => TaskStatus::Finished
             }),
@@ -318,4 +598,9 @@ impl ResolvedTask for VerifyIntegrityResolvedTask {
             Err(err) => anyhow::bail!(err.to_string())
         }
     }
+
+    #[inline]
+    fn take_report(&mut self) -> Option<VerifyReport> {
+        self.report.lock().ok()?.take()
+    }
 }
@@ -1,4 +1,5 @@
 use std::collections::HashSet;
+use std::path::PathBuf;
 
 use relm4::prelude::*;
 
@@ -10,20 +11,35 @@ use crate::tr;
 use crate::config;
 use crate::games;
 
+use crate::games::layers;
+
 use crate::config::games::settings::edition_addons::GameEditionAddon;
+use crate::config::games::settings::edition_profiles::GameEditionProfiles;
 
 use crate::games::integrations::standards::addons::{
     Addon,
-    AddonsGroup
+    AddonsGroup,
+    AddonType
 };
 
 use crate::ui::components::addon::addon_group::{
     AddonsGroupComponent,
     AddonsGroupComponentInit,
+    AddonsGroupComponentInput,
     AddonsGroupComponentOutput
 };
 
+use crate::ui::components::addon::layers_order::{
+    LayersOrderComponent,
+    LayersOrderComponentInput,
+    LayersOrderComponentOutput,
+    LayerInfo
+};
+
 use crate::ui::components::game_card::CardInfo;
+use crate::ui::components::tasks_queue::verify_integrity_task::VerifyMode;
+
+use crate::ui::windows::loading::check_addons;
 
 use super::main::MainAppMsg;
 
@@ -34,9 +50,20 @@ pub struct GameAddonsManagerApp {
     pub addons_groups_widgets: Vec<AsyncController<AddonsGroupComponent>>,
     pub addons_groups_page: adw::PreferencesPage,
 
+    pub layers_order_widget: AsyncController<LayersOrderComponent>,
+
     pub game_info: CardInfo,
+    pub game_path: PathBuf,
+    pub addons_groups: Vec<AddonsGroup>,
 
-    pub enabled_addons: HashSet<GameEditionAddon>
+    pub enabled_addons: HashSet<GameEditionAddon>,
+    pub installed_addons: HashSet<GameEditionAddon>,
+    pub layers_order: Vec<GameEditionAddon>,
+
+    /// Addons profiles of the game edition. Enabled addons belong to the active one
+    pub profiles: GameEditionProfiles,
+    pub profiles_names: Vec<String>,
+    pub profiles_model: gtk::StringList
 }
 
 #[derive(Debug, Clone)]
@@ -56,9 +83,109 @@ pub enum GameAddonsManagerAppMsg {
         group: AddonsGroup
     },
 
+    VerifyAddon {
+        addon: Addon,
+        group: AddonsGroup
+    },
+
     ToggleAddon {
         addon: GameEditionAddon,
         enabled: bool
+    },
+
+    SetLayersOrder(Vec<GameEditionAddon>),
+
+    SelectProfile(String),
+
+    /// Ask name of the new profile
+    CreateProfile {
+        duplicate: bool
+    },
+
+    /// Add new profile. Duplicates the active one if `duplicate` is set
+    AddProfile {
+        name: String,
+        duplicate: bool
+    },
+
+    DeleteProfile
+}
+
+impl GameAddonsManagerApp {
+    /// Rebuild profiles dropdown model
+    fn update_profiles_model(&mut self) {
+        self.profiles_names = self.profiles.get_names();
+
+        self.profiles_model = gtk::StringList::new(&self.profiles_names.iter()
+            .map(String::as_str)
+            .collect::<Vec<_>>());
+    }
+
+    /// Load enabled addons of the active profile
+    fn load_active_profile(&mut self) {
+        self.enabled_addons = HashSet::from_iter(self.profiles.get_active().iter().cloned());
+
+        for group in &self.addons_groups_widgets {
+            group.emit(AddonsGroupComponentInput::SetEnabledAddons(self.enabled_addons.clone()));
+        }
+
+        self.update_layers();
+    }
+
+    /// Store enabled addons in the active profile and save profiles
+    fn save_profiles(&mut self, sender: &AsyncComponentSender<Self>) {
+        let enabled_addons = self.enabled_addons.iter()
+            .cloned()
+            .collect();
+
+        self.profiles.profiles.insert(self.profiles.active.clone(), enabled_addons);
+
+        sender.output(MainAppMsg::SetAddonsProfiles {
+            game: self.game_info.clone(),
+            profiles: self.profiles.clone()
+        }).unwrap();
+    }
+
+    /// Send enabled and installed layers to the load order group
+    fn update_layers(&self) {
+        let mut layers = Vec::new();
+
+        for group in &self.addons_groups {
+            for addon in &group.addons {
+                let entry = GameEditionAddon {
+                    group: group.name.clone(),
+                    name: addon.name.clone()
+                };
+
+                if addon.r#type != AddonType::Layer || !self.installed_addons.contains(&entry) {
+                    continue;
+                }
+
+                if !addon.required && !self.enabled_addons.contains(&entry) {
+                    continue;
+                }
+
+                let Ok(path) = addon.get_installation_path(&group.name, self.game_info.get_name(), self.game_info.get_edition()) else {
+                    continue;
+                };
+
+                layers.push((entry.clone(), LayerInfo {
+                    addon: entry,
+                    title: addon.title.clone(),
+                    path
+                }));
+            }
+        }
+
+        let layers = layers::sort_layers(&self.layers_order, layers)
+            .into_iter()
+            .map(|(_, layer)| layer)
+            .collect();
+
+        self.layers_order_widget.emit(LayersOrderComponentInput::SetLayers {
+            game_path: self.game_path.clone(),
+            layers
+        });
     }
 }
 
@@ -82,7 +209,55 @@ impl SimpleAsyncComponent for GameAddonsManagerApp {
                 set_orientation: gtk::Orientation::Vertical,
 
                 adw::HeaderBar {
-                    add_css_class: "flat"
+                    add_css_class: "flat",
+
+                    pack_start = &gtk::DropDown {
+                        set_tooltip: &tr!("addon-profile"),
+
+                        #[watch]
+                        #[block_signal(profile_handler)]
+                        set_model: Some(&model.profiles_model),
+
+                        #[watch]
+                        #[block_signal(profile_handler)]
+                        set_selected: model.profiles_names.iter()
+                            .position(|name| name == &model.profiles.active)
+                            .unwrap_or(0) as u32,
+
+                        connect_selected_notify[sender] => move |dropdown| {
+                            if let Some(name) = dropdown.selected_item().and_downcast::<gtk::StringObject>() {
+                                sender.input(GameAddonsManagerAppMsg::SelectProfile(name.string().to_string()));
+                            }
+                        } @profile_handler
+                    },
+
+                    pack_start = &gtk::Button {
+                        set_icon_name: "list-add-symbolic",
+                        set_tooltip: &tr!("addon-profile-create"),
+
+                        connect_clicked => GameAddonsManagerAppMsg::CreateProfile {
+                            duplicate: false
+                        }
+                    },
+
+                    pack_start = &gtk::Button {
+                        set_icon_name: "edit-copy-symbolic",
+                        set_tooltip: &tr!("addon-profile-duplicate"),
+
+                        connect_clicked => GameAddonsManagerAppMsg::CreateProfile {
+                            duplicate: true
+                        }
+                    },
+
+                    pack_start = &gtk::Button {
+                        set_icon_name: "user-trash-symbolic",
+                        set_tooltip: &tr!("addon-profile-delete"),
+
+                        #[watch]
+                        set_sensitive: model.profiles_names.len() > 1,
+
+                        connect_clicked => GameAddonsManagerAppMsg::DeleteProfile
+                    }
                 },
 
                 #[local_ref]
@@ -91,20 +266,40 @@ impl SimpleAsyncComponent for GameAddonsManagerApp {
         }
     }
 
-    async fn init(parent: Self::Init, root: Self::Root, _sender: AsyncComponentSender<Self>) -> AsyncComponentParts<Self> {
+    async fn init(parent: Self::Init, root: Self::Root, sender: AsyncComponentSender<Self>) -> AsyncComponentParts<Self> {
         let model = Self {
             addons_groups_widgets: Vec::new(),
             addons_groups_page: adw::PreferencesPage::new(),
 
+            layers_order_widget: LayersOrderComponent::builder()
+                .launch(())
+                .forward(sender.input_sender(), |msg| {
+                    match msg {
+                        LayersOrderComponentOutput::SetLayersOrder(layers)
+                            => GameAddonsManagerAppMsg::SetLayersOrder(layers)
+                    }
+                }),
+
             game_info: CardInfo::default(),
+            game_path: PathBuf::new(),
+            addons_groups: Vec::new(),
+
+            enabled_addons: HashSet::default(),
+            installed_addons: HashSet::default(),
+            layers_order: Vec::new(),
 
-            enabled_addons: HashSet::default()
+            profiles: GameEditionProfiles::default(),
+            profiles_names: Vec::new(),
+            profiles_model: gtk::StringList::new(&[])
         };
 
         let addons_groups_page = &model.addons_groups_page;
 
         let widgets = view_output!();
 
+        // Layers order group is always shown first
+        addons_groups_page.add(model.layers_order_widget.widget());
+
         widgets.window.set_transient_for(Some(&parent));
 
         unsafe {
@@ -125,9 +320,23 @@ impl SimpleAsyncComponent for GameAddonsManagerApp {
                     .games.get_game_settings(game)
                     .unwrap();
 
-                self.enabled_addons = settings.addons
+                self.profiles = settings.addons
+                    .get(game_info.get_edition())
+                    .cloned()
+                    .unwrap_or_default();
+
+                self.enabled_addons = HashSet::from_iter(self.profiles.get_active().iter().cloned());
+
+                self.update_profiles_model();
+
+                self.layers_order = settings.layers_order
+                    .get(game_info.get_edition())
+                    .cloned()
+                    .unwrap_or_default();
+
+                self.game_path = settings.paths
                     .get(game_info.get_edition())
-                    .map(|addons| HashSet::from_iter(addons.clone()))
+                    .map(|paths| paths.game.clone())
                     .unwrap_or_default();
 
                 self.game_info = game_info.clone();
@@ -138,6 +347,8 @@ impl SimpleAsyncComponent for GameAddonsManagerApp {
 
                 self.addons_groups_widgets.clear();
 
+                self.addons_groups = addons.clone();
+
                 let mut installed_addons = HashSet::new();
 
                 for group in addons {
@@ -169,13 +380,23 @@ impl SimpleAsyncComponent for GameAddonsManagerApp {
                                     => GameAddonsManagerAppMsg::InstallAddon { addon, group },
 
                                 AddonsGroupComponentOutput::UninstallAddon { addon, group }
-                                    => GameAddonsManagerAppMsg::UninstallAddon { addon, group }
+                                    => GameAddonsManagerAppMsg::UninstallAddon { addon, group },
+
+                                AddonsGroupComponentOutput::VerifyAddon { addon, group }
+                                    => GameAddonsManagerAppMsg::VerifyAddon { addon, group }
                             }
                         });
 
                     self.addons_groups_page.add(group.widget());
                     self.addons_groups_widgets.push(group);
                 }
+
+                self.installed_addons = installed_addons;
+
+                // Addons could be installed or updated since the last time
+                self.layers_order_widget.emit(LayersOrderComponentInput::ClearCache);
+
+                self.update_layers();
             }
 
             GameAddonsManagerAppMsg::InstallAddon { addon, group } => {
@@ -194,21 +415,180 @@ impl SimpleAsyncComponent for GameAddonsManagerApp {
                 }).unwrap();
             }
 
+            GameAddonsManagerAppMsg::VerifyAddon { addon, group } => {
+                sender.output(MainAppMsg::AddVerifyAddonTask {
+                    game_info: self.game_info.clone(),
+                    addon,
+                    group,
+                    mode: VerifyMode::Full,
+                    dry_run: false
+                }).unwrap();
+            }
+
             GameAddonsManagerAppMsg::ToggleAddon { addon, enabled } => {
-                if enabled {
-                    self.enabled_addons.insert(addon);
-                }
+                let result = if enabled {
+                    check_addons::enable_addon(&self.addons_groups, &mut self.enabled_addons, addon)
+                } else {
+                    check_addons::disable_addon(&self.addons_groups, &mut self.enabled_addons, addon)
+                };
 
-                else {
-                    self.enabled_addons.remove(&addon);
+                // Dependencies and dependents could be toggled as well,
+                // or the addon itself if it can't be toggled
+                for group in &self.addons_groups_widgets {
+                    group.emit(AddonsGroupComponentInput::SetEnabledAddons(self.enabled_addons.clone()));
                 }
 
                 // FIXME move it to the window closing event
-                sender.output(MainAppMsg::SetEnabledAddons {
+                self.save_profiles(&sender);
+
+                self.update_layers();
+
+                match result {
+                    // Download enabled dependencies which are not installed yet
+                    Ok(toggled) if enabled => {
+                        for dependency in toggled {
+                            if self.installed_addons.contains(&dependency) {
+                                continue;
+                            }
+
+                            let group = self.addons_groups.iter()
+                                .find(|group| group.name == dependency.group);
+
+                            let addon = group.and_then(|group| {
+                                group.addons.iter().find(|addon| addon.name == dependency.name)
+                            });
+
+                            if let (Some(addon), Some(group)) = (addon, group) {
+                                sender.output(MainAppMsg::AddDownloadAddonTask {
+                                    game_info: self.game_info.clone(),
+                                    addon: addon.clone(),
+                                    group: group.clone()
+                                }).unwrap();
+                            }
+                        }
+                    }
+
+                    Ok(_) => (),
+
+                    Err(err) => {
+                        sender.output(MainAppMsg::ShowToast {
+                            title: if enabled {
+                                tr!("addon-enable-failed")
+                            } else {
+                                tr!("addon-disable-failed")
+                            },
+                            message: Some(err.to_string())
+                        }).unwrap();
+                    }
+                }
+            }
+
+            GameAddonsManagerAppMsg::SetLayersOrder(mut layers) => {
+                // Keep positions of the layers which are not enabled right now
+                let rest = self.layers_order.iter()
+                    .filter(|layer| !layers.contains(layer))
+                    .cloned()
+                    .collect::<Vec<_>>();
+
+                layers.extend(rest);
+
+                self.layers_order = layers;
+
+                sender.output(MainAppMsg::SetLayersOrder {
                     game: self.game_info.clone(),
-                    addons: self.enabled_addons.clone()
+                    layers: self.layers_order.clone()
                 }).unwrap();
             }
+
+            GameAddonsManagerAppMsg::SelectProfile(name) => {
+                if name == self.profiles.active || !self.profiles.profiles.contains_key(&name) {
+                    return;
+                }
+
+                self.profiles.active = name;
+
+                self.load_active_profile();
+                self.save_profiles(&sender);
+            }
+
+            GameAddonsManagerAppMsg::CreateProfile { duplicate } => {
+                let window = unsafe {
+                    WINDOW.as_ref().unwrap_unchecked()
+                };
+
+                let heading = if duplicate {
+                    tr!("addon-profile-duplicate")
+                } else {
+                    tr!("addon-profile-create")
+                };
+
+                let dialog = adw::MessageDialog::new(
+                    Some(window),
+                    Some(&heading),
+                    None
+                );
+
+                let entry = gtk::Entry::new();
+
+                entry.set_placeholder_text(Some(&tr!("addon-profile-name")));
+                entry.set_activates_default(true);
+
+                dialog.set_extra_child(Some(&entry));
+
+                dialog.add_response("close", &tr!("dialog-close"));
+                dialog.add_response("create", &heading);
+
+                dialog.set_response_appearance("create", adw::ResponseAppearance::Suggested);
+                dialog.set_default_response(Some("create"));
+
+                dialog.connect_response(Some("create"), move |_, _| {
+                    sender.input(GameAddonsManagerAppMsg::AddProfile {
+                        name: entry.text().trim().to_string(),
+                        duplicate
+                    });
+                });
+
+                dialog.present();
+            }
+
+            GameAddonsManagerAppMsg::AddProfile { name, duplicate } => {
+                if name.is_empty() || self.profiles.profiles.contains_key(&name) {
+                    sender.output(MainAppMsg::ShowToast {
+                        title: tr!("addon-profile-name-invalid", {
+                            "name" = name
+                        }),
+                        message: None
+                    }).unwrap();
+
+                    return;
+                }
+
+                let addons = if duplicate {
+                    self.profiles.get_active().to_vec()
+                } else {
+                    Vec::new()
+                };
+
+                self.profiles.profiles.insert(name.clone(), addons);
+                self.profiles.active = name;
+
+                self.update_profiles_model();
+                self.load_active_profile();
+                self.save_profiles(&sender);
+            }
+
+            GameAddonsManagerAppMsg::DeleteProfile => {
+                if self.profiles.profiles.len() < 2 {
+                    return;
+                }
+
+                self.profiles.profiles.remove(&self.profiles.active);
+                self.profiles.active = self.profiles.get_names()[0].clone();
+
+                self.update_profiles_model();
+                self.load_active_profile();
+                self.save_profiles(&sender);
+            }
         }
     }
 }
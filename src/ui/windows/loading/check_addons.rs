@@ -1,3 +1,5 @@
+use std::collections::HashSet;
+
 use crate::config;
 
 use crate::config::games::settings::edition_addons::GameEditionAddon;
@@ -12,7 +14,8 @@ use crate::games::integrations::standards::diff::{
 
 use crate::games::integrations::standards::addons::{
     Addon,
-    AddonsGroup
+    AddonsGroup,
+    AddonReference
 };
 
 use crate::ui::components::game_card::CardInfo;
@@ -24,6 +27,15 @@ pub struct AddonsListEntry {
     pub group: AddonsGroup
 }
 
+/// Addons which should be downloaded for the game edition
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct GameAddonsDownloads {
+    pub addons: Vec<AddonsListEntry>,
+
+    /// Enabled addons which were skipped because of conflicts
+    pub conflicts: Vec<String>
+}
+
 #[inline]
 pub fn is_addon_enabled(enabled_addons: &[GameEditionAddon], addon: &Addon, group: &AddonsGroup) -> bool {
     addon.required || enabled_addons.iter().any(|enabled_addon| {
@@ -31,6 +43,161 @@ pub fn is_addon_enabled(enabled_addons: &[GameEditionAddon], addon: &Addon, grou
     })
 }
 
+#[inline]
+fn get_reference_addon(reference: &AddonReference) -> GameEditionAddon {
+    GameEditionAddon {
+        group: reference.group.clone(),
+        name: reference.name.clone()
+    }
+}
+
+#[inline]
+fn find_addon<'a>(groups: &'a [AddonsGroup], addon: &GameEditionAddon) -> Option<&'a Addon> {
+    groups.iter()
+        .find(|group| group.name == addon.group)
+        .and_then(|group| group.addons.iter().find(|group_addon| group_addon.name == addon.name))
+}
+
+/// Add dependencies to the enabled addons list and check it for conflicts
+///
+/// Required addons are treated as enabled but are not included in the result
+pub fn resolve_enabled_addons(groups: &[AddonsGroup], enabled_addons: &[GameEditionAddon]) -> anyhow::Result<HashSet<GameEditionAddon>> {
+    let mut resolved = HashSet::new();
+
+    let mut queue = groups.iter()
+        .flat_map(|group| {
+            group.addons.iter()
+                .filter(|addon| addon.required)
+                .map(|addon| GameEditionAddon {
+                    group: group.name.clone(),
+                    name: addon.name.clone()
+                })
+        })
+        .chain(enabled_addons.iter().cloned())
+        .collect::<Vec<_>>();
+
+    while let Some(entry) = queue.pop() {
+        if resolved.contains(&entry) {
+            continue;
+        }
+
+        // Addons which are not provided by the game anymore are kept as they are
+        if let Some(addon) = find_addon(groups, &entry) {
+            for dependency in &addon.depends_on {
+                let dependency = get_reference_addon(dependency);
+
+                if find_addon(groups, &dependency).is_none() {
+                    anyhow::bail!("Addon {} depends on unknown addon {}/{}", addon.title, dependency.group, dependency.name);
+                }
+
+                queue.push(dependency);
+            }
+        }
+
+        resolved.insert(entry);
+    }
+
+    for entry in &resolved {
+        let Some(addon) = find_addon(groups, entry) else {
+            continue;
+        };
+
+        for conflict in &addon.conflicts_with {
+            let conflict = get_reference_addon(conflict);
+
+            if resolved.contains(&conflict) {
+                let conflict_title = find_addon(groups, &conflict)
+                    .map(|conflict| conflict.title.as_str())
+                    .unwrap_or(&conflict.name);
+
+                anyhow::bail!("Addon {} conflicts with {conflict_title}", addon.title);
+            }
+        }
+    }
+
+    resolved.retain(|entry| !find_addon(groups, entry).is_some_and(|addon| addon.required));
+
+    Ok(resolved)
+}
+
+/// Enable addon together with its dependencies
+///
+/// Return addons which were enabled as dependencies
+pub fn enable_addon(groups: &[AddonsGroup], enabled_addons: &mut HashSet<GameEditionAddon>, addon: GameEditionAddon) -> anyhow::Result<Vec<GameEditionAddon>> {
+    let mut candidates = enabled_addons.iter()
+        .cloned()
+        .collect::<Vec<_>>();
+
+    candidates.push(addon.clone());
+
+    let resolved = resolve_enabled_addons(groups, &candidates)?;
+
+    let dependencies = resolved.iter()
+        .filter(|entry| **entry != addon && !enabled_addons.contains(entry))
+        .cloned()
+        .collect();
+
+    *enabled_addons = resolved;
+
+    Ok(dependencies)
+}
+
+/// Resolve enabled addons one by one, skipping the conflicting ones
+///
+/// Return resolved addons and the reasons why other addons were skipped
+pub fn resolve_enabled_addons_lossy(groups: &[AddonsGroup], enabled_addons: &[GameEditionAddon]) -> anyhow::Result<(HashSet<GameEditionAddon>, Vec<String>)> {
+    let mut resolved = resolve_enabled_addons(groups, &[])?;
+    let mut conflicts = Vec::new();
+
+    for addon in enabled_addons {
+        if let Err(err) = enable_addon(groups, &mut resolved, addon.clone()) {
+            conflicts.push(err.to_string());
+        }
+    }
+
+    Ok((resolved, conflicts))
+}
+
+/// Disable addon together with all the enabled addons which depend on it
+///
+/// Return addons which were disabled as dependents
+pub fn disable_addon(groups: &[AddonsGroup], enabled_addons: &mut HashSet<GameEditionAddon>, addon: GameEditionAddon) -> anyhow::Result<Vec<GameEditionAddon>> {
+    let mut dependents = Vec::new();
+    let mut queue = vec![addon.clone()];
+
+    while let Some(dependency) = queue.pop() {
+        for group in groups {
+            for group_addon in &group.addons {
+                if !group_addon.depends_on.iter().any(|reference| reference.group == dependency.group && reference.name == dependency.name) {
+                    continue;
+                }
+
+                let entry = GameEditionAddon {
+                    group: group.name.clone(),
+                    name: group_addon.name.clone()
+                };
+
+                if group_addon.required {
+                    anyhow::bail!("Addon is needed by required addon {}", group_addon.title);
+                }
+
+                if enabled_addons.contains(&entry) && !dependents.contains(&entry) {
+                    dependents.push(entry.clone());
+                    queue.push(entry);
+                }
+            }
+        }
+    }
+
+    enabled_addons.remove(&addon);
+
+    for entry in &dependents {
+        enabled_addons.remove(entry);
+    }
+
+    Ok(dependents)
+}
+
 #[inline]
 fn get_addon_download(
     game_info: &CardInfo,
@@ -82,13 +249,23 @@ pub fn get_game_addons_downloads(
     game: &Game,
     edition: &str,
     enabled_addons: &[GameEditionAddon]
-) -> anyhow::Result<Vec<AddonsListEntry>> {
-    let mut result = Vec::new();
+) -> anyhow::Result<GameAddonsDownloads> {
+    let groups = game.driver.get_addons_list(edition)?;
+
+    // Make sure dependencies are downloaded and conflicting addons are not
+    let (enabled_addons, conflicts) = resolve_enabled_addons_lossy(&groups, enabled_addons)?;
 
-    for group in game.driver.get_addons_list(edition)? {
+    let enabled_addons = enabled_addons.into_iter().collect::<Vec<_>>();
+
+    let mut result = GameAddonsDownloads {
+        addons: Vec::new(),
+        conflicts
+    };
+
+    for group in &groups {
         for addon in &group.addons {
-            if let Some(addon) = get_addon_download(game_info, game, edition, enabled_addons, addon, &group)? {
-                result.push(addon);
+            if let Some(addon) = get_addon_download(game_info, game, edition, &enabled_addons, addon, group)? {
+                result.addons.push(addon);
             }
         }
     }
@@ -119,7 +296,7 @@ pub fn get_download(pool: &rusty_pool::ThreadPool) -> anyhow::Result<Vec<AddonsL
 
                 // Check if addons should be installed only if the game itself is installed
                 if installed {
-                    let enabled_addons = &settings.addons[&edition.name];
+                    let enabled_addons = settings.addons[&edition.name].get_active();
 
                     let game_info = CardInfo::Game {
                         name: game.manifest.game_name.clone(),
@@ -129,7 +306,13 @@ pub fn get_download(pool: &rusty_pool::ThreadPool) -> anyhow::Result<Vec<AddonsL
                         edition: edition.name.clone()
                     };
 
-                    addons.extend(get_game_addons_downloads(&game_info, game, &edition.name, enabled_addons)?);
+                    let downloads = get_game_addons_downloads(&game_info, game, &edition.name, enabled_addons)?;
+
+                    for conflict in downloads.conflicts {
+                        tracing::warn!("Skipped {} addon: {conflict}", game.manifest.game_name);
+                    }
+
+                    addons.extend(downloads.addons);
                 }
             }
 
@@ -2,9 +2,8 @@ use std::collections::HashMap;
 
 use serde_json::Value as Json;
 
-use anime_game_core::network::minreq;
-
 use crate::config;
+use crate::network;
 use crate::games::integrations::manifest::Manifest;
 
 struct IntegrationInfo {
@@ -21,7 +20,7 @@ pub fn update_integrations(pool: &rusty_pool::ThreadPool) -> anyhow::Result<()>
 
     for source in config.games.integrations.sources {
         tasks.push(pool.evaluate(move || -> anyhow::Result<HashMap<String, IntegrationInfo>> {
-            let response = minreq::get(format!("{source}/integrations.json"))
+            let response = network::get(format!("{source}/integrations.json"))
                 .send()?;
 
             let mut games = HashMap::new();
@@ -36,8 +35,8 @@ pub fn update_integrations(pool: &rusty_pool::ThreadPool) -> anyhow::Result<()>
 
                 for game in integrations {
                     if let Some(game) = game.as_str() {
-                        let bytes = minreq::get(format!("{source}/games/{game}/manifest.json"))
-                            .send()?.into_bytes();
+                        let bytes = network::get(format!("{source}/games/{game}/manifest.json"))
+                            .send()?.into_bytes()?;
 
                         let manifest = Manifest::from_json(&serde_json::from_slice(&bytes)?)?;
 
@@ -51,12 +50,12 @@ pub fn update_integrations(pool: &rusty_pool::ThreadPool) -> anyhow::Result<()>
             }
 
             else {
-                let response = minreq::get(format!("{source}/manifest.json"))
+                let response = network::get(format!("{source}/manifest.json"))
                     .send()?;
 
                 // HTTP OK
                 if (200..300).contains(&response.status_code) {
-                    let bytes = response.into_bytes();
+                    let bytes = response.into_bytes()?;
 
                     let manifest = Manifest::from_json(&serde_json::from_slice(&bytes)?)?;
 
@@ -109,8 +108,8 @@ pub fn update_integrations(pool: &rusty_pool::ThreadPool) -> anyhow::Result<()>
         }
 
         tasks.push(pool.evaluate(move || -> anyhow::Result<()> {
-            let script = minreq::get(format!("{}/{}", info.source, &info.manifest.script_path))
-                .send()?.into_bytes();
+            let script = network::get(format!("{}/{}", info.source, &info.manifest.script_path))
+                .send()?.into_bytes()?;
 
             std::fs::write(manifest_path, info.manifest_body)?;
             std::fs::write(script_path, script)?;
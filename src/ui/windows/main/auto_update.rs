@@ -0,0 +1,98 @@
+use crate::games;
+use crate::config;
+
+use crate::config::general::prelude::UpdatePolicy;
+
+use crate::games::integrations::standards::diff::{
+    Diff,
+    DiffStatus
+};
+
+use crate::ui::components::game_card::CardInfo;
+
+use crate::ui::windows::loading::check_addons::{
+    AddonsListEntry,
+    get_game_addons_downloads
+};
+
+/// Result of the background updates check
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct UpdatesCheck {
+    /// Installed games which have new versions
+    pub games: Vec<CardInfo>,
+
+    /// Enabled addons of the up to date games
+    /// which are outdated or not installed
+    pub addons: Vec<AddonsListEntry>
+}
+
+/// Get updates policy of the game, or the global one if it doesn't have its own
+pub fn get_policy(game_info: &CardInfo, config: &config::Config) -> UpdatePolicy {
+    games::get(game_info.get_name()).ok().flatten()
+        .and_then(|game| config.games.get_game_settings(game).ok())
+        .and_then(|settings| settings.update_policy)
+        .unwrap_or(config.general.updates.policy)
+}
+
+/// Check given installed or outdated games and their addons for updates
+///
+/// Meant to be run outside of the main thread.
+/// Games which failed to be checked are skipped
+pub fn check_updates(games: Vec<CardInfo>) -> UpdatesCheck {
+    let config = config::get();
+
+    let mut result = UpdatesCheck::default();
+
+    for game_info in games {
+        let game = unsafe {
+            games::get_unsafe(game_info.get_name())
+        };
+
+        let settings = match config.games.get_game_settings(game) {
+            Ok(settings) => settings,
+
+            Err(err) => {
+                tracing::warn!("Failed to get {} settings: {err}", game_info.get_name());
+
+                continue;
+            }
+        };
+
+        let Some(paths) = settings.paths.get(game_info.get_edition()) else {
+            continue;
+        };
+
+        let game_path = paths.game.to_string_lossy();
+
+        match game.driver.get_game_diff(&game_path, game_info.get_edition()) {
+            // Addons are updated together with the game
+            Ok(Some(Diff { status: DiffStatus::Outdated, .. })) => result.games.push(game_info),
+
+            Ok(Some(Diff { status: DiffStatus::Latest, .. })) => {
+                let enabled_addons = settings.addons.get(game_info.get_edition())
+                    .map(|profiles| profiles.get_active().to_vec())
+                    .unwrap_or_default();
+
+                match get_game_addons_downloads(&game_info, game, game_info.get_edition(), &enabled_addons) {
+                    Ok(downloads) => {
+                        // Conflicts are reported when the user downloads the game
+                        for conflict in downloads.conflicts {
+                            tracing::warn!("Skipped {} addon: {conflict}", game_info.get_name());
+                        }
+
+                        result.addons.extend(downloads.addons);
+                    }
+
+                    Err(err) => tracing::warn!("Failed to check {} addons updates: {err}", game_info.get_name())
+                }
+            }
+
+            // TODO: handle "unavailable" status
+            Ok(_) => (),
+
+            Err(err) => tracing::warn!("Failed to check {} updates: {err}", game_info.get_name())
+        }
+    }
+
+    result
+}
@@ -0,0 +1,244 @@
+use std::cell::Cell;
+
+use adw::prelude::*;
+
+use crate::tr;
+
+use crate::games;
+use crate::config;
+
+use crate::games::integrations::standards::addons::Addon;
+
+use crate::ui::components::game_card::CardInfo;
+
+use crate::ui::components::tasks_queue::download_diff_task::DownloadDiffQueuedTask;
+
+use crate::ui::components::tasks_queue::verify_integrity_task::{
+    VerifyIntegrityQueuedTask,
+    VerifyMode
+};
+
+use crate::ui::windows::loading::check_addons::get_game_addons_downloads;
+
+use crate::utils::pretty_bytes;
+
+use super::download_game_task::{
+    self,
+    DownloadGameResult
+};
+
+use super::download_addon_task;
+use super::verify_game_task;
+
+use super::{
+    MainAppMsg,
+    WINDOW as MAIN_WINDOW
+};
+
+/// Tasks of the "update all" action
+#[derive(Debug, Default)]
+pub struct UpdateAllTasks {
+    pub games: Vec<(CardInfo, DownloadGameResult)>,
+    pub addons: Vec<(Addon, Box<DownloadDiffQueuedTask>)>
+}
+
+impl UpdateAllTasks {
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.games.is_empty() && self.addons.is_empty()
+    }
+
+    #[inline]
+    pub fn tasks(&self) -> impl Iterator<Item = &DownloadDiffQueuedTask> {
+        self.games.iter()
+            .map(|(_, result)| result.game_task.as_ref())
+            .chain(self.addons.iter().map(|(_, task)| task.as_ref()))
+    }
+
+    #[inline]
+    /// Get size of the files which will be downloaded by all the tasks
+    pub fn download_size(&self) -> u64 {
+        self.tasks()
+            .map(|task| task.diff_info.size())
+            .sum()
+    }
+}
+
+/// Tasks of the "verify all" action
+#[derive(Debug, Default)]
+pub struct VerifyAllTasks {
+    pub games: Vec<Box<VerifyIntegrityQueuedTask>>
+}
+
+impl VerifyAllTasks {
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.games.is_empty()
+    }
+
+    #[inline]
+    /// Get size of the files which will be verified by all the tasks
+    pub fn files_size(&self) -> u64 {
+        self.games.iter()
+            .flat_map(|task| task.integrity_info.iter())
+            .map(|info| info.file.size)
+            .sum()
+    }
+}
+
+/// Get tasks to update all the outdated games
+/// and all the outdated or missing enabled addons of the installed games
+///
+/// Errors of the games which can't be updated are returned
+/// as toasts without stopping the rest of the games
+pub fn get_update_all_tasks<'a>(
+    outdated_games: impl IntoIterator<Item = &'a CardInfo>,
+    installed_games: impl IntoIterator<Item = &'a CardInfo>,
+    config: &config::Config
+) -> (UpdateAllTasks, Vec<MainAppMsg>) {
+    let mut tasks = UpdateAllTasks::default();
+    let mut errors = Vec::new();
+
+    let mut addons = Vec::new();
+
+    for game_info in outdated_games {
+        match download_game_task::get_download_game_task(game_info, config) {
+            Ok(mut result) => {
+                // Addons are queued separately to count their download size
+                addons.append(&mut result.download_addons);
+
+                tasks.games.push((game_info.clone(), result));
+            }
+
+            Err(err) => errors.push(*err)
+        }
+    }
+
+    for game_info in installed_games {
+        let game = unsafe {
+            games::get_unsafe(game_info.get_name())
+        };
+
+        let enabled_addons = config.games.get_game_settings(game)
+            .map(|settings| settings.addons[game_info.get_edition()].get_active().to_vec())
+            .and_then(|enabled_addons| get_game_addons_downloads(game_info, game, game_info.get_edition(), &enabled_addons));
+
+        match enabled_addons {
+            Ok(mut downloads) => {
+                addons.append(&mut downloads.addons);
+
+                if !downloads.conflicts.is_empty() {
+                    errors.push(MainAppMsg::ShowToast {
+                        title: tr!("game-addons-conflicts", {
+                            "game-title" = game_info.get_title()
+                        }),
+                        message: Some(downloads.conflicts.join("\n"))
+                    });
+                }
+            }
+
+            Err(err) => errors.push(MainAppMsg::ShowToast {
+                title: tr!("game-get-addons-failed", {
+                    "game-title" = game_info.get_title()
+                }),
+                message: Some(err.to_string())
+            })
+        }
+    }
+
+    for entry in addons {
+        match download_addon_task::get_download_addon_task(&entry.game_info, &entry.addon, &entry.group) {
+            Ok(task) => tasks.addons.push((entry.addon, task)),
+            Err(err) => errors.push(*err)
+        }
+    }
+
+    (tasks, errors)
+}
+
+/// Get tasks to verify all the installed games
+///
+/// Outdated games are skipped because their files
+/// would be compared with the latest game version
+pub fn get_verify_all_tasks<'a>(
+    installed_games: impl IntoIterator<Item = &'a CardInfo>,
+    config: &config::Config
+) -> (VerifyAllTasks, Vec<MainAppMsg>) {
+    let mut tasks = VerifyAllTasks::default();
+    let mut errors = Vec::new();
+
+    for game_info in installed_games {
+        match verify_game_task::get_verify_game_task(game_info, VerifyMode::Quick, false, config) {
+            Ok(task) => tasks.games.push(task),
+            Err(err) => errors.push(*err)
+        }
+    }
+
+    (tasks, errors)
+}
+
+/// Show summary dialog of the batch action
+///
+/// `on_confirm` is called with the given tasks if the user decided to queue them
+fn show_dialog<T: 'static>(heading: String, lines: Vec<String>, tasks: T, on_confirm: impl Fn(T) + 'static) {
+    let window = unsafe {
+        MAIN_WINDOW.as_ref().unwrap_unchecked()
+    };
+
+    let dialog = adw::MessageDialog::new(
+        Some(window),
+        Some(&heading),
+        Some(&lines.join("\n"))
+    );
+
+    dialog.add_response("close", &tr!("dialog-close"));
+    dialog.add_response("queue", &tr!("batch-queue"));
+
+    dialog.set_response_appearance("queue", adw::ResponseAppearance::Suggested);
+
+    let tasks = Cell::new(Some(tasks));
+
+    dialog.connect_response(Some("queue"), move |_, _| {
+        if let Some(tasks) = tasks.take() {
+            on_confirm(tasks);
+        }
+    });
+
+    dialog.present();
+}
+
+/// Show summary of the "update all" tasks
+pub fn show_update_all_dialog(tasks: UpdateAllTasks, on_confirm: impl Fn(UpdateAllTasks) + 'static) {
+    let mut lines = tasks.games.iter()
+        .map(|(game_info, result)| format!("{} — {}", game_info.get_title(), pretty_bytes(result.game_task.diff_info.size())))
+        .chain(tasks.addons.iter().map(|(addon, task)| {
+            format!("{} ({}) — {}", task.card_info.get_title(), addon.title, pretty_bytes(task.diff_info.size()))
+        }))
+        .collect::<Vec<_>>();
+
+    lines.push(String::new());
+
+    lines.push(tr!("batch-update-summary", {
+        "games" = tasks.games.len(),
+        "addons" = tasks.addons.len(),
+        "size" = pretty_bytes(tasks.download_size())
+    }));
+
+    show_dialog(tr!("batch-update-all"), lines, tasks, on_confirm);
+}
+
+/// Show summary of the "verify all" tasks
+pub fn show_verify_all_dialog(tasks: VerifyAllTasks, on_confirm: impl Fn(VerifyAllTasks) + 'static) {
+    let mut lines = tasks.games.iter()
+        .map(|task| task.card_info.get_title().to_string())
+        .collect::<Vec<_>>();
+
+    lines.push(String::new());
+
+    lines.push(tr!("batch-verify-summary", {
+        "games" = tasks.games.len(),
+        "size" = pretty_bytes(tasks.files_size())
+    }));
+
+    show_dialog(tr!("batch-verify-all"), lines, tasks, on_confirm);
+}
@@ -0,0 +1,226 @@
+use std::path::{Path, PathBuf};
+use std::os::unix::fs::MetadataExt;
+use std::os::unix::ffi::OsStrExt;
+use std::ffi::CString;
+
+use adw::prelude::*;
+
+use crate::tr;
+
+use crate::config;
+use crate::extract;
+
+use crate::games::integrations::standards::diff::DiffInfo;
+
+use crate::ui::components::tasks_queue::download_diff_task::DownloadDiffQueuedTask;
+
+use crate::ui::windows::main::WINDOW as MAIN_WINDOW;
+
+use crate::utils::pretty_bytes;
+
+/// Warn user if less than this amount of free space will remain after the installation
+const LOW_SPACE_THRESHOLD: u64 = 2 * 1024 * 1024 * 1024;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FilesystemSpace {
+    /// Path within the filesystem
+    pub path: PathBuf,
+
+    pub required: u64,
+    pub available: u64
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DiskSpaceCheck {
+    Enough,
+    Low(Vec<FilesystemSpace>),
+    NotEnough(Vec<FilesystemSpace>)
+}
+
+/// Get space needed to download and install the diff
+///
+/// Returns `(transition, installation)` where `transition` is
+/// the space needed in the transitions folder and `installation`
+/// is the space needed in the installation folder
+pub fn get_required_space(diff: &DiffInfo, stream_extraction: bool) -> (u64, u64) {
+    match diff {
+        // Streamed archives are never stored on the disk
+        DiffInfo::Archive { size, unpacked_size, uri } if stream_extraction && extract::is_streamable(uri.file_name()) => {
+            let unpacked_size = unpacked_size.unwrap_or(*size);
+
+            (unpacked_size, unpacked_size)
+        }
+
+        // Archives are downloaded and extracted in the transition folder
+        DiffInfo::Archive { size, unpacked_size, .. } |
+        DiffInfo::Segments { size, unpacked_size, .. } => {
+            let unpacked_size = unpacked_size.unwrap_or(*size);
+
+            (size + unpacked_size, unpacked_size)
+        }
+
+        DiffInfo::Files { size, .. } |
+        DiffInfo::Patch { size, .. } => (*size, *size)
+    }
+}
+
+/// Get `(device, available_space)` of the filesystem which holds given path
+///
+/// If the path doesn't exist yet then its closest existing parent is used
+pub fn get_available_space(path: impl AsRef<Path>) -> anyhow::Result<(u64, u64)> {
+    let mut path = path.as_ref();
+
+    while !path.exists() {
+        path = path.parent()
+            .ok_or_else(|| anyhow::anyhow!("Failed to find existing parent of the path: {:?}", path))?;
+    }
+
+    let device = path.metadata()?.dev();
+
+    let c_path = CString::new(path.as_os_str().as_bytes())?;
+
+    let mut stat = std::mem::MaybeUninit::<libc::statvfs>::uninit();
+
+    let result = unsafe {
+        libc::statvfs(c_path.as_ptr(), stat.as_mut_ptr())
+    };
+
+    if result != 0 {
+        return Err(std::io::Error::last_os_error().into());
+    }
+
+    let stat = unsafe {
+        stat.assume_init()
+    };
+
+    Ok((device, stat.f_bavail as u64 * stat.f_frsize as u64))
+}
+
+fn add_required_space(filesystems: &mut Vec<(u64, FilesystemSpace)>, device: u64, path: &Path, required: u64, available: u64) {
+    match filesystems.iter_mut().find(|(fs_device, _)| *fs_device == device) {
+        Some((_, fs)) => fs.required += required,
+
+        None => filesystems.push((device, FilesystemSpace {
+            path: path.to_path_buf(),
+            required,
+            available
+        }))
+    }
+}
+
+/// Check if there's enough free space to download and install the task
+#[inline]
+pub fn check_task(task: &DownloadDiffQueuedTask) -> anyhow::Result<DiskSpaceCheck> {
+    check_tasks([task])
+}
+
+/// Check if there's enough free space to download and install all the tasks
+///
+/// Required space of the tasks is summed up since they can be run at the same time
+pub fn check_tasks<'a>(tasks: impl IntoIterator<Item = &'a DownloadDiffQueuedTask>) -> anyhow::Result<DiskSpaceCheck> {
+    let config = config::get();
+
+    let (transitions_device, transitions_available) = get_available_space(&config.general.transitions.path)?;
+
+    // Required space by the filesystem devices
+    let mut filesystems: Vec<(u64, FilesystemSpace)> = Vec::new();
+
+    for task in tasks {
+        let (transition_space, installation_space) = get_required_space(&task.diff_info, config.general.stream_extraction);
+
+        let (installation_device, installation_available) = get_available_space(&task.download_path)?;
+
+        // Files are moved from the transition folder to the installation folder
+        // so if they're on the same filesystem then no additional space is needed
+        if transitions_device == installation_device {
+            add_required_space(&mut filesystems, transitions_device, &task.download_path, transition_space, transitions_available);
+        } else {
+            add_required_space(&mut filesystems, transitions_device, &config.general.transitions.path, transition_space, transitions_available);
+            add_required_space(&mut filesystems, installation_device, &task.download_path, installation_space, installation_available);
+        }
+    }
+
+    let filesystems = filesystems.into_iter()
+        .map(|(_, fs)| fs)
+        .collect::<Vec<_>>();
+
+    let not_enough = filesystems.iter()
+        .filter(|fs| fs.available < fs.required)
+        .cloned()
+        .collect::<Vec<_>>();
+
+    if !not_enough.is_empty() {
+        return Ok(DiskSpaceCheck::NotEnough(not_enough));
+    }
+
+    let low = filesystems.into_iter()
+        .filter(|fs| fs.available - fs.required < LOW_SPACE_THRESHOLD)
+        .collect::<Vec<_>>();
+
+    if !low.is_empty() {
+        return Ok(DiskSpaceCheck::Low(low));
+    }
+
+    Ok(DiskSpaceCheck::Enough)
+}
+
+/// Show dialog about insufficient disk space
+///
+/// `on_continue` is called if the user decided to continue
+/// the installation when there's enough space but it's low
+pub fn show_dialog(check: DiskSpaceCheck, on_continue: impl Fn() + 'static) {
+    let window = unsafe {
+        MAIN_WINDOW.as_ref().unwrap_unchecked()
+    };
+
+    let (heading, message) = match &check {
+        DiskSpaceCheck::Enough => return on_continue(),
+
+        DiskSpaceCheck::Low(filesystems) => (
+            tr!("disk-space-low"),
+            filesystems.iter()
+                .map(|fs| tr!("disk-space-low-message", {
+                    "path" = fs.path.to_string_lossy().to_string(),
+                    "remaining" = pretty_bytes(fs.available - fs.required)
+                }))
+                .collect::<Vec<_>>()
+        ),
+
+        DiskSpaceCheck::NotEnough(filesystems) => (
+            tr!("disk-space-not-enough"),
+            filesystems.iter()
+                .map(|fs| tr!("disk-space-not-enough-message", {
+                    "path" = fs.path.to_string_lossy().to_string(),
+                    "required" = pretty_bytes(fs.required),
+                    "available" = pretty_bytes(fs.available)
+                }))
+                .collect::<Vec<_>>()
+        )
+    };
+
+    let dialog = adw::MessageDialog::new(
+        Some(window),
+        Some(&heading),
+        Some(&message.join("\n\n"))
+    );
+
+    if let DiskSpaceCheck::Low(_) = check {
+        dialog.add_response("stop", &tr!("dialog-stop"));
+        dialog.add_response("continue", &tr!("dialog-continue"));
+
+        dialog.set_response_appearance("stop", adw::ResponseAppearance::Suggested);
+        dialog.set_response_appearance("continue", adw::ResponseAppearance::Destructive);
+
+        dialog.connect_response(None, move |_, id| {
+            if id == "continue" {
+                on_continue();
+            }
+        });
+    }
+
+    else {
+        dialog.add_response("close", &tr!("dialog-close"));
+    }
+
+    dialog.present();
+}
@@ -83,19 +83,30 @@ pub fn get_download_addon_task(game_info: &CardInfo, addon: &Addon, group: &Addo
         games::get_unsafe(game_info.get_name())
     };
 
+    let diff_info = get_diff_or_download(
+        game,
+        &group.name,
+        &addon.name,
+        &download_path.to_string_lossy(),
+        game_info.get_edition()
+    )?;
+
+    crate::patch::check_diff(&diff_info)
+        .map_err(|err| Box::new(MainAppMsg::ShowToast {
+            title: tr!("addon-patches-unsupported", {
+                "game-title" = game_info.get_title().to_owned()
+            }),
+            message: Some(err.to_string())
+        }))?;
+
     Ok(Box::new(DownloadDiffQueuedTask {
         card_info: game_info.clone(),
-        diff_info: get_diff_or_download(
-            game,
-            &group.name,
-            &addon.name,
-            &download_path.to_string_lossy(),
-            game_info.get_edition()
-        )?,
+        diff_info,
         diff_origin: DiffOrigin::Addon {
             group_name: group.name.clone(),
             addon_name: addon.name.clone()
         },
-        download_path
+        download_path,
+        staging_path: None
     }))
 }
@@ -1,6 +1,7 @@
 use crate::tr;
 
 use crate::games;
+use crate::games::predownload;
 use crate::config;
 
 use crate::config::games::prelude::*;
@@ -17,6 +18,7 @@ use crate::games::integrations::standards::diff::DiffInfo;
 
 use crate::ui::windows::loading::check_addons::{
     AddonsListEntry,
+    GameAddonsDownloads,
     get_game_addons_downloads
 };
 
@@ -36,7 +38,7 @@ fn is_installed(game: &Game, game_path: &str, edition: &str) -> HeapResult<bool>
 }
 
 #[inline]
-fn get_diff(game: &Game, edition: impl AsRef<str>, game_path: impl AsRef<str>) -> HeapResult<DiffInfo> {
+fn get_diff(game: &Game, edition: impl AsRef<str>, game_path: impl AsRef<str>) -> HeapResult<(String, DiffInfo)> {
     game.driver.get_game_diff(game_path.as_ref(), edition.as_ref())
         .map_err(|err| MainAppMsg::ShowToast {
             title: tr!("game-find-diff-failed", {
@@ -44,7 +46,7 @@ fn get_diff(game: &Game, edition: impl AsRef<str>, game_path: impl AsRef<str>) -
             }),
             message: Some(err.to_string())
         })?
-        .and_then(|diff| diff.diff)
+        .and_then(|diff| Some((diff.latest_version, diff.diff?)))
         .ok_or_else(|| Box::new(MainAppMsg::ShowToast {
             title: tr!("game-not-installed", {
                 "game-title" = game.manifest.game_title.clone()
@@ -54,7 +56,7 @@ fn get_diff(game: &Game, edition: impl AsRef<str>, game_path: impl AsRef<str>) -
 }
 
 #[inline]
-fn get_download(game: &Game, edition: &str) -> HeapResult<DiffInfo> {
+fn get_download(game: &Game, edition: &str) -> HeapResult<(String, DiffInfo)> {
     game.driver.get_game_download(edition.as_ref())
         .map_err(|err| Box::new(MainAppMsg::ShowToast {
             title: tr!("game-find-download-failed", {
@@ -62,16 +64,27 @@ fn get_download(game: &Game, edition: &str) -> HeapResult<DiffInfo> {
             }),
             message: Some(err.to_string())
         }))
-        .map(|download| download.download)
+        .map(|download| (download.version, download.download))
 }
 
 #[inline]
-fn get_diff_or_download(game: &Game, game_path: &str, edition: &str) -> HeapResult<DiffInfo> {
+fn get_diff_or_download(game: &Game, game_path: &str, edition: &str) -> HeapResult<(String, DiffInfo)> {
     is_installed(game, game_path, edition)?
         .then(|| get_diff(game, edition, game_path))
         .unwrap_or_else(|| get_download(game, edition))
 }
 
+#[inline]
+fn check_diff(game: &Game, diff_info: &DiffInfo) -> HeapResult<()> {
+    crate::patch::check_diff(diff_info)
+        .map_err(|err| Box::new(MainAppMsg::ShowToast {
+            title: tr!("game-patches-unsupported", {
+                "game-title" = game.manifest.game_title.clone()
+            }),
+            message: Some(err.to_string())
+        }))
+}
+
 #[inline]
 fn get_settings(game: &Game, config: &config::Config) -> HeapResult<GameSettings> {
     config.games.get_game_settings(game)
@@ -84,7 +97,7 @@ fn get_settings(game: &Game, config: &config::Config) -> HeapResult<GameSettings
 }
 
 #[inline]
-fn get_addons(game: &Game, game_info: &CardInfo, edition: &str, enabled_addons: &[GameEditionAddon]) -> HeapResult<Vec<AddonsListEntry>> {
+fn get_addons(game: &Game, game_info: &CardInfo, edition: &str, enabled_addons: &[GameEditionAddon]) -> HeapResult<GameAddonsDownloads> {
     get_game_addons_downloads(game_info, game, edition, enabled_addons)
         .map_err(|err| Box::new(MainAppMsg::ShowToast {
             title: tr!("game-get-addons-failed", {
@@ -94,9 +107,13 @@ fn get_addons(game: &Game, game_info: &CardInfo, edition: &str, enabled_addons:
         }))
 }
 
+#[derive(Debug)]
 pub struct DownloadGameResult {
     pub game_task: Box<DownloadDiffQueuedTask>,
-    pub download_addons: Vec<AddonsListEntry>
+    pub download_addons: Vec<AddonsListEntry>,
+
+    /// Enabled addons which were skipped because of conflicts
+    pub addons_conflicts: Vec<String>
 }
 
 #[inline]
@@ -110,21 +127,35 @@ pub fn get_download_game_task(game_info: &CardInfo, config: &config::Config) ->
     // Game installation path
     let game_path = &settings.paths[game_info.get_edition()].game;
 
-    // Enabled game addons
-    let enabled_addons = &settings.addons[game_info.get_edition()];
+    let (version, diff_info) = get_diff_or_download(
+        game,
+        &game_path.to_string_lossy(),
+        game_info.get_edition()
+    )?;
+
+    check_diff(game, &diff_info)?;
+
+    // Folder with pre-downloaded files of this version
+    let staging_path = predownload::get_staging_path(
+        &settings.paths[game_info.get_edition()].predownload,
+        version
+    );
+
+    // Enabled game addons of the active profile
+    let enabled_addons = settings.addons[game_info.get_edition()].get_active();
+
+    let addons = get_addons(game, game_info, game_info.get_edition(), enabled_addons)?;
 
     Ok(DownloadGameResult {
         game_task: Box::new(DownloadDiffQueuedTask {
             card_info: game_info.clone(),
             download_path: game_path.clone(),
-            diff_info: get_diff_or_download(
-                game,
-                &game_path.to_string_lossy(),
-                game_info.get_edition()
-            )?,
+            staging_path: Some(staging_path),
+            diff_info,
             diff_origin: DiffOrigin::Game
         }),
 
-        download_addons: get_addons(game, game_info, game_info.get_edition(), enabled_addons)?
+        download_addons: addons.addons,
+        addons_conflicts: addons.conflicts
     })
 }
@@ -10,6 +10,8 @@ use crate::tr;
 use crate::games;
 use crate::config;
 
+use crate::games::layers;
+
 use crate::config::games::settings::prelude::*;
 use crate::games::integrations::standards::prelude::*;
 
@@ -69,13 +71,17 @@ pub fn addon_unavailable(addon_title: impl AsRef<str>, group_title: impl AsRef<s
 }
 
 #[inline]
-pub fn prepare_folders(game: &Game, info: &CardInfo, paths: &GameEditionPaths, enabled_addons: &[GameEditionAddon]) -> anyhow::Result<Option<(PathBuf, PathBuf)>> {
+pub fn prepare_folders(
+    game: &Game,
+    info: &CardInfo,
+    paths: &GameEditionPaths,
+    enabled_addons: &[GameEditionAddon],
+    layers_order: &[GameEditionAddon]
+) -> anyhow::Result<Option<(PathBuf, PathBuf)>> {
     // TODO: move files of disabled addons
 
-    // Init game merge tree filesystem
-    let mut tree = MergeTree::create(&paths.game)?;
-
-    let mut has_merged_layers = false;
+    // Layers which should be merged to the game folder
+    let mut layers = Vec::new();
 
     // Go through game addons list
     for group in game.driver.get_addons_list(info.get_edition())? {
@@ -91,9 +97,10 @@ pub fn prepare_folders(game: &Game, info: &CardInfo, paths: &GameEditionPaths, e
                 if let Some(Diff { status: DiffStatus::Latest, .. }) = diff {
                     // Merge it to the game folder if its type is "layer"
                     if addon.r#type == AddonType::Layer {
-                        tree.add_layer(&addon_path)?;
-
-                        has_merged_layers = true;
+                        layers.push((GameEditionAddon {
+                            group: group.name.clone(),
+                            name: addon.name.clone()
+                        }, addon_path));
                     }
 
                     continue;
@@ -111,9 +118,10 @@ pub fn prepare_folders(game: &Game, info: &CardInfo, paths: &GameEditionPaths, e
                     "continue" => {
                         // Merge it to the game folder if its type is "layer"
                         if addon.r#type == AddonType::Layer {
-                            tree.add_layer(&addon_path)?;
-
-                            has_merged_layers = true;
+                            layers.push((GameEditionAddon {
+                                group: group.name.clone(),
+                                name: addon.name.clone()
+                            }, addon_path));
                         }
                     }
 
@@ -123,11 +131,45 @@ pub fn prepare_folders(game: &Game, info: &CardInfo, paths: &GameEditionPaths, e
         }
     }
 
+    let layers = layers::sort_layers(layers_order, layers);
+
+    // Report files replaced by the layers
+    // Layers scanning is slow so it's done only for debugging
+    if tracing::enabled!(tracing::Level::DEBUG) {
+        match layers::get_overrides(&paths.game, &layers) {
+            Ok(overrides) => {
+                for entry in overrides {
+                    match entry.overridden {
+                        Some(overridden) => tracing::debug!(
+                            "{:?} of the {}/{} layer overrides {}/{} layer",
+                            entry.path, entry.layer.group, entry.layer.name, overridden.group, overridden.name
+                        ),
+
+                        None => tracing::debug!(
+                            "{:?} of the {}/{} layer overrides game file",
+                            entry.path, entry.layer.group, entry.layer.name
+                        )
+                    }
+                }
+            }
+
+            Err(err) => tracing::warn!("Failed to find layers overrides: {err}")
+        }
+    }
+
     let mut game_path = paths.game.clone();
     let mut addons_path = paths.addons.clone();
 
     // Create deployment folder it there's layered addons
-    if has_merged_layers {
+    if !layers.is_empty() {
+        // Init game merge tree filesystem
+        let mut tree = MergeTree::create(&paths.game)?;
+
+        // Layers are added in their load order so the last ones take priority
+        for (_, layer_path) in &layers {
+            tree.add_layer(layer_path)?;
+        }
+
         // Prepare deployment folder
         if paths.deployment.exists() {
             std::fs::remove_dir_all(&paths.deployment)?;
@@ -216,13 +258,19 @@ pub fn launch_game(info: &CardInfo) -> anyhow::Result<()> {
         anyhow::bail!("Unable to find {} paths", info.get_title());
     };
 
-    // Get game addons
-    let Some(enabled_addons) = settings.addons.get(info.get_edition()) else {
-        anyhow::bail!("Unable to find {} enabled addons", info.get_title());
+    // Get game addons of the active profile
+    let Some(profiles) = settings.addons.get(info.get_edition()) else {
+        anyhow::bail!("Unable to find {} addons profiles", info.get_title());
     };
 
+    let enabled_addons = profiles.get_active();
+
+    let layers_order = settings.layers_order.get(info.get_edition())
+        .map(Vec::as_slice)
+        .unwrap_or_default();
+
     // Prepare game and addons folders
-    let Some((game_path, addons_path)) = prepare_folders(game, info, paths, enabled_addons)? else {
+    let Some((game_path, addons_path)) = prepare_folders(game, info, paths, enabled_addons, layers_order)? else {
         return Ok(())
     };
 
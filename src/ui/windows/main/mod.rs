@@ -2,7 +2,9 @@
 #![allow(deprecated)]
 
 use std::path::PathBuf;
-use std::collections::{HashMap, HashSet};
+use std::collections::HashMap;
+use std::cell::Cell;
+use std::time::{Instant, Duration};
 
 use relm4::prelude::*;
 use relm4::factory::*;
@@ -15,13 +17,18 @@ use crate::tr;
 
 use crate::config;
 use crate::games;
+use crate::utils::pretty_bytes;
+use crate::games::predownload;
 
 use crate::components::wine::*;
 use crate::components::dxvk::*;
 
 use crate::config::games::settings::edition_addons::GameEditionAddon;
+use crate::config::games::settings::edition_profiles::GameEditionProfiles;
+use crate::config::general::prelude::UpdatePolicy;
 
 use crate::games::metadata::LauncherMetadata;
+use crate::games::integrations::standards::diff::DiffStatus;
 use crate::games::integrations::standards::addons::{
     Addon,
     AddonsGroup
@@ -29,6 +36,11 @@ use crate::games::integrations::standards::addons::{
 
 use crate::ui::windows::preferences::PreferencesApp;
 
+use crate::ui::windows::tasks_history::{
+    TasksHistoryApp,
+    TasksHistoryAppMsg
+};
+
 use crate::ui::windows::about::{
     AboutDialog,
     AboutDialogMsg
@@ -56,12 +68,26 @@ use crate::ui::components::game_details::{
 };
 
 use crate::ui::components::tasks_queue::{
+    self as tasks_queue,
     TasksQueueComponent,
     TasksQueueComponentInput,
     TasksQueueComponentOutput,
+    TaskDescriptor,
+
+    download_diff_task::{
+        DownloadDiffQueuedTask,
+        DiffOrigin
+    },
 
     apply_dxvk_task::ApplyDxvkQueuedTask,
-    create_prefix_task::CreatePrefixQueuedTask
+    create_prefix_task::CreatePrefixQueuedTask,
+
+    verify_integrity_task::{
+        VerifyIntegrityQueuedTask,
+        VerifyMode,
+        VerifyReport,
+        FileProblem
+    }
 };
 
 use crate::LAUNCHER_FOLDER;
@@ -74,10 +100,25 @@ pub mod download_game_task;
 pub mod download_addon_task;
 pub mod uninstall_addon_task;
 pub mod verify_game_task;
+pub mod verify_addon_task;
+pub mod predownload_game_task;
+pub mod disk_space;
+pub mod batch_tasks;
+pub mod auto_update;
+pub mod restore_tasks;
+
+use download_game_task::DownloadGameResult;
+use disk_space::DiskSpaceCheck;
+use batch_tasks::{UpdateAllTasks, VerifyAllTasks};
+use auto_update::UpdatesCheck;
+
+/// Time between checks if the background updates check should be run
+const UPDATES_TIMER_INTERVAL: u32 = 60;
 
 pub static mut WINDOW: Option<adw::Window> = None;
 pub static mut PREFERENCES_APP: Option<AsyncController<PreferencesApp>> = None;
 pub static mut GAME_ADDONS_MANAGER_APP: Option<AsyncController<GameAddonsManagerApp>> = None;
+pub static mut TASKS_HISTORY_APP: Option<AsyncController<TasksHistoryApp>> = None;
 pub static mut ABOUT_DIALOG: Option<Controller<AboutDialog>> = None;
 
 pub struct MainApp {
@@ -102,7 +143,11 @@ pub struct MainApp {
     outdated_games_indexes: HashMap<CardInfo, DynamicIndex>,
     available_games_indexes: HashMap<CardInfo, DynamicIndex>,
 
-    tasks_queue: AsyncController<TasksQueueComponent>
+    tasks_queue: AsyncController<TasksQueueComponent>,
+
+    /// `None` if updates weren't checked yet
+    updates_checked_at: Option<Instant>,
+    checking_updates: bool
 }
 
 #[derive(Debug)]
@@ -119,10 +164,26 @@ pub enum MainAppMsg {
 
     OpenPreferences,
     OpenAddonsManager(CardInfo),
+    OpenTasksHistory,
 
-    SetEnabledAddons {
+    SetAddonsProfiles {
         game: CardInfo,
-        addons: HashSet<GameEditionAddon>
+        profiles: GameEditionProfiles
+    },
+
+    SelectAddonsProfile {
+        game: CardInfo,
+        profile: String
+    },
+
+    SetUpdatePolicy {
+        game: CardInfo,
+        policy: Option<UpdatePolicy>
+    },
+
+    SetLayersOrder {
+        game: CardInfo,
+        layers: Vec<GameEditionAddon>
     },
 
     ShowTasksFlap,
@@ -130,8 +191,36 @@ pub enum MainAppMsg {
     ToggleTasksFlap,
 
     AddDownloadGameTask(CardInfo),
-    AddVerifyGameTask(CardInfo),
+    AddVerifyGameTask {
+        game_info: CardInfo,
+        mode: VerifyMode,
+        dry_run: bool
+    },
+
+    QueueVerifyGameTask(Box<VerifyIntegrityQueuedTask>),
+
+    ShowVerifyReport(VerifyReport),
+    ExportVerifyReport(VerifyReport),
+
+    /// Repair broken files from the verification report
+    RepairGame(VerifyReport),
+
+    AddPredownloadGameTask(CardInfo),
     FinishQueuedTask(CardInfo),
+    CancelQueuedTask(CardInfo),
+
+    /// Put the game with cancelled tasks back to the list matching its diff status
+    ///
+    /// `None` if the game is not installed
+    RestoreCancelledGame {
+        info: CardInfo,
+        status: Option<DiffStatus>
+    },
+
+    QueueDownloadGameTask {
+        game_info: CardInfo,
+        result: DownloadGameResult
+    },
 
     AddDownloadAddonTask {
         game_info: CardInfo,
@@ -139,12 +228,22 @@ pub enum MainAppMsg {
         group: AddonsGroup
     },
 
+    QueueDownloadAddonTask(Box<DownloadDiffQueuedTask>),
+
     AddUninstallAddonTask {
         game_info: CardInfo,
         addon: Addon,
         group: AddonsGroup
     },
 
+    AddVerifyAddonTask {
+        game_info: CardInfo,
+        addon: Addon,
+        group: AddonsGroup,
+        mode: VerifyMode,
+        dry_run: bool
+    },
+
     AddDownloadWineTask(Wine),
     AddDownloadDxvkTask(Dxvk),
     AddApplyDxvkTask(Dxvk),
@@ -154,6 +253,25 @@ pub enum MainAppMsg {
         install_corefonts: bool
     },
 
+    /// Show summary of the tasks updating all the outdated games and addons
+    UpdateAll,
+
+    /// Show summary of the tasks verifying all the installed games
+    VerifyAll,
+
+    QueueUpdateAllTasks(UpdateAllTasks),
+    QueueVerifyAllTasks(VerifyAllTasks),
+
+    /// Run background updates check if it's time to
+    CheckUpdates,
+
+    /// Notify about found updates or queue them according to the games policies
+    ApplyUpdates(UpdatesCheck),
+
+    /// Queue tasks saved before the launcher was closed
+    /// keeping their original order
+    RestoreTasks(Vec<TaskDescriptor>),
+
     LaunchGame(CardInfo),
     KillGame(CardInfo),
     FinishRunningGame(CardInfo),
@@ -170,6 +288,10 @@ relm4::new_stateless_action!(LauncherFolder, WindowActionGroup, "launcher_folder
 relm4::new_stateless_action!(ConfigFile, WindowActionGroup, "config_file");
 relm4::new_stateless_action!(DebugFile, WindowActionGroup, "debug_file");
 
+relm4::new_stateless_action!(UpdateAll, WindowActionGroup, "update_all");
+relm4::new_stateless_action!(VerifyAll, WindowActionGroup, "verify_all");
+
+relm4::new_stateless_action!(TasksHistory, WindowActionGroup, "tasks_history");
 relm4::new_stateless_action!(About, WindowActionGroup, "about");
 
 #[relm4::component(pub, async)]
@@ -186,6 +308,15 @@ impl SimpleAsyncComponent for MainApp {
                 &tr!("main-menu-debug-file")      => DebugFile,
             },
 
+            section! {
+                &tr!("main-menu-update-all") => UpdateAll,
+                &tr!("main-menu-verify-all") => VerifyAll
+            },
+
+            section! {
+                &tr!("main-menu-tasks-history") => TasksHistory
+            },
+
             section! {
                 &tr!("main-menu-about") => About
             }
@@ -426,8 +557,11 @@ impl SimpleAsyncComponent for MainApp {
                     GameDetailsComponentOutput::DownloadGame(info)
                         => MainAppMsg::AddDownloadGameTask(info),
 
-                    GameDetailsComponentOutput::VerifyGame(info)
-                        => MainAppMsg::AddVerifyGameTask(info),
+                    GameDetailsComponentOutput::VerifyGame { info, mode, dry_run }
+                        => MainAppMsg::AddVerifyGameTask { game_info: info, mode, dry_run },
+
+                    GameDetailsComponentOutput::PredownloadGame(info)
+                        => MainAppMsg::AddPredownloadGameTask(info),
 
                     GameDetailsComponentOutput::LaunchGame(info)
                         => MainAppMsg::LaunchGame(info),
@@ -438,6 +572,12 @@ impl SimpleAsyncComponent for MainApp {
                     GameDetailsComponentOutput::OpenAddonsManager(info)
                         => MainAppMsg::OpenAddonsManager(info),
 
+                    GameDetailsComponentOutput::SetUpdatePolicy { info, policy }
+                        => MainAppMsg::SetUpdatePolicy { game: info, policy },
+
+                    GameDetailsComponentOutput::SelectAddonsProfile { info, profile }
+                        => MainAppMsg::SelectAddonsProfile { game: info, profile },
+
                     GameDetailsComponentOutput::ShowToast { title, message }
                         => MainAppMsg::ShowToast { title, message }
                 }),
@@ -497,17 +637,26 @@ impl SimpleAsyncComponent for MainApp {
                 }),
 
             tasks_queue: TasksQueueComponent::builder()
-                .launch(CardInfo::default())
+                .launch(())
                 .forward(sender.input_sender(), |output| match output {
                     TasksQueueComponentOutput::TaskFinished(info)
                         => MainAppMsg::FinishQueuedTask(info),
 
+                    TasksQueueComponentOutput::TaskCancelled(info)
+                        => MainAppMsg::CancelQueuedTask(info),
+
+                    TasksQueueComponentOutput::ShowReport(report)
+                        => MainAppMsg::ShowVerifyReport(report),
+
                     TasksQueueComponentOutput::HideTasksFlap
                         => MainAppMsg::HideTasksFlap,
 
                     TasksQueueComponentOutput::ShowToast { title, message }
                         => MainAppMsg::ShowToast { title, message }
                 }),
+
+            updates_checked_at: None,
+            checking_updates: false
         };
 
         let leaflet = &model.leaflet;
@@ -537,6 +686,10 @@ impl SimpleAsyncComponent for MainApp {
                 .launch(widgets.window.clone())
                 .forward(sender.input_sender(), std::convert::identity));
 
+            TASKS_HISTORY_APP = Some(TasksHistoryApp::builder()
+                .launch(widgets.window.clone())
+                .detach());
+
             ABOUT_DIALOG = Some(AboutDialog::builder()
                 .transient_for(widgets.window.clone())
                 .launch_with_broker((), &about_dialog_broker)
@@ -578,12 +731,30 @@ impl SimpleAsyncComponent for MainApp {
             }
         })));
 
+        group.add_action::<UpdateAll>(RelmAction::new_stateless(gtk::glib::clone!(@strong sender => move |_| {
+            sender.input(MainAppMsg::UpdateAll);
+        })));
+
+        group.add_action::<VerifyAll>(RelmAction::new_stateless(gtk::glib::clone!(@strong sender => move |_| {
+            sender.input(MainAppMsg::VerifyAll);
+        })));
+
+        group.add_action::<TasksHistory>(RelmAction::new_stateless(gtk::glib::clone!(@strong sender => move |_| {
+            sender.input(MainAppMsg::OpenTasksHistory);
+        })));
+
         group.add_action::<About>(RelmAction::new_stateless(move |_| {
             about_dialog_broker.send(AboutDialogMsg::Show);
         }));
 
         widgets.window.insert_action_group("win", Some(&group.into_action_group()));
 
+        gtk::glib::timeout_add_seconds_local(UPDATES_TIMER_INTERVAL, gtk::glib::clone!(@strong sender => move || {
+            sender.input(MainAppMsg::CheckUpdates);
+
+            gtk::glib::ControlFlow::Continue
+        }));
+
         AsyncComponentParts { model, widgets }
     }
 
@@ -671,6 +842,20 @@ impl SimpleAsyncComponent for MainApp {
 
                     sender.input(MainAppMsg::ShowTasksFlap);
                 }
+
+                // Already queued tasks are skipped by the tasks queue
+                match tasks_queue::load_queue() {
+                    Ok(tasks) => {
+                        if !tasks.is_empty() {
+                            sender.input(MainAppMsg::ShowTasksFlap);
+                            sender.input(MainAppMsg::RestoreTasks(tasks));
+                        }
+                    }
+
+                    Err(err) => tracing::warn!("Failed to load saved tasks queue: {err}")
+                }
+
+                sender.input(MainAppMsg::CheckUpdates);
             }
 
             MainAppMsg::OpenDetails { info, installed, running } => {
@@ -682,6 +867,11 @@ impl SimpleAsyncComponent for MainApp {
 
                 if !installed {
                     self.game_details.emit(GameDetailsComponentInput::SetStatus(None));
+
+                    self.game_details.emit(GameDetailsComponentInput::SetPredownload {
+                        available: false,
+                        finished: false
+                    });
                 }
 
                 else {
@@ -691,6 +881,18 @@ impl SimpleAsyncComponent for MainApp {
 
                     let settings = config::get().games.get_game_settings(game).unwrap();
 
+                    self.game_details.emit(GameDetailsComponentInput::SetUpdatePolicy(settings.update_policy));
+
+                    let profiles = settings.addons
+                        .get(info.get_edition())
+                        .cloned()
+                        .unwrap_or_default();
+
+                    self.game_details.emit(GameDetailsComponentInput::SetAddonsProfiles {
+                        names: profiles.get_names(),
+                        active: profiles.active
+                    });
+
                     let paths = settings
                         .paths
                         .get(info.get_edition())
@@ -714,6 +916,33 @@ impl SimpleAsyncComponent for MainApp {
                             });
                         }
                     }
+
+                    match game.driver.get_game_predownload(&paths.game.to_string_lossy(), info.get_edition()) {
+                        Ok(predownload) => {
+                            let finished = predownload.as_ref()
+                                .map(|download| predownload::is_finished(predownload::get_staging_path(&paths.predownload, &download.version)))
+                                .unwrap_or_default();
+
+                            self.game_details.emit(GameDetailsComponentInput::SetPredownload {
+                                available: predownload.is_some(),
+                                finished
+                            });
+                        }
+
+                        Err(err) => {
+                            self.game_details.emit(GameDetailsComponentInput::SetPredownload {
+                                available: false,
+                                finished: false
+                            });
+
+                            sender.input(MainAppMsg::ShowToast {
+                                title: tr!("game-find-predownload-failed", {
+                                    "game-title" = info.get_title()
+                                }),
+                                message: Some(err.to_string())
+                            });
+                        }
+                    }
                 }
 
                 self.leaflet.navigate(adw::NavigationDirection::Forward);
@@ -730,6 +959,14 @@ impl SimpleAsyncComponent for MainApp {
                     .present();
             }
 
+            MainAppMsg::OpenTasksHistory => unsafe {
+                let controller = TASKS_HISTORY_APP.as_ref()
+                    .unwrap_unchecked();
+
+                controller.emit(TasksHistoryAppMsg::Update);
+                controller.widget().present();
+            }
+
             MainAppMsg::OpenAddonsManager(game_info) => unsafe {
                 let controller = GAME_ADDONS_MANAGER_APP.as_ref()
                     .unwrap_unchecked();
@@ -758,13 +995,74 @@ impl SimpleAsyncComponent for MainApp {
             }
 
             // FIXME: doesn't look really safe
-            MainAppMsg::SetEnabledAddons { game, addons } => {
+            MainAppMsg::SetAddonsProfiles { game, profiles } => {
                 let property = format!("games.settings.{}.addons.{}", game.get_name(), game.get_edition());
-                let value = serde_json::to_value(&addons).unwrap();
+                let value = serde_json::to_value(&profiles).unwrap();
 
-                config::set(property, value).unwrap();
+                if let Err(err) = config::set(property, value) {
+                    sender.input(MainAppMsg::ShowToast {
+                        title: tr!("config-property-update-failed"),
+                        message: Some(err.to_string())
+                    });
+                }
 
+                if self.game_details_info == game {
+                    self.game_details.emit(GameDetailsComponentInput::SetAddonsProfiles {
+                        names: profiles.get_names(),
+                        active: profiles.active
+                    });
+                }
+            }
+
+            MainAppMsg::SelectAddonsProfile { game, profile } => {
+                let driver = unsafe {
+                    games::get_unsafe(game.get_name())
+                };
+
+                match config::get().games.get_game_settings(driver) {
+                    Ok(settings) => {
+                        let mut profiles = settings.addons
+                            .get(game.get_edition())
+                            .cloned()
+                            .unwrap_or_default();
+
+                        if profiles.profiles.contains_key(&profile) {
+                            profiles.active = profile;
+
+                            sender.input(MainAppMsg::SetAddonsProfiles {
+                                game,
+                                profiles
+                            });
+                        }
+                    }
+
+                    Err(err) => sender.input(MainAppMsg::ShowToast {
+                        title: tr!("config-property-update-failed"),
+                        message: Some(err.to_string())
+                    })
+                }
+            }
 
+            MainAppMsg::SetUpdatePolicy { game, policy } => {
+                let property = format!("games.settings.{}.update_policy", game.get_name());
+
+                if let Err(err) = config::set(property, serde_json::to_value(policy).unwrap()) {
+                    sender.input(MainAppMsg::ShowToast {
+                        title: tr!("config-property-update-failed"),
+                        message: Some(err.to_string())
+                    });
+                }
+            }
+
+            MainAppMsg::SetLayersOrder { game, layers } => {
+                let property = format!("games.settings.{}.layers_order.{}", game.get_name(), game.get_edition());
+
+                if let Err(err) = config::set(property, serde_json::to_value(layers).unwrap()) {
+                    sender.input(MainAppMsg::ShowToast {
+                        title: tr!("config-property-update-failed"),
+                        message: Some(err.to_string())
+                    });
+                }
             }
 
             MainAppMsg::ShowTasksFlap => {
@@ -783,36 +1081,32 @@ impl SimpleAsyncComponent for MainApp {
                 let config = config::get();
 
                 match download_game_task::get_download_game_task(&game_info, &config) {
-                    Ok(result) => {
-                        self.tasks_queue.emit(TasksQueueComponentInput::AddTask(result.game_task));
-
-                        if let Some(index) = self.available_games_indexes.get(&game_info) {
-                            self.available_games.guard().remove(index.current_index());
-                            self.available_games_indexes.remove(&game_info);
-                        }
+                    Ok(result) => match disk_space::check_task(&result.game_task) {
+                        Ok(DiskSpaceCheck::Enough) => sender.input(MainAppMsg::QueueDownloadGameTask {
+                            game_info,
+                            result
+                        }),
 
-                        else if let Some(index) = self.outdated_games_indexes.get(&game_info) {
-                            self.outdated_games.guard().remove(index.current_index());
-                            self.outdated_games_indexes.remove(&game_info);
-                        }
+                        Ok(check) => {
+                            let sender = sender.clone();
+                            let result = Cell::new(Some(result));
 
-                        #[allow(clippy::map_entry)]
-                        if !self.queued_games_indexes.contains_key(&game_info) {
-                            self.queued_games_indexes.insert(game_info.clone(), self.queued_games.guard().push_back(game_info.clone()));
-
-                            self.queued_games.broadcast(CardComponentInput::SetInstalled(false));
-                            self.queued_games.broadcast(CardComponentInput::SetClickable(false));
+                            disk_space::show_dialog(check, move || {
+                                if let Some(result) = result.take() {
+                                    sender.input(MainAppMsg::QueueDownloadGameTask {
+                                        game_info: game_info.clone(),
+                                        result
+                                    });
+                                }
+                            });
                         }
 
-                        if config.general.verify_games {
-                            sender.input(MainAppMsg::AddVerifyGameTask(game_info.clone()));
-                        }
+                        Err(err) => {
+                            tracing::warn!("Failed to check available disk space: {err}");
 
-                        for addon in result.download_addons {
-                            sender.input(MainAppMsg::AddDownloadAddonTask {
-                                game_info: game_info.clone(),
-                                addon: addon.addon,
-                                group: addon.group
+                            sender.input(MainAppMsg::QueueDownloadGameTask {
+                                game_info,
+                                result
                             });
                         }
                     }
@@ -821,27 +1115,219 @@ impl SimpleAsyncComponent for MainApp {
                 }
             }
 
-            MainAppMsg::AddVerifyGameTask(game_info) => {
+            MainAppMsg::QueueDownloadGameTask { game_info, result } => {
                 let config = config::get();
 
-                match verify_game_task::get_verify_game_task(&game_info, &config) {
-                    Ok(task) => {
-                        self.tasks_queue.emit(TasksQueueComponentInput::AddTask(task));
+                self.tasks_queue.emit(TasksQueueComponentInput::AddTask(result.game_task));
 
-                        if let Some(index) = self.installed_games_indexes.get(&game_info) {
-                            self.installed_games.guard().remove(index.current_index());
-                            self.installed_games_indexes.remove(&game_info);
+                if !result.addons_conflicts.is_empty() {
+                    sender.input(MainAppMsg::ShowToast {
+                        title: tr!("game-addons-conflicts", {
+                            "game-title" = game_info.get_title().to_string()
+                        }),
+                        message: Some(result.addons_conflicts.join("\n"))
+                    });
+                }
+
+                if let Some(index) = self.available_games_indexes.get(&game_info) {
+                    self.available_games.guard().remove(index.current_index());
+                    self.available_games_indexes.remove(&game_info);
+                }
 
-                            #[allow(clippy::map_entry)]
-                            if !self.queued_games_indexes.contains_key(&game_info) {
-                                self.queued_games_indexes.insert(game_info.clone(), self.queued_games.guard().push_back(game_info.clone()));
+                else if let Some(index) = self.outdated_games_indexes.get(&game_info) {
+                    self.outdated_games.guard().remove(index.current_index());
+                    self.outdated_games_indexes.remove(&game_info);
+                }
 
-                                self.queued_games.broadcast(CardComponentInput::SetInstalled(false));
-                                self.queued_games.broadcast(CardComponentInput::SetClickable(false));
-                            }
+                #[allow(clippy::map_entry)]
+                if !self.queued_games_indexes.contains_key(&game_info) {
+                    self.queued_games_indexes.insert(game_info.clone(), self.queued_games.guard().push_back(game_info.clone()));
+
+                    self.queued_games.broadcast(CardComponentInput::SetInstalled(false));
+                    self.queued_games.broadcast(CardComponentInput::SetClickable(false));
+                }
+
+                if config.general.verify_games {
+                    // Only updated files have to be hashed
+                    sender.input(MainAppMsg::AddVerifyGameTask {
+                        game_info: game_info.clone(),
+                        mode: VerifyMode::Quick,
+                        dry_run: false
+                    });
+                }
+
+                for addon in result.download_addons {
+                    sender.input(MainAppMsg::AddDownloadAddonTask {
+                        game_info: game_info.clone(),
+                        addon: addon.addon,
+                        group: addon.group
+                    });
+                }
+            }
+
+            MainAppMsg::AddVerifyGameTask { game_info, mode, dry_run } => {
+                let config = config::get();
+
+                match verify_game_task::get_verify_game_task(&game_info, mode, dry_run, &config) {
+                    Ok(task) => sender.input(MainAppMsg::QueueVerifyGameTask(task)),
+                    Err(err) => sender.input(*err)
+                }
+            }
+
+            MainAppMsg::QueueVerifyGameTask(task) => {
+                let game_info = task.card_info.clone();
+                let is_game = task.origin == DiffOrigin::Game;
+
+                self.tasks_queue.emit(TasksQueueComponentInput::AddTask(task));
+
+                // Addons verification doesn't block the game itself
+                if !is_game {
+                    return;
+                }
+
+                if let Some(index) = self.installed_games_indexes.get(&game_info) {
+                    self.installed_games.guard().remove(index.current_index());
+                    self.installed_games_indexes.remove(&game_info);
+
+                    #[allow(clippy::map_entry)]
+                    if !self.queued_games_indexes.contains_key(&game_info) {
+                        self.queued_games_indexes.insert(game_info.clone(), self.queued_games.guard().push_back(game_info.clone()));
+
+                        self.queued_games.broadcast(CardComponentInput::SetInstalled(false));
+                        self.queued_games.broadcast(CardComponentInput::SetClickable(false));
+                    }
+                }
+            }
+
+            MainAppMsg::ShowVerifyReport(report) => {
+                let window = unsafe {
+                    WINDOW.as_ref().unwrap_unchecked()
+                };
+
+                let body = tr!("verify-report-summary", {
+                    "broken" = report.broken_files.len(),
+                    "total" = report.total_files
+                });
+
+                let dialog = adw::MessageDialog::new(
+                    Some(window),
+                    Some(&tr!("verify-report", {
+                        "game-title" = report.game.get_title().to_string()
+                    })),
+                    Some(&body)
+                );
+
+                if !report.broken_files.is_empty() {
+                    let files = report.broken_files.iter()
+                        .map(|file| {
+                            let problem = match &file.problem {
+                                FileProblem::Missing { .. } => tr!("verify-report-missing"),
+
+                                FileProblem::SizeMismatch { expected_size, actual_size } => tr!("verify-report-size-mismatch", {
+                                    "expected" = pretty_bytes(*expected_size),
+                                    "actual" = pretty_bytes(*actual_size)
+                                }),
+
+                                FileProblem::HashMismatch { hash_type, expected_hash, actual_hash } => tr!("verify-report-hash-mismatch", {
+                                    "hash-type" = hash_type.clone(),
+                                    "expected" = expected_hash.clone(),
+                                    "actual" = actual_hash.clone()
+                                })
+                            };
+
+                            format!("{}: {problem}", file.path)
+                        })
+                        .collect::<Vec<_>>()
+                        .join("\n");
+
+                    let label = gtk::Label::new(Some(&files));
+
+                    label.set_halign(gtk::Align::Start);
+                    label.set_valign(gtk::Align::Start);
+                    label.set_selectable(true);
+                    label.set_wrap(true);
+
+                    let scrolled_window = gtk::ScrolledWindow::new();
+
+                    scrolled_window.set_propagate_natural_height(true);
+                    scrolled_window.set_max_content_height(320);
+                    scrolled_window.set_child(Some(&label));
+
+                    dialog.set_extra_child(Some(&scrolled_window));
+                }
+
+                dialog.add_response("close", &tr!("dialog-close"));
+                dialog.add_response("export", &tr!("verify-report-export"));
+
+                dialog.connect_response(Some("export"), gtk::glib::clone!(@strong sender, @strong report => move |_, _| {
+                    sender.input(MainAppMsg::ExportVerifyReport(report.clone()));
+                }));
+
+                if !report.broken_files.is_empty() {
+                    dialog.add_response("repair", &tr!("verify-report-repair"));
+
+                    dialog.set_response_appearance("repair", adw::ResponseAppearance::Suggested);
+
+                    dialog.connect_response(Some("repair"), gtk::glib::clone!(@strong sender, @strong report => move |_, _| {
+                        sender.input(MainAppMsg::RepairGame(report.clone()));
+                    }));
+                }
+
+                dialog.present();
+            }
+
+            MainAppMsg::ExportVerifyReport(report) => {
+                let window = unsafe {
+                    WINDOW.as_ref().unwrap_unchecked()
+                };
+
+                let dialog = gtk::FileDialog::builder()
+                    .title(tr!("verify-report-export"))
+                    .initial_name(format!("{}-{}-verify-report.json", report.game.get_name(), report.game.get_edition()))
+                    .modal(true)
+                    .build();
+
+                match dialog.save_future(Some(window)).await {
+                    Ok(file) => {
+                        let Some(path) = file.path() else {
+                            return;
+                        };
+
+                        if let Err(err) = report.export(&path) {
+                            tracing::error!("Failed to export verification report to {path:?}: {err}");
+
+                            sender.input(MainAppMsg::ShowToast {
+                                title: tr!("verify-report-export-failed"),
+                                message: Some(err.to_string())
+                            });
                         }
                     }
 
+                    Err(err) => {
+                        if !err.matches(gtk::DialogError::Dismissed) {
+                            tracing::error!("Failed to choose verification report export path: {err}");
+                        }
+                    }
+                }
+            }
+
+            MainAppMsg::RepairGame(report) => {
+                // Only broken files are verified again
+                sender.input(MainAppMsg::QueueVerifyGameTask(Box::new(VerifyIntegrityQueuedTask {
+                    card_info: report.game,
+                    origin: report.origin,
+                    integrity_info: report.integrity_info,
+                    path: report.path,
+                    mode: VerifyMode::Full,
+                    dry_run: false
+                })));
+            }
+
+            MainAppMsg::AddPredownloadGameTask(game_info) => {
+                let config = config::get();
+
+                match predownload_game_task::get_predownload_game_task(&game_info, &config) {
+                    Ok(task) => self.tasks_queue.emit(TasksQueueComponentInput::AddTask(task)),
                     Err(err) => sender.input(*err)
                 }
             }
@@ -858,6 +1344,63 @@ impl SimpleAsyncComponent for MainApp {
                 }
             }
 
+            MainAppMsg::CancelQueuedTask(info) => {
+                if let Some(index) = self.queued_games_indexes.remove(&info) {
+                    self.queued_games.guard().remove(index.current_index());
+
+                    let sender = sender.clone();
+
+                    // Game diff is requested from the integration script
+                    // which can take some time, so it's done outside of the UI thread
+                    std::thread::spawn(move || {
+                        let status = games::get(info.get_name()).ok().flatten().and_then(|game| {
+                            let path = config::get().games.get_game_settings(game).ok()?
+                                .paths.get(info.get_edition())?
+                                .game.to_string_lossy()
+                                .to_string();
+
+                            game.driver.get_game_diff(&path, info.get_edition()).ok()?
+                                .map(|diff| diff.status)
+                        });
+
+                        sender.input(MainAppMsg::RestoreCancelledGame { info, status });
+                    });
+                }
+            }
+
+            MainAppMsg::RestoreCancelledGame { info, status } => {
+                // The game could be queued again while its diff was requested
+                let listed = self.queued_games_indexes.contains_key(&info) ||
+                    self.installed_games_indexes.contains_key(&info) ||
+                    self.outdated_games_indexes.contains_key(&info) ||
+                    self.available_games_indexes.contains_key(&info);
+
+                if listed {
+                    return;
+                }
+
+                match status {
+                    Some(DiffStatus::Latest) => {
+                        self.installed_games_indexes.insert(info.clone(), self.installed_games.guard().push_back(info));
+                    }
+
+                    Some(DiffStatus::Outdated) => {
+                        let index = self.outdated_games.guard().push_back(info.clone());
+
+                        self.outdated_games.send(index.current_index(), CardComponentInput::SetInstalled(false));
+                        self.outdated_games_indexes.insert(info, index);
+                    }
+
+                    // TODO: handle "unavailable" status
+                    _ => {
+                        let index = self.available_games.guard().push_back(info.clone());
+
+                        self.available_games.send(index.current_index(), CardComponentInput::SetInstalled(false));
+                        self.available_games_indexes.insert(info, index);
+                    }
+                }
+            }
+
             MainAppMsg::AddDownloadAddonTask { game_info, addon, group } => {
                 unsafe {
                     GAME_ADDONS_MANAGER_APP.as_ref()
@@ -870,15 +1413,36 @@ impl SimpleAsyncComponent for MainApp {
                 sender.input(MainAppMsg::ShowTasksFlap);
 
                 match download_addon_task::get_download_addon_task(&game_info, &addon, &group) {
-                    Ok(task) => {
-                        // TODO: should I move game to "queued"?
-                        self.tasks_queue.emit(TasksQueueComponentInput::AddTask(task));
+                    Ok(task) => match disk_space::check_task(&task) {
+                        Ok(DiskSpaceCheck::Enough) => sender.input(MainAppMsg::QueueDownloadAddonTask(task)),
+
+                        Ok(check) => {
+                            let sender = sender.clone();
+                            let task = Cell::new(Some(task));
+
+                            disk_space::show_dialog(check, move || {
+                                if let Some(task) = task.take() {
+                                    sender.input(MainAppMsg::QueueDownloadAddonTask(task));
+                                }
+                            });
+                        }
+
+                        Err(err) => {
+                            tracing::warn!("Failed to check available disk space: {err}");
+
+                            sender.input(MainAppMsg::QueueDownloadAddonTask(task));
+                        }
                     }
 
                     Err(err) => sender.input(*err)
                 }
             }
 
+            MainAppMsg::QueueDownloadAddonTask(task) => {
+                // TODO: should I move game to "queued"?
+                self.tasks_queue.emit(TasksQueueComponentInput::AddTask(task));
+            }
+
             MainAppMsg::AddUninstallAddonTask { game_info, addon, group } => {
                 unsafe {
                     GAME_ADDONS_MANAGER_APP.as_ref()
@@ -900,6 +1464,23 @@ impl SimpleAsyncComponent for MainApp {
                 }
             }
 
+            MainAppMsg::AddVerifyAddonTask { game_info, addon, group, mode, dry_run } => {
+                unsafe {
+                    GAME_ADDONS_MANAGER_APP.as_ref()
+                        .unwrap_unchecked()
+                        .widget()
+                        .close();
+                }
+
+                sender.input(MainAppMsg::HideDetails);
+                sender.input(MainAppMsg::ShowTasksFlap);
+
+                match verify_addon_task::get_verify_addon_task(&game_info, &addon, &group, mode, dry_run) {
+                    Ok(task) => sender.input(MainAppMsg::QueueVerifyGameTask(task)),
+                    Err(err) => sender.input(*err)
+                }
+            }
+
             MainAppMsg::AddDownloadWineTask(version) => {
                 self.tasks_queue.emit(TasksQueueComponentInput::AddTask(Box::new(DownloadWineQueuedTask {
                     card_info: CardInfo::Component {
@@ -941,6 +1522,271 @@ impl SimpleAsyncComponent for MainApp {
                 })));
             }
 
+            MainAppMsg::UpdateAll => {
+                let config = config::get();
+
+                let (tasks, errors) = batch_tasks::get_update_all_tasks(
+                    self.outdated_games_indexes.keys(),
+                    self.installed_games_indexes.keys(),
+                    &config
+                );
+
+                for err in errors {
+                    sender.input(err);
+                }
+
+                if tasks.is_empty() {
+                    sender.input(MainAppMsg::ShowToast {
+                        title: tr!("batch-nothing-to-update"),
+                        message: None
+                    });
+
+                    return;
+                }
+
+                let sender = sender.clone();
+
+                batch_tasks::show_update_all_dialog(tasks, move |tasks| {
+                    let check = disk_space::check_tasks(tasks.tasks())
+                        .unwrap_or_else(|err| {
+                            tracing::warn!("Failed to check available disk space: {err}");
+
+                            DiskSpaceCheck::Enough
+                        });
+
+                    let sender = sender.clone();
+                    let tasks = Cell::new(Some(tasks));
+
+                    disk_space::show_dialog(check, move || {
+                        if let Some(tasks) = tasks.take() {
+                            sender.input(MainAppMsg::QueueUpdateAllTasks(tasks));
+                        }
+                    });
+                });
+            }
+
+            MainAppMsg::VerifyAll => {
+                let config = config::get();
+
+                let (tasks, errors) = batch_tasks::get_verify_all_tasks(
+                    self.installed_games_indexes.keys(),
+                    &config
+                );
+
+                for err in errors {
+                    sender.input(err);
+                }
+
+                if tasks.is_empty() {
+                    sender.input(MainAppMsg::ShowToast {
+                        title: tr!("batch-nothing-to-verify"),
+                        message: None
+                    });
+
+                    return;
+                }
+
+                let sender = sender.clone();
+
+                batch_tasks::show_verify_all_dialog(tasks, move |tasks| {
+                    sender.input(MainAppMsg::QueueVerifyAllTasks(tasks));
+                });
+            }
+
+            MainAppMsg::QueueUpdateAllTasks(tasks) => {
+                for (game_info, result) in tasks.games {
+                    sender.input(MainAppMsg::QueueDownloadGameTask {
+                        game_info,
+                        result
+                    });
+                }
+
+                for (_, task) in tasks.addons {
+                    sender.input(MainAppMsg::QueueDownloadAddonTask(task));
+                }
+
+                sender.input(MainAppMsg::ShowTasksFlap);
+            }
+
+            MainAppMsg::QueueVerifyAllTasks(tasks) => {
+                for task in tasks.games {
+                    sender.input(MainAppMsg::QueueVerifyGameTask(task));
+                }
+
+                sender.input(MainAppMsg::ShowTasksFlap);
+            }
+
+            MainAppMsg::CheckUpdates => {
+                let config = config::get();
+
+                let interval = Duration::from_secs(config.general.updates.check_interval * 60);
+
+                let checked = self.updates_checked_at
+                    .is_some_and(|checked_at| checked_at.elapsed() < interval);
+
+                if self.checking_updates || checked {
+                    return;
+                }
+
+                // Outdated games are checked too so they can be downloaded
+                let games = self.installed_games_indexes.keys()
+                    .chain(self.outdated_games_indexes.keys())
+                    .filter(|game_info| !self.queued_games_indexes.contains_key(*game_info))
+                    .filter(|game_info| auto_update::get_policy(game_info, &config) != UpdatePolicy::Never)
+                    .cloned()
+                    .collect::<Vec<_>>();
+
+                if games.is_empty() {
+                    self.updates_checked_at = Some(Instant::now());
+
+                    return;
+                }
+
+                self.checking_updates = true;
+
+                let sender = sender.clone();
+
+                std::thread::spawn(move || {
+                    sender.input(MainAppMsg::ApplyUpdates(auto_update::check_updates(games)));
+                });
+            }
+
+            MainAppMsg::ApplyUpdates(check) => {
+                self.checking_updates = false;
+                self.updates_checked_at = Some(Instant::now());
+
+                let config = config::get();
+
+                let mut updates = Vec::new();
+
+                for game_info in check.games {
+                    let policy = auto_update::get_policy(&game_info, &config);
+
+                    if policy == UpdatePolicy::Never {
+                        continue;
+                    }
+
+                    // Games which were already outdated are shown
+                    // in the outdated list, so they're not notified about
+                    let notify = if let Some(index) = self.installed_games_indexes.remove(&game_info) {
+                        // Move game to the outdated list
+                        self.installed_games.guard().remove(index.current_index());
+
+                        let index = self.outdated_games.guard().push_back(game_info.clone());
+
+                        self.outdated_games.send(index.current_index(), CardComponentInput::SetInstalled(false));
+                        self.outdated_games_indexes.insert(game_info.clone(), index);
+
+                        true
+                    }
+
+                    // Skip games which were queued while checking
+                    else if self.outdated_games_indexes.contains_key(&game_info) {
+                        false
+                    }
+
+                    else {
+                        continue;
+                    };
+
+                    if policy == UpdatePolicy::Download {
+                        // Updates which need user's attention are only notified about
+                        if let Ok(result) = download_game_task::get_download_game_task(&game_info, &config) {
+                            if let Ok(DiskSpaceCheck::Enough) = disk_space::check_task(&result.game_task) {
+                                sender.input(MainAppMsg::QueueDownloadGameTask {
+                                    game_info,
+                                    result
+                                });
+
+                                continue;
+                            }
+                        }
+                    }
+
+                    if notify {
+                        updates.push(game_info.get_title().to_string());
+                    }
+                }
+
+                for entry in check.addons {
+                    // Skip addons of the games which were queued while checking
+                    if !self.installed_games_indexes.contains_key(&entry.game_info) {
+                        continue;
+                    }
+
+                    match auto_update::get_policy(&entry.game_info, &config) {
+                        UpdatePolicy::Never => continue,
+
+                        UpdatePolicy::Notify => (),
+
+                        UpdatePolicy::Download => {
+                            if let Ok(task) = download_addon_task::get_download_addon_task(&entry.game_info, &entry.addon, &entry.group) {
+                                if let Ok(DiskSpaceCheck::Enough) = disk_space::check_task(&task) {
+                                    sender.input(MainAppMsg::QueueDownloadAddonTask(task));
+
+                                    continue;
+                                }
+                            }
+                        }
+                    }
+
+                    updates.push(format!("{} ({})", entry.game_info.get_title(), entry.addon.title));
+                }
+
+                if !updates.is_empty() {
+                    sender.input(MainAppMsg::ShowToast {
+                        title: tr!("auto-update-available"),
+                        message: Some(updates.join("\n"))
+                    });
+                }
+            }
+
+            MainAppMsg::RestoreTasks(descriptors) => {
+                let config = config::get();
+
+                let mut tasks = Vec::with_capacity(descriptors.len());
+
+                for descriptor in descriptors {
+                    let task = match restore_tasks::get_restored_task(descriptor, &config) {
+                        Ok(Some(task)) => task,
+                        Ok(None) => continue,
+
+                        Err(err) => {
+                            sender.input(*err);
+
+                            continue;
+                        }
+                    };
+
+                    // Move the game to "queued" like when its task is added manually
+                    if let TaskDescriptor::DownloadGame { game_info } | TaskDescriptor::VerifyGame { game_info, .. } = task.get_descriptor() {
+                        if let Some(index) = self.available_games_indexes.remove(&game_info) {
+                            self.available_games.guard().remove(index.current_index());
+                        }
+
+                        else if let Some(index) = self.outdated_games_indexes.remove(&game_info) {
+                            self.outdated_games.guard().remove(index.current_index());
+                        }
+
+                        else if let Some(index) = self.installed_games_indexes.remove(&game_info) {
+                            self.installed_games.guard().remove(index.current_index());
+                        }
+
+                        #[allow(clippy::map_entry)]
+                        if !self.queued_games_indexes.contains_key(&game_info) {
+                            self.queued_games_indexes.insert(game_info.clone(), self.queued_games.guard().push_back(game_info));
+
+                            self.queued_games.broadcast(CardComponentInput::SetInstalled(false));
+                            self.queued_games.broadcast(CardComponentInput::SetClickable(false));
+                        }
+                    }
+
+                    tasks.push(task);
+                }
+
+                self.tasks_queue.emit(TasksQueueComponentInput::RestoreTasks(tasks));
+            }
+
             MainAppMsg::LaunchGame(info) => {
                 if let Some(index) = self.installed_games_indexes.get(&info) {
                     self.installed_games.guard().remove(index.current_index());
@@ -0,0 +1,68 @@
+use crate::tr;
+
+use crate::games;
+use crate::games::predownload;
+use crate::config;
+
+use crate::config::games::prelude::*;
+
+use crate::ui::components::game_card::CardInfo;
+use crate::ui::components::tasks_queue::predownload_task::PredownloadQueuedTask;
+
+use crate::games::integrations::Game;
+use crate::games::integrations::standards::download::Download;
+
+use super::MainAppMsg;
+
+type HeapResult<T> = Result<T, Box<MainAppMsg>>;
+
+#[inline]
+fn get_settings(game: &Game, config: &config::Config) -> HeapResult<GameSettings> {
+    config.games.get_game_settings(game)
+        .map_err(|err| Box::new(MainAppMsg::ShowToast {
+            title: tr!("game-get-settings-failed", {
+                "game-title" = game.manifest.game_title.clone()
+            }),
+            message: Some(err.to_string())
+        }))
+}
+
+#[inline]
+fn get_predownload(game: &Game, game_path: &str, edition: &str) -> HeapResult<Download> {
+    game.driver.get_game_predownload(game_path, edition)
+        .map_err(|err| MainAppMsg::ShowToast {
+            title: tr!("game-find-predownload-failed", {
+                "game-title" = game.manifest.game_title.clone()
+            }),
+            message: Some(err.to_string())
+        })?
+        .ok_or_else(|| Box::new(MainAppMsg::ShowToast {
+            title: tr!("game-predownload-unavailable", {
+                "game-title" = game.manifest.game_title.clone()
+            }),
+            message: None
+        }))
+}
+
+#[inline]
+pub fn get_predownload_game_task(game_info: &CardInfo, config: &config::Config) -> HeapResult<Box<PredownloadQueuedTask>> {
+    let game = unsafe {
+        games::get_unsafe(game_info.get_name())
+    };
+
+    let settings = get_settings(game, config)?;
+
+    let paths = &settings.paths[game_info.get_edition()];
+
+    let download = get_predownload(
+        game,
+        &paths.game.to_string_lossy(),
+        game_info.get_edition()
+    )?;
+
+    Ok(Box::new(PredownloadQueuedTask {
+        card_info: game_info.clone(),
+        staging_path: predownload::get_staging_path(&paths.predownload, download.version),
+        diff_info: download.download
+    }))
+}
@@ -0,0 +1,194 @@
+use crate::tr;
+
+use crate::games;
+use crate::config;
+
+use crate::components::wine::*;
+use crate::components::dxvk::*;
+
+use crate::ui::components::game_card::CardInfo;
+
+use crate::ui::components::tasks_queue::{
+    QueuedTask,
+    TaskDescriptor,
+
+    apply_dxvk_task::ApplyDxvkQueuedTask,
+    create_prefix_task::CreatePrefixQueuedTask,
+    delete_files_task::DeleteFilesQueuedTask
+};
+
+use crate::games::integrations::standards::addons::{
+    Addon,
+    AddonsGroup
+};
+
+use super::download_game_task;
+use super::download_addon_task;
+use super::verify_game_task;
+use super::verify_addon_task;
+use super::predownload_game_task;
+
+use super::MainAppMsg;
+
+type HeapResult<T> = Result<T, Box<MainAppMsg>>;
+
+/// Find game edition addon and its group by their names
+fn find_addon(game_info: &CardInfo, group_name: &str, addon_name: &str) -> HeapResult<Option<(Addon, AddonsGroup)>> {
+    let game = unsafe {
+        games::get_unsafe(game_info.get_name())
+    };
+
+    let groups = game.driver.get_addons_list(game_info.get_edition())
+        .map_err(|err| Box::new(MainAppMsg::ShowToast {
+            title: tr!("game-get-addons-failed", {
+                "game-title" = game_info.get_title()
+            }),
+            message: Some(err.to_string())
+        }))?;
+
+    let addon = groups.into_iter()
+        .find(|group| group.name == group_name)
+        .and_then(|group| {
+            group.addons.iter()
+                .find(|addon| addon.name == addon_name)
+                .cloned()
+                .map(|addon| (addon, group))
+        });
+
+    if addon.is_none() {
+        tracing::warn!("Failed to restore task of unknown addon: {group_name}/{addon_name}");
+    }
+
+    Ok(addon)
+}
+
+/// Build task saved before the launcher was closed
+///
+/// Return `None` if the task can't be restored anymore,
+/// e.g. if its game or component was removed
+pub fn get_restored_task(descriptor: TaskDescriptor, config: &config::Config) -> HeapResult<Option<Box<dyn QueuedTask>>> {
+    // Skip tasks of removed games
+    if let TaskDescriptor::DownloadGame { game_info } |
+           TaskDescriptor::VerifyGame { game_info, .. } |
+           TaskDescriptor::PredownloadGame { game_info } |
+           TaskDescriptor::DownloadAddon { game_info, .. } |
+           TaskDescriptor::VerifyAddon { game_info, .. } = &descriptor
+    {
+        if !matches!(games::get(game_info.get_name()), Ok(Some(_))) {
+            tracing::warn!("Failed to restore task of unknown game: {}", game_info.get_name());
+
+            return Ok(None);
+        }
+    }
+
+    let task: Box<dyn QueuedTask> = match descriptor {
+        // Addons and verification are restored as separate tasks
+        TaskDescriptor::DownloadGame { game_info } => download_game_task::get_download_game_task(&game_info, config)?.game_task,
+
+        TaskDescriptor::VerifyGame { game_info, mode, dry_run } => verify_game_task::get_verify_game_task(&game_info, mode, dry_run, config)?,
+        TaskDescriptor::PredownloadGame { game_info } => predownload_game_task::get_predownload_game_task(&game_info, config)?,
+
+        TaskDescriptor::DownloadAddon { game_info, group_name, addon_name } => {
+            let Some((addon, group)) = find_addon(&game_info, &group_name, &addon_name)? else {
+                return Ok(None);
+            };
+
+            download_addon_task::get_download_addon_task(&game_info, &addon, &group)?
+        }
+
+        TaskDescriptor::VerifyAddon { game_info, group_name, addon_name, mode, dry_run } => {
+            let Some((addon, group)) = find_addon(&game_info, &group_name, &addon_name)? else {
+                return Ok(None);
+            };
+
+            verify_addon_task::get_verify_addon_task(&game_info, &addon, &group, mode, dry_run)?
+        }
+
+        TaskDescriptor::DeleteFiles { paths } => Box::new(DeleteFilesQueuedTask {
+            paths
+        }),
+
+        TaskDescriptor::DownloadWine { name } => {
+            match Wine::versions().map(|versions| versions.into_iter().find(|version| version.name == name)) {
+                Ok(Some(version)) => Box::new(DownloadWineQueuedTask {
+                    card_info: CardInfo::Component {
+                        name: version.name.clone(),
+                        title: version.title.clone(),
+                        developer: String::new()
+                    },
+                    version
+                }),
+
+                Ok(None) => {
+                    tracing::warn!("Failed to restore task of unknown wine version: {name}");
+
+                    return Ok(None);
+                }
+
+                Err(err) => {
+                    tracing::warn!("Failed to restore wine download task: {err}");
+
+                    return Ok(None);
+                }
+            }
+        }
+
+        TaskDescriptor::DownloadDxvk { name } => {
+            match Dxvk::versions().map(|versions| versions.into_iter().find(|version| version.name == name)) {
+                Ok(Some(version)) => Box::new(DownloadDxvkQueuedTask {
+                    card_info: CardInfo::Component {
+                        name: version.name.clone(),
+                        title: version.name.clone(), // version.title.clone(),
+                        developer: String::new()
+                    },
+                    version
+                }),
+
+                Ok(None) => {
+                    tracing::warn!("Failed to restore task of unknown dxvk version: {name}");
+
+                    return Ok(None);
+                }
+
+                Err(err) => {
+                    tracing::warn!("Failed to restore dxvk download task: {err}");
+
+                    return Ok(None);
+                }
+            }
+        }
+
+        TaskDescriptor::ApplyDxvk { name, prefix_path } => {
+            match Dxvk::versions().map(|versions| versions.into_iter().find(|version| version.name == name)) {
+                Ok(Some(version)) => Box::new(ApplyDxvkQueuedTask {
+                    card_info: CardInfo::Component {
+                        name: version.name.clone(),
+                        title: version.title.clone(),
+                        developer: String::new()
+                    },
+                    dxvk_version: version,
+                    prefix_path
+                }),
+
+                Ok(None) => {
+                    tracing::warn!("Failed to restore task of unknown dxvk version: {name}");
+
+                    return Ok(None);
+                }
+
+                Err(err) => {
+                    tracing::warn!("Failed to restore dxvk applying task: {err}");
+
+                    return Ok(None);
+                }
+            }
+        }
+
+        TaskDescriptor::CreatePrefix { path, install_corefonts } => Box::new(CreatePrefixQueuedTask {
+            path,
+            install_corefonts
+        })
+    };
+
+    Ok(Some(task))
+}
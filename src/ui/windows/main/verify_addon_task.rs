@@ -0,0 +1,56 @@
+use crate::tr;
+
+use crate::games;
+
+use crate::games::integrations::standards::addons::{
+    Addon,
+    AddonsGroup
+};
+
+use crate::ui::components::game_card::CardInfo;
+
+use crate::ui::components::tasks_queue::download_diff_task::DiffOrigin;
+
+use crate::ui::components::tasks_queue::verify_integrity_task::{
+    VerifyIntegrityQueuedTask,
+    VerifyMode
+};
+
+use super::MainAppMsg;
+
+type HeapResult<T> = Result<T, Box<MainAppMsg>>;
+
+#[inline]
+pub fn get_verify_addon_task(game_info: &CardInfo, addon: &Addon, group: &AddonsGroup, mode: VerifyMode, dry_run: bool) -> HeapResult<Box<VerifyIntegrityQueuedTask>> {
+    let addon_path = addon.get_installation_path(&group.name, game_info.get_name(), game_info.get_edition())
+        .map_err(|err| Box::new(MainAppMsg::ShowToast {
+            title: tr!("addon-find-path-failed", {
+                "game-title" = game_info.get_title().to_string()
+            }),
+            message: Some(err.to_string())
+        }))?;
+
+    let game = unsafe {
+        games::get_unsafe(game_info.get_name())
+    };
+
+    let integrity_info = game.driver.get_addon_integrity(&group.name, &addon.name, &addon_path.to_string_lossy(), game_info.get_edition())
+        .map_err(|err| Box::new(MainAppMsg::ShowToast {
+            title: tr!("addon-get-integrity-failed", {
+                "game-title" = game_info.get_title().to_string()
+            }),
+            message: Some(err.to_string())
+        }))?;
+
+    Ok(Box::new(VerifyIntegrityQueuedTask {
+        card_info: game_info.clone(),
+        origin: DiffOrigin::Addon {
+            group_name: group.name.clone(),
+            addon_name: addon.name.clone()
+        },
+        integrity_info,
+        path: addon_path,
+        mode,
+        dry_run
+    }))
+}
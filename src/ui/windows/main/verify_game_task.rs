@@ -8,7 +8,11 @@ use crate::config;
 use crate::config::games::settings::GameSettings;
 
 use crate::ui::components::game_card::CardInfo;
-use crate::ui::components::tasks_queue::verify_integrity_task::VerifyIntegrityQueuedTask;
+use crate::ui::components::tasks_queue::download_diff_task::DiffOrigin;
+use crate::ui::components::tasks_queue::verify_integrity_task::{
+    VerifyIntegrityQueuedTask,
+    VerifyMode
+};
 
 use crate::games::integrations::Game;
 use crate::games::integrations::standards::prelude::IntegrityInfo;
@@ -54,7 +58,7 @@ fn get_game_path<'a>(game: &'a Game, edition: &str, config: &'a config::Config)
 }
 
 #[inline]
-pub fn get_verify_game_task(game_info: &CardInfo, config: &config::Config) -> HeapResult<Box<VerifyIntegrityQueuedTask>> {
+pub fn get_verify_game_task(game_info: &CardInfo, mode: VerifyMode, dry_run: bool, config: &config::Config) -> HeapResult<Box<VerifyIntegrityQueuedTask>> {
     let game = unsafe {
         games::get_unsafe(game_info.get_name())
     };
@@ -63,11 +67,14 @@ pub fn get_verify_game_task(game_info: &CardInfo, config: &config::Config) -> He
 
     Ok(Box::new(VerifyIntegrityQueuedTask {
         card_info: game_info.clone(),
+        origin: DiffOrigin::Game,
         integrity_info: get_integrity_info(
             game,
             &game_path.to_string_lossy(),
             game_info.get_edition()
         )?,
-        path: game_path
+        path: game_path,
+        mode,
+        dry_run
     }))
 }
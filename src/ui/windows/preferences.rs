@@ -10,6 +10,7 @@ use crate::config;
 use crate::components::wine::Wine;
 use crate::components::dxvk::Dxvk;
 
+use crate::config::general::prelude::UpdatePolicy;
 use crate::config::games::wine::prelude::*;
 use crate::config::games::enhancements::prelude::*;
 
@@ -107,14 +108,153 @@ impl SimpleAsyncComponent for PreferencesApp {
                         }
                     },
 
-                    // adw::ActionRow {
-                    //     set_title: "Update games",
-                    //     set_subtitle: "Download updates for installed games when they become available",
+                    adw::SwitchRow {
+                        set_title: &tr!("general-stream-extraction"),
+                        set_subtitle: &tr!("general-stream-extraction-description"),
 
-                    //     add_suffix = &gtk::Switch {
-                    //         set_valign: gtk::Align::Center
-                    //     }
-                    // },
+                        set_active: config::get().general.stream_extraction,
+
+                        connect_active_notify[sender] => move |switch| {
+                            if let Err(err) = config::set("general.stream_extraction", switch.is_active()) {
+                                sender.input(PreferencesAppMsg::ShowToast {
+                                    title: tr!("config-property-update-failed"),
+                                    message: Some(err.to_string())
+                                })
+                            }
+                        }
+                    },
+
+                    adw::SwitchRow {
+                        set_title: &tr!("general-files-cache"),
+                        set_subtitle: &tr!("general-files-cache-description"),
+
+                        set_active: config::get().general.cache.enabled,
+
+                        connect_active_notify[sender] => move |switch| {
+                            if let Err(err) = config::set("general.cache.enabled", switch.is_active()) {
+                                sender.input(PreferencesAppMsg::ShowToast {
+                                    title: tr!("config-property-update-failed"),
+                                    message: Some(err.to_string())
+                                })
+                            }
+                        }
+                    },
+
+                    adw::SpinRow {
+                        set_title: &tr!("general-max-concurrent-tasks"),
+                        set_subtitle: &tr!("general-max-concurrent-tasks-description"),
+
+                        set_adjustment: Some(&gtk::Adjustment::new(
+                            config::get().general.tasks.max_concurrent as f64,
+                            1.0, 16.0, 1.0, 1.0, 0.0
+                        )),
+
+                        connect_value_notify[sender] => move |row| {
+                            if let Err(err) = config::set("general.tasks.max_concurrent", row.value() as u64) {
+                                sender.input(PreferencesAppMsg::ShowToast {
+                                    title: tr!("config-property-update-failed"),
+                                    message: Some(err.to_string())
+                                })
+                            }
+                        }
+                    },
+
+                    adw::ExpanderRow {
+                        set_title: &tr!("general-proxy"),
+                        set_subtitle: &tr!("general-proxy-description"),
+
+                        add_row = &adw::EntryRow {
+                            set_title: &tr!("general-proxy-uri"),
+                            set_text: &config::get().general.network.proxy.uri,
+
+                            set_show_apply_button: true,
+
+                            connect_apply[sender] => move |row| {
+                                if let Err(err) = config::set("general.network.proxy.uri", row.text().trim()) {
+                                    sender.input(PreferencesAppMsg::ShowToast {
+                                        title: tr!("config-property-update-failed"),
+                                        message: Some(err.to_string())
+                                    })
+                                }
+                            }
+                        },
+
+                        add_row = &adw::EntryRow {
+                            set_title: &tr!("general-proxy-bypass"),
+                            set_text: &config::get().general.network.proxy.bypass.join(", "),
+
+                            set_show_apply_button: true,
+
+                            connect_apply[sender] => move |row| {
+                                let hosts = row.text()
+                                    .split(',')
+                                    .map(str::trim)
+                                    .filter(|host| !host.is_empty())
+                                    .map(String::from)
+                                    .collect::<Vec<_>>();
+
+                                if let Err(err) = config::set("general.network.proxy.bypass", hosts) {
+                                    sender.input(PreferencesAppMsg::ShowToast {
+                                        title: tr!("config-property-update-failed"),
+                                        message: Some(err.to_string())
+                                    })
+                                }
+                            }
+                        }
+                    },
+
+                    adw::ComboRow {
+                        set_title: &tr!("general-update-policy"),
+                        set_subtitle: &tr!("general-update-policy-description"),
+
+                        set_model: Some(&gtk::StringList::new(&[
+                            &tr!("general-update-policy-never"),
+                            &tr!("general-update-policy-notify"),
+                            &tr!("general-update-policy-download")
+                        ])),
+
+                        set_selected: match config::get().general.updates.policy {
+                            UpdatePolicy::Never    => 0,
+                            UpdatePolicy::Notify   => 1,
+                            UpdatePolicy::Download => 2
+                        },
+
+                        connect_selected_notify[sender] => move |row| {
+                            let policy = [
+                                UpdatePolicy::Never,
+                                UpdatePolicy::Notify,
+                                UpdatePolicy::Download
+                            ][row.selected() as usize];
+
+                            let value = serde_json::to_value(policy).unwrap();
+
+                            if let Err(err) = config::set("general.updates.policy", value) {
+                                sender.input(PreferencesAppMsg::ShowToast {
+                                    title: tr!("config-property-update-failed"),
+                                    message: Some(err.to_string())
+                                })
+                            }
+                        }
+                    },
+
+                    adw::SpinRow {
+                        set_title: &tr!("general-updates-check-interval"),
+                        set_subtitle: &tr!("general-updates-check-interval-description"),
+
+                        set_adjustment: Some(&gtk::Adjustment::new(
+                            config::get().general.updates.check_interval as f64,
+                            5.0, 1440.0, 5.0, 60.0, 0.0
+                        )),
+
+                        connect_value_notify[sender] => move |row| {
+                            if let Err(err) = config::set("general.updates.check_interval", row.value() as u64) {
+                                sender.input(PreferencesAppMsg::ShowToast {
+                                    title: tr!("config-property-update-failed"),
+                                    message: Some(err.to_string())
+                                })
+                            }
+                        }
+                    },
 
                     // adw::ActionRow {
                     //     set_title: "Pre-download updates",
@@ -0,0 +1,299 @@
+use relm4::prelude::*;
+
+use gtk::prelude::*;
+use adw::prelude::*;
+
+use crate::tr;
+
+use crate::ui::components::tasks_queue::history::{
+    self,
+    TaskHistoryEntry,
+    TaskResult
+};
+
+use crate::utils::{
+    pretty_bytes,
+    pretty_seconds
+};
+
+pub static mut WINDOW: Option<adw::Window> = None;
+
+#[derive(Debug)]
+pub struct TasksHistoryApp {
+    pub toast_overlay: adw::ToastOverlay,
+
+    pub history_page: adw::PreferencesPage,
+    pub history_group: adw::PreferencesGroup,
+    pub history_rows: Vec<adw::ExpanderRow>
+}
+
+#[derive(Debug, Clone)]
+pub enum TasksHistoryAppMsg {
+    /// Reload tasks history from the file
+    Update,
+
+    Export,
+    Clear,
+
+    ShowToast {
+        title: String,
+        message: Option<String>
+    }
+}
+
+/// Make row with the tasks history entry
+fn build_row(entry: &TaskHistoryEntry) -> adw::ExpanderRow {
+    let started_at = chrono::DateTime::from_timestamp(entry.started_at, 0)
+        .map(|time| time.with_timezone(&chrono::Local).format("%Y-%m-%d %H:%M:%S").to_string())
+        .unwrap_or_default();
+
+    let duration = pretty_seconds(entry.finished_at.saturating_sub(entry.started_at).max(0) as u64);
+
+    let row = adw::ExpanderRow::new();
+
+    row.set_title(entry.info.get_title());
+    row.set_subtitle(&format!("{started_at} · {duration}"));
+
+    let (result, css_class) = match entry.result {
+        TaskResult::Finished  => (tr!("tasks-history-finished"), "success"),
+        TaskResult::Failed    => (tr!("tasks-history-failed"), "error"),
+        TaskResult::Cancelled => (tr!("tasks-history-cancelled"), "dim-label")
+    };
+
+    let result = gtk::Label::new(Some(&result));
+
+    result.add_css_class(css_class);
+
+    row.add_suffix(&result);
+
+    if let Some(bytes) = entry.transferred_bytes {
+        let transferred_row = adw::ActionRow::new();
+
+        transferred_row.set_title(&tr!("tasks-history-transferred"));
+        transferred_row.set_subtitle(&pretty_bytes(bytes));
+
+        row.add_row(&transferred_row);
+    }
+
+    if let Some(speed) = entry.average_speed {
+        let speed_row = adw::ActionRow::new();
+
+        speed_row.set_title(&tr!("tasks-avg-speed", {
+            "format" = "data",
+            "speed" = pretty_bytes(speed)
+        }));
+
+        row.add_row(&speed_row);
+    }
+
+    if !entry.errors.is_empty() {
+        let errors_row = adw::ActionRow::new();
+
+        errors_row.set_title(&tr!("tasks-history-errors"));
+        errors_row.set_subtitle(&entry.errors.join("\n"));
+        errors_row.set_subtitle_lines(0);
+        errors_row.set_subtitle_selectable(true);
+
+        row.add_row(&errors_row);
+    }
+
+    row.set_enable_expansion(entry.transferred_bytes.is_some() || !entry.errors.is_empty());
+
+    row
+}
+
+#[relm4::component(pub, async)]
+impl SimpleAsyncComponent for TasksHistoryApp {
+    type Init = adw::Window;
+    type Input = TasksHistoryAppMsg;
+    type Output = ();
+
+    view! {
+        window = adw::Window {
+            set_default_size: (700, 560),
+            set_title: Some(&tr!("tasks-history")),
+
+            set_hide_on_close: true,
+            set_modal: true,
+
+            add_css_class?: crate::APP_DEBUG.then_some("devel"),
+
+            #[local_ref]
+            toast_overlay -> adw::ToastOverlay {
+                gtk::Box {
+                    set_orientation: gtk::Orientation::Vertical,
+
+                    adw::HeaderBar {
+                        add_css_class: "flat",
+
+                        pack_start = &gtk::Button {
+                            set_icon_name: "user-trash-symbolic",
+                            set_tooltip: &tr!("tasks-history-clear"),
+
+                            #[watch]
+                            set_sensitive: !model.history_rows.is_empty(),
+
+                            connect_clicked => TasksHistoryAppMsg::Clear
+                        },
+
+                        pack_end = &gtk::Button {
+                            set_icon_name: "document-save-symbolic",
+                            set_tooltip: &tr!("tasks-history-export"),
+
+                            #[watch]
+                            set_sensitive: !model.history_rows.is_empty(),
+
+                            connect_clicked => TasksHistoryAppMsg::Export
+                        }
+                    },
+
+                    adw::StatusPage {
+                        set_vexpand: true,
+
+                        set_icon_name: Some("document-open-recent-symbolic"),
+                        set_title: &tr!("tasks-history-empty"),
+
+                        #[watch]
+                        set_visible: model.history_rows.is_empty()
+                    },
+
+                    #[local_ref]
+                    history_page -> adw::PreferencesPage {
+                        #[watch]
+                        set_visible: !model.history_rows.is_empty()
+                    }
+                }
+            }
+        }
+    }
+
+    async fn init(parent: Self::Init, root: Self::Root, _sender: AsyncComponentSender<Self>) -> AsyncComponentParts<Self> {
+        let model = Self {
+            toast_overlay: adw::ToastOverlay::new(),
+
+            history_page: adw::PreferencesPage::new(),
+            history_group: adw::PreferencesGroup::new(),
+            history_rows: Vec::new()
+        };
+
+        model.history_page.add(&model.history_group);
+
+        let toast_overlay = &model.toast_overlay;
+        let history_page = &model.history_page;
+
+        let widgets = view_output!();
+
+        widgets.window.set_transient_for(Some(&parent));
+
+        unsafe {
+            WINDOW = Some(widgets.window.clone());
+        }
+
+        AsyncComponentParts { model, widgets }
+    }
+
+    async fn update(&mut self, msg: Self::Input, sender: AsyncComponentSender<Self>) {
+        match msg {
+            TasksHistoryAppMsg::Update => {
+                for row in self.history_rows.drain(..) {
+                    self.history_group.remove(&row);
+                }
+
+                match history::get() {
+                    Ok(entries) => {
+                        // Show the newest entries first
+                        for entry in entries.iter().rev() {
+                            let row = build_row(entry);
+
+                            self.history_group.add(&row);
+                            self.history_rows.push(row);
+                        }
+                    }
+
+                    Err(err) => {
+                        tracing::error!("Failed to read tasks history: {err}");
+
+                        sender.input(TasksHistoryAppMsg::ShowToast {
+                            title: tr!("tasks-history-read-failed"),
+                            message: Some(err.to_string())
+                        });
+                    }
+                }
+            }
+
+            TasksHistoryAppMsg::Export => {
+                let window = unsafe {
+                    WINDOW.as_ref().unwrap_unchecked()
+                };
+
+                let dialog = gtk::FileDialog::builder()
+                    .title(tr!("tasks-history-export"))
+                    .initial_name("tasks-history.json")
+                    .modal(true)
+                    .build();
+
+                match dialog.save_future(Some(window)).await {
+                    Ok(file) => {
+                        let Some(path) = file.path() else {
+                            return;
+                        };
+
+                        if let Err(err) = history::export(&path) {
+                            tracing::error!("Failed to export tasks history to {path:?}: {err}");
+
+                            sender.input(TasksHistoryAppMsg::ShowToast {
+                                title: tr!("tasks-history-export-failed"),
+                                message: Some(err.to_string())
+                            });
+                        }
+                    }
+
+                    Err(err) => {
+                        if !err.matches(gtk::DialogError::Dismissed) {
+                            tracing::error!("Failed to choose tasks history export path: {err}");
+                        }
+                    }
+                }
+            }
+
+            TasksHistoryAppMsg::Clear => {
+                if let Err(err) = history::clear() {
+                    tracing::error!("Failed to clear tasks history: {err}");
+
+                    sender.input(TasksHistoryAppMsg::ShowToast {
+                        title: tr!("tasks-history-clear-failed"),
+                        message: Some(err.to_string())
+                    });
+                }
+
+                sender.input(TasksHistoryAppMsg::Update);
+            }
+
+            TasksHistoryAppMsg::ShowToast { title, message } => {
+                let window = unsafe {
+                    WINDOW.as_ref().unwrap_unchecked()
+                };
+
+                let toast = adw::Toast::new(&title);
+
+                if let Some(message) = message {
+                    toast.set_button_label(Some(&tr!("dialog-toast-details")));
+
+                    let dialog = adw::MessageDialog::new(
+                        Some(window),
+                        Some(&title),
+                        Some(&message)
+                    );
+
+                    dialog.add_response("close", &tr!("dialog-close"));
+
+                    toast.connect_button_clicked(move |_| {
+                        dialog.present();
+                    });
+                }
+
+                self.toast_overlay.add_toast(toast);
+            }
+        }
+    }
+}